@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ore_monitor::file_naming::{extract_filename, NameTemplate};
+
+// The header comes from the download server, the name it holds ends up on disk
+fuzz_target!(|header: &str| {
+    if let Some(file) = extract_filename(header) {
+        let name = NameTemplate::default().render("id", "1.0", file);
+        assert!(!name.contains('/') && !name.contains('\\'));
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ore_monitor::file_reader::FileReader;
+use std::io::Cursor;
+
+// Jars are downloaded or copied in by hand, any of them may be truncated or not a jar at all
+fuzz_target!(|data: &[u8]| {
+    let _ = FileReader::read_jar(Cursor::new(data));
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ore_monitor::update_order::satisfies;
+use ore_monitor_common::version_status::VersionStatus;
+
+// Versions and ranges are written by plugin authors, with little consistency between them
+fuzz_target!(|input: (&str, &str)| {
+    let (version, range) = input;
+    let _ = satisfies(version, range);
+    let _ = VersionStatus::new(version, range);
+});
@@ -1,18 +1,33 @@
 pub mod core_command {
     use anyhow::Result;
     use async_trait::async_trait;
-    use clap::Parser;
+    use clap::{ArgAction, Parser, Subcommand};
     use ore_monitor::gen_matches;
-    use ore_monitor::query::Query;
-    use reqwest::Response;
-    use serde::de::DeserializeOwned;
-    use std::fmt::Display;
+    use ore_monitor::{
+        ore::ore_client::{CacheMode, OreClient},
+        query::Query,
+    };
 
-    use crate::ore::ore_client::OreClient;
+    use std::{fmt::Display, path::PathBuf};
+
+    use crate::config::ore_config::Config;
 
     use super::{
-        install_command::InstallCommand, plugin_command::PluginCommand,
-        search_command::SearchCommand, version_check_command::VersionCheckCommand,
+        activity_command::ActivityCommand, audit_command::AuditCommand,
+        cache_command::CacheCommand, changelog_command::ChangelogCommand,
+        compare_command::CompareCommand, config_command::ConfigCommand,
+        debug_bundle_command::DebugBundleCommand, freeze_command::FreezeCommand,
+        identify_command::IdentifyCommand, index_command::IndexCommand,
+        init_server_command::InitServerCommand, install_command::InstallCommand,
+        lock_command::LockCommand, manifest_command::ManifestCommand, open_command::OpenCommand,
+        owner_command::OwnerCommand, ping_command::PingCommand, plugin_command::PluginCommand,
+        prefetch_command::PrefetchCommand, project_command::ProjectCommand,
+        publish_command::PublishCommand, reconcile_command::ReconcileCommand,
+        release_command::ReleaseCommand, search_command::SearchCommand,
+        toggle_command::DisableCommand, toggle_command::EnableCommand, user_command::UserCommand,
+        version_check_command::VersionCheckCommand, version_command::VersionCommand,
+        watch_command::WatchCommand, watchlist_command::WatchlistCommand,
+        why_outdated_command::WhyOutdatedCommand,
     };
 
     /// Represents a regular Command
@@ -20,32 +35,44 @@ pub mod core_command {
     pub trait OreCommand {
         async fn handle(&self, ore_client: OreClient, link_query: Option<Query>) -> Result<()>;
 
-        async fn serialize<T: DeserializeOwned>(&self, res: Response) -> Result<T>
-        where
-            Self: Sized,
-        {
-            self.serialize_str(&res.text().await?).await
-        }
-
-        async fn serialize_str<T: DeserializeOwned>(&self, txt: &str) -> Result<T>
-        where
-            Self: Sized,
-        {
-            serde_json::from_str(txt).map_err(|e| anyhow::Error::from(e))
-        }
-
         fn print_res<T: Display>(&self, res: T) -> Result<()>
         where
             Self: Sized,
         {
-            Ok(println!("{}", res))
+            println!("{}", res);
+            Ok(())
         }
     }
 
-    /// Represents the "root" commands
+    /// Represents the "root" of the application
     #[derive(Parser)]
     #[command(version)]
-    pub enum Cli {
+    pub struct Cli {
+        /// Prints the method, URL and query of each API call instead of sending it
+        #[arg(long, global = true)]
+        pub explain: bool,
+        /// Sends the API calls as usual when combined with --explain
+        #[arg(long, global = true, requires = "explain")]
+        pub run: bool,
+        /// Sends every request to Ore, instead of using cached responses
+        #[arg(long, global = true)]
+        pub no_cache: bool,
+        /// Only logs errors, progress bars and status messages are left out
+        #[arg(short, long, global = true, conflicts_with_all = ["verbose", "debug"])]
+        pub quiet: bool,
+        /// Logs more to stderr, -v for cache hits and -vv for every API call sent to Ore
+        #[arg(short, long, global = true, action = ArgAction::Count)]
+        pub verbose: u8,
+        /// Logs the id, method, URL, status and timing of each API call to stderr, the same as -vv
+        #[arg(long, global = true)]
+        pub debug: bool,
+        #[command(subcommand)]
+        pub command: RootCommand,
+    }
+
+    /// Represents the "root" commands
+    #[derive(Subcommand)]
+    pub enum RootCommand {
         /// Allows for searching for a list of plugins based off of the query
         Search(SearchCommand),
         /// Retreives info about a plugin from its plugin_id
@@ -54,9 +81,176 @@ pub mod core_command {
         Install(InstallCommand),
         /// Checks the version(s) and compares them against Ore
         Check(VersionCheckCommand),
+        /// Explains how check reached its verdict for a plugin
+        WhyOutdated(WhyOutdatedCommand),
+        /// Adopts manually updated jars into the lockfile
+        Reconcile(ReconcileCommand),
+        /// Identifies an unknown jar by searching Ore for its metadata and file hash
+        Identify(IdentifyCommand),
+        /// Compares several projects side by side
+        Compare(CompareCommand),
+        /// Keeps running, notifying about new projects of saved searches
+        Watch(WatchCommand),
+        /// Displays a dashboard of every project an owner maintains
+        Owner(OwnerCommand),
+        /// Uploads a new version of a project
+        #[command(visible_alias = "deploy")]
+        Publish(PublishCommand),
+        /// Tools for plugin authors releasing a new version
+        Release(ReleaseCommand),
+        /// Tools for authors managing their project
+        Project(ProjectCommand),
+        /// Tools for authors managing published versions
+        Version(VersionCommand),
+        /// Opens the Ore page of a project in the browser
+        Open(OpenCommand),
+        /// Displays a user and the projects they're a member of
+        User(UserCommand),
+        /// Reads and changes the config file
+        Config(ConfigCommand),
+        /// Checks Ore can be reached, measuring authentication and a sample request
+        Ping(PingCommand),
+        /// Gathers the version, platform, settings and last request, for reporting a problem
+        DebugBundle(DebugBundleCommand),
+        /// Warms the response and scan caches for every installed plugin
+        Prefetch(PrefetchCommand),
+        /// Manages the cached responses of Ore
+        Cache(CacheCommand),
+        /// Records the installed plugins in the lockfile, and optionally a manifest
+        Freeze(FreezeCommand),
+        /// Describes the plugins directory for tools other than ore-monitor
+        Manifest(ManifestCommand),
+        /// Enables a disabled plugin by renaming its jar back
+        Enable(EnableCommand),
+        /// Disables a plugin by renaming its jar so the server skips it
+        Disable(DisableCommand),
+        /// Flags a server on an end-of-life platform, and plugins that won't run on it
+        Audit(AuditCommand),
+        /// Shows the changelogs of a plugin's latest versions, newest first
+        Changelog(ChangelogCommand),
+        /// Reports which tracked plugins released versions, changed promoted versions or descriptions recently
+        Activity(ActivityCommand),
+        /// Keeps a local catalog of Ore's projects, searchable without asking Ore
+        Index(IndexCommand),
+        /// Signs lockfiles and verifies where their jars came from
+        Lock(LockCommand),
+        /// Guides setting up a new server, picking plugins by category, then writing their manifest and installing it
+        InitServer(InitServerCommand),
+        /// Follows plugins that aren't installed, reporting the versions they publish
+        Watchlist(WatchlistCommand),
+    }
+
+    impl RootCommand {
+        /// Fills in what wasn't given on the command line from the config
+        pub fn apply_config(&mut self, config: &Config) {
+            match self {
+                RootCommand::Check(command) => command.apply_config(config),
+                RootCommand::WhyOutdated(command) => command.apply_config(config),
+                RootCommand::Install(command) => command.apply_config(config),
+                RootCommand::Reconcile(command) => command.apply_config(config),
+                RootCommand::Prefetch(command) => command.apply_config(config),
+                RootCommand::Freeze(command) => command.apply_config(config),
+                RootCommand::Manifest(command) => command.apply_config(config),
+                RootCommand::Enable(command) => command.apply_config(config),
+                RootCommand::Disable(command) => command.apply_config(config),
+                RootCommand::Audit(command) => command.apply_config(config),
+                RootCommand::Activity(command) => command.apply_config(config),
+                RootCommand::Lock(command) => command.apply_config(config),
+                _ => (),
+            }
+        }
+
+        /// Prefetching refreshes the cached responses, commands that only look things up read them unless told not to
+        /// Polling, measuring and managing projects always need what Ore currently has.
+        pub fn cache_mode(&self, no_cache: bool) -> CacheMode {
+            match self {
+                RootCommand::Check(check) if check.is_offline() => CacheMode::Offline,
+                RootCommand::Prefetch(_) => CacheMode::Refresh,
+                RootCommand::Watch(_)
+                | RootCommand::Watchlist(_)
+                | RootCommand::Ping(_)
+                | RootCommand::Publish(_)
+                | RootCommand::Release(_)
+                | RootCommand::Project(_)
+                | RootCommand::Version(_)
+                | RootCommand::Index(_)
+                | RootCommand::Cache(_) => CacheMode::Off,
+                _ if no_cache => CacheMode::Off,
+                _ => CacheMode::Read,
+            }
+        }
+
+        /// Commands that never contact Ore, or authenticate on their own, run without a client being authenticated
+        pub fn requires_auth(&self) -> bool {
+            !matches!(
+                self,
+                RootCommand::Config(_)
+                    | RootCommand::Ping(_)
+                    | RootCommand::DebugBundle(_)
+                    | RootCommand::Cache(_)
+                    | RootCommand::Manifest(_)
+                    | RootCommand::Enable(_)
+                    | RootCommand::Disable(_)
+                    | RootCommand::Audit(_)
+                    | RootCommand::Lock(_)
+            ) && !matches!(self, RootCommand::Index(index) if index.is_offline())
+                && !matches!(self, RootCommand::Watchlist(watchlist) if watchlist.is_local())
+                && !matches!(self, RootCommand::Check(check) if check.is_offline())
+        }
+
+        /// The directory whose jars or lockfile the command changes, locked so other instances wait their turn
+        /// A cron `check --update` and an `install` by hand would otherwise race on the same jars.
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            match self {
+                RootCommand::Install(command) => command.changes_dir(),
+                RootCommand::Check(command) => command.changes_dir(),
+                RootCommand::Reconcile(command) => command.changes_dir(),
+                RootCommand::Freeze(command) => command.changes_dir(),
+                RootCommand::Enable(command) => command.changes_dir(),
+                RootCommand::Disable(command) => command.changes_dir(),
+                RootCommand::Lock(command) => command.changes_dir(),
+                RootCommand::InitServer(command) => command.changes_dir(),
+                _ => None,
+            }
+        }
     }
 
-    gen_matches! {Cli, OreCommand, Cli::Search, Cli::Plugin, Cli::Install, Cli::Check}
+    gen_matches! {
+        RootCommand,
+        OreCommand,
+        RootCommand::Search,
+        RootCommand::Plugin,
+        RootCommand::Install,
+        RootCommand::Check,
+        RootCommand::WhyOutdated,
+        RootCommand::Reconcile,
+        RootCommand::Identify,
+        RootCommand::Compare,
+        RootCommand::Watch,
+        RootCommand::Owner,
+        RootCommand::Publish,
+        RootCommand::Release,
+        RootCommand::Project,
+        RootCommand::Version,
+        RootCommand::Open,
+        RootCommand::User,
+        RootCommand::Config,
+        RootCommand::Ping,
+        RootCommand::DebugBundle,
+        RootCommand::Prefetch,
+        RootCommand::Cache,
+        RootCommand::Freeze,
+        RootCommand::Manifest,
+        RootCommand::Enable,
+        RootCommand::Disable,
+        RootCommand::Audit,
+        RootCommand::Changelog,
+        RootCommand::Activity,
+        RootCommand::Index,
+        RootCommand::Lock,
+        RootCommand::InitServer,
+        RootCommand::Watchlist
+    }
 }
 
 mod search_command {
@@ -64,13 +258,20 @@ mod search_command {
     use anyhow::Result;
 
     use crate::{
-        commands::core_command::OreCommand,
-        ore::ore_client::OreClient,
-        sponge_schemas::{Category, PaginatedProjectResult, ProjectSortingStrategy},
+        commands::{core_command::OreCommand, install_command::InstallCommand, pagination::follow},
+        tui::browser::browse,
     };
     use async_trait::async_trait;
-    use clap::Parser;
-    use ore_monitor::{query::Query, query_builder};
+    use clap::{Args, Parser, ValueEnum};
+    use ore_monitor::{
+        ore::ore_client::OreClient,
+        query::Query,
+        query_builder,
+        sponge_schemas::{
+            Category, PaginatedProjectResult, Pagination, Project, ProjectColumn,
+            ProjectSortingStrategy,
+        },
+    };
 
     /// Enables the searching of plugins based on a query if provided
     #[derive(Parser, Default)]
@@ -99,12 +300,127 @@ mod search_command {
         #[arg(long)]
         #[clap(default_value_t = 0)]
         offset: u64,
+        /// Re-sorts the fetched plugins locally, explaining each placement
+        #[arg(long)]
+        rank: Option<Ranking>,
+        /// Follows every page from the offset, the limit being the size of each page
+        #[arg(long)]
+        all: bool,
+        /// A comma separated list of the columns to display, ex: plugin_id,downloads,owner,description
+        #[arg(long, visible_alias = "fields", value_delimiter = ',')]
+        columns: Option<Vec<ProjectColumn>>,
+        /// Prints every project's full info, the same as `plugin` does, instead of a table
+        #[arg(long, conflicts_with_all = ["rank", "columns"])]
+        detail: bool,
+        #[command(flatten)]
+        filter: ProjectFilter,
+        /// Browses the results in the terminal, opening a project to list its versions and installing one with `i`
+        #[arg(short, long, conflicts_with_all = ["rank", "all", "columns", "detail", "has_sources", "license", "mc_version"])]
+        interactive: bool,
+        /// How the results are printed, `json` prints the page as Ore returned it
+        #[arg(long, value_enum, default_value_t, conflicts_with_all = ["rank", "columns", "detail", "interactive"])]
+        output: Output,
     }
 
-    #[async_trait]
-    impl OreCommand for SearchCommand {
-        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
-            let query = query_builder!(
+    /// Filters Ore can't search by, so they're applied to the fetched pages
+    #[derive(Args, Default)]
+    struct ProjectFilter {
+        /// Only projects linking to their source code
+        #[arg(long)]
+        has_sources: bool,
+        /// Only projects under a license, ex: `mit`, matched by its name ignoring case
+        #[arg(long)]
+        license: Option<String>,
+        /// Only projects promoting a version for a Minecraft version, ex: `1.12` or `1.12.2`
+        #[arg(long)]
+        mc_version: Option<String>,
+    }
+
+    impl ProjectFilter {
+        fn is_empty(&self) -> bool {
+            !self.has_sources && self.license.is_none() && self.mc_version.is_none()
+        }
+
+        fn matches(&self, project: &Project) -> bool {
+            let sources = !self.has_sources || project.settings.sources().is_some();
+            let license = self.license.as_ref().is_none_or(|wanted| {
+                project
+                    .settings
+                    .license
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.to_lowercase().contains(&wanted.to_lowercase()))
+            });
+            let mc_version = self.mc_version.as_ref().is_none_or(|wanted| {
+                project.minecraft_versions().any(|version| {
+                    version == wanted || version.starts_with(&format!("{}.", wanted))
+                })
+            });
+            sources && license && mc_version
+        }
+    }
+
+    /// How search results are printed
+    #[derive(ValueEnum, Clone, Copy, Default, PartialEq)]
+    enum Output {
+        /// A table of the projects
+        #[default]
+        Text,
+        Json,
+    }
+
+    /// Local sorting strategies, independent of how Ore sorted the results
+    #[derive(ValueEnum, Clone)]
+    enum Ranking {
+        Downloads,
+        Recent,
+        Stars,
+        Updated,
+    }
+
+    impl SearchCommand {
+        /// The results from the offset, following pages while the filter leaves less than a page of them
+        pub(super) async fn results(
+            &self,
+            ore_client: &OreClient,
+            offset: i64,
+        ) -> Result<PaginatedProjectResult> {
+            if self.filter.is_empty() {
+                return match self.all {
+                    true => follow(offset, |offset| ore_client.projects(self.query(offset))).await,
+                    false => ore_client.projects(self.query(offset)).await,
+                };
+            }
+
+            let mut matched = vec![];
+            let mut next = offset;
+            loop {
+                let page = ore_client.projects(self.query(next)).await?;
+                let fetched = page.result.len();
+                next += fetched as i64;
+                matched.extend(page.result.into_iter().filter(|p| self.filter.matches(p)));
+
+                let wanted = self.limit.unwrap_or(page.pagination.limit) as usize;
+                let enough = !self.all && matched.len() >= wanted;
+                if fetched == 0 || next >= page.pagination.count || enough {
+                    if !self.all {
+                        matched.truncate(wanted);
+                    }
+                    let pagination = Pagination {
+                        limit: matched.len() as i64,
+                        offset,
+                        count: matched.len() as i64,
+                    };
+                    return Ok(PaginatedProjectResult {
+                        pagination,
+                        result: matched,
+                    });
+                }
+            }
+        }
+
+        fn query(&self, offset: i64) -> Query {
+            query_builder!(
                 "q" : QueryType::Value(self.search.as_ref()),
                 "categories" : QueryType::Vec(self.category.clone()),
                 "tags" : QueryType::Vec(self.tags.clone()),
@@ -112,15 +428,83 @@ mod search_command {
                 "sort" : QueryType::Value(self.sort.as_ref()),
                 "relevance" : QueryType::Value(self.relevance),
                 "limit" : QueryType::Value(self.limit),
-                "offset" : QueryType::Value(Some(self.offset))
+                "offset" : QueryType::Value(Some(offset))
             )
-            .to_vec();
+        }
+    }
+
+    impl Ranking {
+        fn key(&self, project: &Project) -> i64 {
+            match self {
+                Ranking::Downloads => project.stats.downloads,
+                Ranking::Recent => project.stats.recent_downloads,
+                Ranking::Stars => project.stats.stars,
+                Ranking::Updated => project.last_updated.timestamp(),
+            }
+        }
+
+        fn reason(&self, project: &Project) -> String {
+            match self {
+                Ranking::Downloads => format!("{} downloads", project.stats.downloads),
+                Ranking::Recent => format!("{} recent downloads", project.stats.recent_downloads),
+                Ranking::Stars => format!("{} stars", project.stats.stars),
+                Ranking::Updated => format!("updated {}", project.last_updated),
+            }
+        }
+
+        /// Sorts descending, ties are broken by plugin id so the order is reproducible
+        fn rank(&self, mut projects: Vec<Project>) -> String {
+            projects.sort_by(|a, b| {
+                self.key(b)
+                    .cmp(&self.key(a))
+                    .then_with(|| a.plugin_id.cmp(&b.plugin_id))
+            });
+            projects
+                .iter()
+                .enumerate()
+                .map(|(i, p)| format!("{}. {} - {}", i + 1, p.plugin_id, self.reason(p)))
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for SearchCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let offset = self.offset as i64;
+            if self.interactive {
+                let Some(pick) = browse(&ore_client, |offset| self.query(offset), offset).await?
+                else {
+                    return Ok(());
+                };
+                return InstallCommand::configured(&pick.plugin_id, &pick.version)?
+                    .handle(ore_client, None)
+                    .await;
+            }
+
+            let res = self.results(&ore_client, offset).await?;
 
-            let res = ore_client.get("/projects".to_string(), Some(query)).await?;
+            if self.output == Output::Json {
+                return self.print_res(serde_json::to_string_pretty(&res)?);
+            }
+            if let Some(rank) = &self.rank {
+                return self.print_res(rank.rank(res.result));
+            }
+            if res.result.is_empty() {
+                return self.print_res("No projects found");
+            }
 
-            let res: PaginatedProjectResult = self.serialize(res).await?;
+            if self.detail {
+                let details = res
+                    .result
+                    .iter()
+                    .map(|project| project.as_of(ore_client.clock().now()).to_string())
+                    .collect::<Vec<String>>();
+                return self.print_res(details.join("\n\n"));
+            }
 
-            Ok(self.print_res(res)?)
+            let columns = self.columns.as_deref().unwrap_or(&ProjectColumn::ALL);
+            self.print_res(ProjectColumn::table(columns, &res.result))
         }
     }
 }
@@ -128,41 +512,140 @@ mod search_command {
 mod plugin_command {
     use anyhow::Result;
     use async_trait::async_trait;
+    use chrono::{Duration, NaiveDate};
     use clap::{Parser, Subcommand};
-    use ore_monitor::{plugin_response, query::Query, query_builder};
-    use reqwest::Response;
+    use ore_monitor::{
+        clock::Clock,
+        ore::ore_client::OreClient,
+        query::Query,
+        query_builder,
+        sponge_schemas::{Project, ProjectMember, ProjectStatsDay, VersionStatsDay},
+        table::{Align, Table},
+    };
 
-    use crate::ore::ore_client::OreClient;
-    use crate::sponge_schemas::{PaginatedVersionResult, Project, Version};
+    use std::{collections::BTreeMap, fmt::Display};
 
-    use crate::commands::core_command::OreCommand;
+    use crate::commands::{
+        changelog_command::changelog, core_command::OreCommand, pagination::follow,
+        project_inference::plugin_id_or_detect,
+    };
 
     /// Retreives project information about a plugin
     #[derive(Parser, Default)]
+    #[command(subcommand_precedence_over_arg = true)]
     pub struct PluginCommand {
-        /// The plugin ID to search by
-        plugin_id: String,
+        /// The plugin ID(s) to search by, inferred from the plugin project in the current directory if omitted
+        plugin_ids: Vec<String>,
         /// A Subcommand for displaying versions of the plugin
         #[command(subcommand)]
         versions: Option<PluginSubCommand>,
     }
 
+    impl PluginCommand {
+        /// Fetches each project, keeping the error of any that couldn't be retrieved
+        async fn batch(&self, ore_client: &OreClient, plugin_ids: &[String]) -> ProjectBatch {
+            let responses = ore_client.projects_by_id(plugin_ids.to_vec()).await;
+
+            let mut batch = ProjectBatch::default();
+            for (id, project) in responses {
+                match project {
+                    Ok(project) => batch.projects.push(project),
+                    Err(e) => batch.errors.push((id, e.to_string())),
+                }
+            }
+            batch
+        }
+    }
+
     #[async_trait]
     impl OreCommand for PluginCommand {
         async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let plugin_ids = match self.plugin_ids.is_empty() {
+                true => vec![plugin_id_or_detect(None)?],
+                false => self.plugin_ids.clone(),
+            };
+
+            let [plugin_id] = plugin_ids.as_slice() else {
+                if self.versions.is_some() {
+                    return Err(anyhow::Error::msg(
+                        "Versions can only be displayed for a single plugin id",
+                    ));
+                }
+                let batch = self.batch(&ore_client, &plugin_ids).await;
+                return self.print_res(batch);
+            };
+
             let query = query_builder!(
-                "plugin_id" : QueryType::Value(Some(&self.plugin_id)),
+                "plugin_id" : QueryType::Value(Some(plugin_id)),
             );
 
             if let Some(ver) = &self.versions {
-                return Ok(ver.handle(ore_client, Some(query)).await?);
+                return ver.handle(ore_client, Some(query)).await;
             }
 
-            let res: Response = plugin_response!(query.get_query("plugin_id"), &ore_client).await?;
+            let res = ore_client.project(&query.get_query("plugin_id")).await?;
+
+            Ok(self.print_res(res.as_of(ore_client.clock().now()))?)
+        }
+    }
+
+    /// The projects of several plugin ids, and the ids that failed
+    #[derive(Default)]
+    struct ProjectBatch {
+        projects: Vec<Project>,
+        errors: Vec<(String, String)>,
+    }
+
+    impl Display for ProjectBatch {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(
+                f,
+                "{:<24} {:<20} {:>10} {:>6}  {:<12} Promoted Versions",
+                "Plugin ID", "Author", "Downloads", "Stars", "Last Updated"
+            )?;
+            for project in &self.projects {
+                writeln!(
+                    f,
+                    "{:<24} {:<20} {:>10} {:>6}  {:<12} {}",
+                    project.plugin_id,
+                    project.namespace.owner,
+                    project.stats.downloads,
+                    project.stats.stars,
+                    project.last_updated.format("%Y-%m-%d"),
+                    project
+                        .promoted_versions
+                        .iter()
+                        .map(|v| v.version.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )?;
+            }
+            if !self.errors.is_empty() {
+                writeln!(f, "\nErrors :")?;
+            }
+            self.errors
+                .iter()
+                .try_for_each(|(id, e)| writeln!(f, "{} : {}", id, e))
+        }
+    }
 
-            let res: Project = self.serialize(res).await?;
+    /// The members of a project, listed with their roles
+    pub(super) struct ProjectMembers(pub(super) Vec<ProjectMember>);
 
-            Ok(self.print_res(res)?)
+    impl Display for ProjectMembers {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.0.is_empty() {
+                return write!(f, "The project has no members");
+            }
+            let mut table = Table::new(vec![("User", Align::Left), ("Roles", Align::Left)]);
+            for member in &self.0 {
+                let roles = member.roles.iter().map(|role| role.title.as_str());
+                table.row(vec![
+                    member.user.to_string(),
+                    roles.collect::<Vec<&str>>().join(", "),
+                ]);
+            }
+            write!(f, "{}", table)
         }
     }
 
@@ -171,6 +654,113 @@ mod plugin_command {
     enum PluginSubCommand {
         /// Shows a list of available versions
         Versions(PluginVersionCommand),
+        /// Stars the plugin with the account the API key belongs to
+        Star,
+        /// Watches the plugin with the account the API key belongs to
+        Watch,
+        /// Lists the members of the project and their roles, to know who to contact about it
+        Members,
+        /// Shows the downloads and views of each day within a range
+        Stats(PluginStatsCommand),
+    }
+
+    /// A subcommand of [PluginCommand] that shows the daily stats of the project
+    #[derive(Parser)]
+    struct PluginStatsCommand {
+        /// The first day, ex: 2024-05-01, 30 days before the last when omitted
+        #[arg(long)]
+        from: Option<NaiveDate>,
+        /// The last day, ex: 2024-05-31, today when omitted
+        #[arg(long)]
+        to: Option<NaiveDate>,
+        /// Prints the days as CSV, to be graphed elsewhere
+        #[arg(long)]
+        csv: bool,
+    }
+
+    impl PluginStatsCommand {
+        /// How many days are shown when the first isn't given
+        const DEFAULT_DAYS: i64 = 30;
+
+        /// The days shown, ending today by the clock unless given
+        fn range(&self, clock: &dyn Clock) -> Result<(NaiveDate, NaiveDate)> {
+            let to = self.to.unwrap_or(clock.now().date_naive());
+            let from = self.from.unwrap_or(to - Duration::days(Self::DEFAULT_DAYS));
+            if from > to {
+                return Err(anyhow::Error::msg(format!(
+                    "The range starts after it ends : {} to {}",
+                    from, to
+                )));
+            }
+            Ok((from, to))
+        }
+    }
+
+    /// The stats of each day of a project
+    pub(super) struct ProjectStats(pub(super) BTreeMap<NaiveDate, ProjectStatsDay>);
+
+    impl ProjectStats {
+        pub(super) fn csv(&self) -> String {
+            let mut csv = String::from("date,downloads,views\n");
+            for (day, stats) in &self.0 {
+                csv.push_str(&format!("{},{},{}\n", day, stats.downloads, stats.views));
+            }
+            csv
+        }
+    }
+
+    impl Display for ProjectStats {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut table = Table::new(vec![
+                ("Date", Align::Left),
+                ("Downloads", Align::Right),
+                ("Views", Align::Right),
+            ]);
+            for (day, stats) in &self.0 {
+                table.row(vec![
+                    day.to_string(),
+                    stats.downloads.to_string(),
+                    stats.views.to_string(),
+                ]);
+            }
+            let downloads: i64 = self.0.values().map(|stats| stats.downloads).sum();
+            let views: i64 = self.0.values().map(|stats| stats.views).sum();
+            table.row(vec![
+                "Total".to_string(),
+                downloads.to_string(),
+                views.to_string(),
+            ]);
+            write!(f, "{}", table)
+        }
+    }
+
+    /// A user action that can be applied to a project
+    enum UserAction {
+        Star,
+        Watch,
+    }
+
+    impl UserAction {
+        /// Permission the API key needs for acting on behalf of its user
+        const PERMISSION: &'static str = "edit_own_user_settings";
+
+        /// Applies the action, if the API key is permitted to
+        async fn apply(&self, ore_client: &OreClient, plugin_id: &str) -> Result<()> {
+            let query = query_builder!("pluginId" : QueryType::Value(Some(plugin_id)));
+            let permissions = ore_client.permissions(query).await?;
+
+            if !permissions.has(Self::PERMISSION) {
+                return Err(anyhow::Error::msg(format!(
+                    "The API key requires the '{}' permission",
+                    Self::PERMISSION
+                )));
+            }
+
+            match self {
+                UserAction::Star => ore_client.star(plugin_id).await,
+                UserAction::Watch => ore_client.watch(plugin_id).await,
+            }
+        }
     }
 
     /// A subcommand of [PluginCommand] that shows all available versions
@@ -187,217 +777,5911 @@ mod plugin_command {
         /// Where to begin display the list from
         #[arg(long)]
         offset: Option<i64>,
+        /// Follows every page from the offset, the limit being the size of each page
+        #[arg(long, conflicts_with = "name")]
+        all: bool,
+        /// Prints the description of the version as written, instead of rendering its markdown
+        #[arg(long, requires = "name")]
+        raw: bool,
+        /// A subcommand for the named version
+        #[command(subcommand)]
+        action: Option<VersionSubCommand>,
+    }
+
+    /// Represents subcommands of [PluginVersionCommand], acting on a single version
+    #[derive(Subcommand)]
+    enum VersionSubCommand {
+        /// Shows the downloads of each of the last days
+        Stats {
+            /// How many days are shown, ending today
+            #[arg(long, default_value_t = 30)]
+            days: i64,
+        },
+    }
+
+    /// The downloads of each day of a version
+    pub(super) struct VersionStats(pub(super) BTreeMap<NaiveDate, VersionStatsDay>);
+
+    impl Display for VersionStats {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut table = Table::new(vec![("Date", Align::Left), ("Downloads", Align::Right)]);
+            for (day, stats) in &self.0 {
+                table.row(vec![day.to_string(), stats.downloads.to_string()]);
+            }
+            let downloads: i64 = self.0.values().map(|stats| stats.downloads).sum();
+            table.row(vec!["Total".to_string(), downloads.to_string()]);
+            write!(f, "{}", table)
+        }
     }
 
     #[async_trait]
     impl OreCommand for PluginSubCommand {
         async fn handle(&self, ore_client: OreClient, link_query: Option<Query>) -> Result<()> {
-            let Self::Versions(cmd) = self;
+            let plugin_id = link_query.unwrap().get_query("plugin_id");
 
-            let query = query_builder!(
-                "tags" : QueryType::Vec(cmd.tags.clone()),
-                "limit" : QueryType::Value(cmd.limit),
-                "offset" : QueryType::Value(cmd.offset)
-            )
-            .to_vec();
+            let cmd = match self {
+                Self::Versions(cmd) => cmd,
+                Self::Star => {
+                    return self
+                        .user_action(ore_client, UserAction::Star, plugin_id)
+                        .await
+                }
+                Self::Watch => {
+                    return self
+                        .user_action(ore_client, UserAction::Watch, plugin_id)
+                        .await
+                }
+                Self::Members => {
+                    let members = ore_client.members(&plugin_id).await?;
+                    return self.print_res(ProjectMembers(members));
+                }
+                Self::Stats(cmd) => {
+                    let (from, to) = cmd.range(ore_client.clock())?;
+                    let query = query_builder!(
+                        "fromDate" : QueryType::Value(Some(from)),
+                        "toDate" : QueryType::Value(Some(to))
+                    );
+                    let stats = ProjectStats(ore_client.project_stats(&plugin_id, query).await?);
+                    return match cmd.csv {
+                        true => {
+                            print!("{}", stats.csv());
+                            Ok(())
+                        }
+                        false => self.print_res(stats),
+                    };
+                }
+            };
 
-            let link = format!(
-                "/projects/{}/versions",
-                link_query.unwrap().get_query("plugin_id")
-            );
+            let query = |offset: Option<i64>| {
+                query_builder!(
+                    "tags" : QueryType::Vec(cmd.tags.clone()),
+                    "limit" : QueryType::Value(cmd.limit),
+                    "offset" : QueryType::Value(offset)
+                )
+            };
+
+            if let Some(VersionSubCommand::Stats { days }) = &cmd.action {
+                let Some(name) = &cmd.name else {
+                    return Err(anyhow::Error::msg(
+                        "Stats can only be displayed for a named version",
+                    ));
+                };
+                if *days < 1 {
+                    return Err(anyhow::Error::msg("At least one day must be shown"));
+                }
+                let to = ore_client.clock().now().date_naive();
+                let query = query_builder!(
+                    "fromDate" : QueryType::Value(Some(to - Duration::days(days - 1))),
+                    "toDate" : QueryType::Value(Some(to))
+                );
+                let stats = ore_client.version_stats(&plugin_id, name, query).await?;
+                return self.print_res(VersionStats(stats));
+            }
+
+            if cmd.all {
+                let res = follow(cmd.offset.unwrap_or_default(), |offset| {
+                    ore_client.versions(&plugin_id, query(Some(offset)))
+                })
+                .await?;
+                return self.print_res(res.as_of(ore_client.clock().now()));
+            }
 
             if let Some(name) = &cmd.name {
-                let link = format!("{}/{}", link, name);
-                let res = ore_client.get(link, Some(query)).await?;
-                let res: Version = self.serialize(res).await?;
-                return Ok(self.print_res(res)?);
+                let res = ore_client.version(&plugin_id, name).await?;
+                self.print_res(res.as_of(ore_client.clock().now()))?;
+                return self.print_res(changelog(res.description(), cmd.raw));
             }
 
-            let res = ore_client.get(link, Some(query)).await?;
-            let res: PaginatedVersionResult = self.serialize(res).await?;
+            let res = ore_client.versions(&plugin_id, query(cmd.offset)).await?;
 
-            return Ok(self.print_res(res)?);
+            return self.print_res(res.as_of(ore_client.clock().now()));
         }
     }
-}
 
-mod install_command {
-    use std::{io::Cursor, path::PathBuf};
+    impl PluginSubCommand {
+        /// Applies the action, then displays the resulting state of the project
+        async fn user_action(
+            &self,
+            ore_client: OreClient,
+            action: UserAction,
+            plugin_id: String,
+        ) -> Result<()> {
+            action.apply(&ore_client, &plugin_id).await?;
+
+            let project = ore_client.project(&plugin_id).await?;
+            self.print_res(project.user_actions)
+        }
+    }
+}
 
+mod changelog_command {
     use anyhow::Result;
     use async_trait::async_trait;
     use clap::Parser;
-    use ore_monitor::{plugin_response, query::Query};
-    use reqwest::StatusCode;
-
-    use crate::{ore::ore_client::OreClient, sponge_schemas::Project};
+    use ore_monitor::{markdown::render, ore::ore_client::OreClient, query::Query, query_builder};
+    use ore_monitor_common::version_status::VersionStatus;
 
-    use crate::commands::core_command::OreCommand;
+    use super::{core_command::OreCommand, project_inference::plugin_id_or_detect};
 
-    /// A command to Install plugins
+    /// Shows the changelogs of a plugin's latest versions, newest first
     #[derive(Parser, Default)]
-    pub struct InstallCommand {
-        /// Directory to install into
-        #[arg(short, long)]
-        dir: Option<PathBuf>,
-        /// The plugin id to install
-        plugin_id: String,
-        /// The version to install
-        version: String,
+    pub struct ChangelogCommand {
+        /// The plugin id, inferred from the plugin project in the current directory if omitted
+        plugin_id: Option<String>,
+        /// Only shows versions newer than this one, ex: the installed version
+        #[arg(long)]
+        since: Option<String>,
+        /// How many of the latest versions are looked at
+        #[arg(short, long, default_value_t = 5)]
+        limit: i64,
+        /// Prints the descriptions as written, instead of rendering their markdown
+        #[arg(long)]
+        raw: bool,
     }
 
-    impl InstallCommand {
-        const DEFAULT_FILE_NAME: &'static str = "unknown_file";
-        pub fn extract_filename(headers: &str) -> Option<&str> {
-            let start = headers.find('"')?;
-            let end = headers.rfind('"')?;
-            (start != end).then_some(&headers[start + 1..end])
+    /// The description of a version, rendered for the terminal unless raw
+    pub(super) fn changelog(description: Option<&str>, raw: bool) -> String {
+        match description {
+            Some(description) if raw => description.to_string(),
+            Some(description) => render(description),
+            None => "No changelog".to_string(),
         }
     }
 
     #[async_trait]
-    impl OreCommand for InstallCommand {
+    impl OreCommand for ChangelogCommand {
         async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
-            // This whole command is basically a workaround for the API not having a download link available
-            // This response allows me to generate the owner:slug information for a valid link to download
-            let res = plugin_response!(self.plugin_id, &ore_client).await?;
-
-            let proj: Project = self.serialize(res).await?;
-
-            // This is a link for the main website, in the same way users would
-            // retrieve a file.
-            let link = format!(
-                "/{}/{}/versions/{}/download",
-                proj.namespace.owner, proj.namespace.slug, self.version
-            );
+            let plugin_id = plugin_id_or_detect(self.plugin_id.as_deref())?;
+            let query = query_builder!("limit" : QueryType::Value(Some(self.limit)));
+            let versions = ore_client.versions(&plugin_id, query).await?.result;
 
-            // get_install uses a modified base_url to function
-            let res = ore_client.get_install(link, None).await?;
+            let changelogs = versions
+                .iter()
+                .filter(|version| match &self.since {
+                    Some(since) => {
+                        VersionStatus::new(since, &version.name) == VersionStatus::OutOfDate
+                    }
+                    None => true,
+                })
+                .map(|version| {
+                    format!(
+                        "{:=^45}\n{}\n",
+                        format!("[{}]", version.name),
+                        changelog(version.description(), self.raw)
+                    )
+                })
+                .collect::<Vec<String>>();
 
-            // Proper error handling is needed here
-            // should probably check for a successful status code instead
-            if res.status() == StatusCode::NOT_FOUND {
-                return Err(anyhow::Error::msg(
-                    "Resource not available, ensure you're using a valid ID & Version!",
-                ));
+            if changelogs.is_empty() {
+                return self.print_res(format!("No newer versions of {} found", plugin_id));
             }
-
-            // Because we don't install from the API, we have to retrieve the file name from where available.
-            let file_name = res
-                .headers()
-                .get(reqwest::header::CONTENT_DISPOSITION)
-                .and_then(|s| Some(s.to_str()))
-                .and_then(|f| Some(f.unwrap_or(Self::DEFAULT_FILE_NAME)))
-                .and_then(|header| Self::extract_filename(header))
-                .unwrap_or(Self::DEFAULT_FILE_NAME);
-
-            let dir = self
-                .dir
-                .as_deref()
-                .and_then(|f| Some(f.display()))
-                .and_then(|f| Some(f.to_string()))
-                .unwrap_or(".".to_string());
-
-            let message = format!("Installed '{}' into '{}'", file_name, dir);
-
-            let dir = dir + file_name;
-
-            let mut file = std::fs::File::create(&dir)?;
-            let mut content = Cursor::new(res.bytes().await?);
-
-            std::io::copy(&mut content, &mut file)?;
-
-            Ok(println!("{}", message))
+            self.print_res(changelogs.join("\n"))
         }
     }
 }
 
-mod version_check_command {
+mod activity_command {
     use anyhow::Result;
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use clap::Parser;
-    use ore_monitor::{file_reader::FileReader, ore_mod_info::OreModInfo, query::Query};
-    use ore_monitor_common::version_status::VersionStatus;
-    use std::{fmt::Display, ops::Deref, path::PathBuf};
-    use tokio_stream::StreamExt;
+    use futures::{stream, StreamExt};
+    use ore_monitor::{
+        activity_log::{ActivityLog, ObservedChange},
+        clock::{ago, Clock},
+        duration::parse_duration,
+        ore::ore_client::OreClient,
+        query::Query,
+        query_builder,
+        scan_filter::ScanFilter,
+        source::Source,
+    };
+    use std::{
+        collections::BTreeSet,
+        fmt::Display,
+        path::{Path, PathBuf},
+        time::Duration,
+    };
+    use tokio::task;
+    use tracing::warn;
 
-    use crate::{ore::ore_client::OreClient, sponge_schemas::Project};
+    use crate::{
+        config::ore_config::Config,
+        notify::notification::{Notification, Notifier, WebhookSink},
+    };
 
-    use super::core_command::OreCommand;
+    use super::{
+        core_command::OreCommand,
+        version_check_command::{scan, ScanArgs},
+    };
 
+    /// Reports which tracked plugins released versions, changed promoted versions or descriptions recently
     #[derive(Parser, Default)]
-    pub struct VersionCheckCommand {
-        /// path to file(s) to check otherwise checks where it was ran from
-        #[clap(default_value = ".")]
-        file: PathBuf,
+    pub struct ActivityCommand {
+        /// A file or directory of jars whose plugins are tracked, otherwise the configured plugins directory or where it was ran from
+        path: Option<PathBuf>,
+        /// How far back to report, ex: 24h, 7d
+        #[arg(long, default_value = "7d", value_parser = parse_duration)]
+        since: Duration,
+        /// Another plugin id to track, can be repeated
+        #[arg(short, long)]
+        plugin: Vec<String>,
+        #[command(flatten)]
+        scan: ScanArgs,
+        /// Sends the digest to the configured webhook as well
+        #[arg(long)]
+        notify: bool,
     }
 
-    #[async_trait]
-    impl OreCommand for VersionCheckCommand {
-        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
-            let files = {
-                let reader = FileReader::from(self.file.deref());
+    impl ActivityCommand {
+        pub fn apply_config(&mut self, config: &Config) {
+            self.path = self.path.take().or(config.plugins_dir.clone());
+        }
+    }
 
-                match self {
-                    _ if self.file.is_dir() => Some(reader.handle_dir()?),
-                    _ if self.file.is_file() => Some(vec![reader.handle_file(None)?]),
-                    __ => None,
-                }
-                .unwrap_or_default()
-            };
+    /// The Ore ids of the plugins installed at the path that come from Ore, along with the extra ids
+    pub(super) async fn tracked_ids(
+        path: &Path,
+        filter: ScanFilter,
+        extra: &[String],
+    ) -> Result<Vec<String>> {
+        let config = Config::load()?;
+        let scanned = path.to_path_buf();
+        let files = task::spawn_blocking(move || scan(&scanned, &filter)).await??;
+
+        let mut ids = files
+            .iter()
+            .map(|(_, info)| config.plugin_source(&info.modid))
+            .zip(&files)
+            .filter(|(source, _)| source.source == Source::Ore)
+            .map(|(source, (_, info))| source.id_or(&info.modid).to_string())
+            .collect::<BTreeSet<String>>();
+        ids.extend(extra.iter().cloned());
+        Ok(ids.into_iter().collect())
+    }
 
-            let projects = {
-                let names = files.iter().map(|f| f.modid.deref().to_string()).collect();
+    /// What a project did within the window
+    struct ProjectActivity {
+        plugin_id: String,
+        /// Names of the versions created within the window, and when
+        released: Vec<(String, DateTime<Utc>)>,
+        changes: Vec<ObservedChange>,
+    }
 
-                let responses = ore_client.plugin_responses(names).await?;
-                let mut responses = tokio_stream::iter(responses);
+    /// The activity of every tracked plugin within the window
+    pub(super) struct Digest {
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+        active: Vec<ProjectActivity>,
+        quiet: Vec<String>,
+        errors: Vec<(String, String)>,
+    }
 
-                let mut projects: Vec<Project> = vec![];
-                while let Some(v) = responses.next().await {
-                    let info = self.serialize_str(&v).await?;
-                    projects.push(info)
-                }
-                projects
-            };
+    impl Digest {
+        /// How many of the latest versions are looked at for releases
+        const VERSION_LIMIT: i64 = 25;
 
-            let checklist = files
+        /// Looks up each plugin, recording its promoted versions and description in the [ActivityLog]
+        pub(super) async fn new(
+            ore_client: &OreClient,
+            plugin_ids: Vec<String>,
+            window: Duration,
+            clock: &dyn Clock,
+        ) -> Result<Digest> {
+            let now = clock.now();
+            let since = now - chrono::Duration::from_std(window)?;
+
+            let lookups = plugin_ids
                 .into_iter()
-                .zip(projects)
-                .map(|vers: (OreModInfo, Project)| VersionDisplay::new(vers).to_string())
-                .collect::<Vec<String>>()
-                .join("\n");
+                .map(|id| async {
+                    let query =
+                        query_builder!("limit" : QueryType::Value(Some(Self::VERSION_LIMIT)));
+                    let project = ore_client.project(&id).await;
+                    let versions = ore_client.versions(&id, query).await;
+                    (id, project, versions)
+                })
+                .collect::<Vec<_>>();
+            let responses = stream::iter(lookups)
+                .buffered(ore_client.concurrency())
+                .collect::<Vec<_>>()
+                .await;
+
+            let mut log = ActivityLog::load();
+            let mut digest = Digest {
+                since,
+                now,
+                active: vec![],
+                quiet: vec![],
+                errors: vec![],
+            };
+            for (plugin_id, project, versions) in responses {
+                let (project, versions) = match (project, versions) {
+                    (Ok(project), Ok(versions)) => (project, versions),
+                    (Err(e), _) | (_, Err(e)) => {
+                        digest.errors.push((plugin_id, e.to_string()));
+                        continue;
+                    }
+                };
+
+                let promoted = project
+                    .promoted_versions
+                    .iter()
+                    .map(|v| v.version.to_string())
+                    .collect::<Vec<String>>();
+                log.observe(
+                    &plugin_id,
+                    &promoted,
+                    project.description(),
+                    project.last_updated,
+                    now,
+                );
+
+                let released = versions
+                    .result
+                    .into_iter()
+                    .filter(|version| version.created_at >= since)
+                    .map(|version| (version.name, version.created_at))
+                    .collect::<Vec<_>>();
+                let changes = log
+                    .changes_since(&plugin_id, since)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                match released.is_empty() && changes.is_empty() {
+                    true => digest.quiet.push(plugin_id),
+                    false => digest.active.push(ProjectActivity {
+                        plugin_id,
+                        released,
+                        changes,
+                    }),
+                }
+            }
 
-            self.print_res(checklist)
+            // The log only makes later digests more precise, failing to save it isn't worth failing this one
+            if let Err(e) = log.save(now) {
+                warn!("Unable to save the activity log : {}", e)
+            }
+            Ok(digest)
         }
-    }
 
-    struct VersionDisplay {
-        id: String,
-        local_version: String,
-        remote_version: String,
-        status: VersionStatus,
+        pub(super) fn notification(&self) -> Notification {
+            Notification::new("Plugin activity", self.to_string())
+        }
     }
 
-    impl VersionDisplay {
-        fn new((local, remote): (OreModInfo, Project)) -> VersionDisplay {
-            let sponge_tag = local.major_api_version;
-            let remote = remote.version_from_tag(sponge_tag).to_string();
-            let status = VersionStatus::new(&local.version, &remote);
-            Self {
+    impl Display for Digest {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(
+                f,
+                "Activity since {} ({} of {} plugins active)",
+                self.since.format("%Y-%m-%d %H:%M"),
+                self.active.len(),
+                self.active.len() + self.quiet.len()
+            )?;
+            for activity in &self.active {
+                writeln!(f, "{}", activity.plugin_id)?;
+                for (name, created_at) in &activity.released {
+                    writeln!(f, "  Released {} ({})", name, ago(*created_at, self.now))?;
+                }
+                for change in &activity.changes {
+                    writeln!(f, "  {} ({})", change.kind, ago(change.at, self.now))?;
+                }
+            }
+            if !self.quiet.is_empty() {
+                writeln!(f, "No activity : {}", self.quiet.join(", "))?;
+            }
+            if !self.errors.is_empty() {
+                writeln!(f, "\nErrors :")?;
+            }
+            self.errors
+                .iter()
+                .try_for_each(|(id, e)| writeln!(f, "{} : {}", id, e))
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for ActivityCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let path = self.path.clone().unwrap_or(PathBuf::from("."));
+            let ids = tracked_ids(&path, self.scan.filter(), &self.plugin).await?;
+            if ids.is_empty() {
+                return Err(anyhow::Error::msg(format!(
+                    "No plugins to track in '{}', provide a path or plugin ids",
+                    path.display()
+                )));
+            }
+
+            let digest = Digest::new(&ore_client, ids, self.since, ore_client.clock()).await?;
+
+            if self.notify {
+                let config = Config::load()?;
+                let url = config.notify.webhook_url.ok_or_else(|| {
+                    anyhow::Error::msg("No webhook configured, set `notify.webhook_url`")
+                })?;
+                // The digest is printed below, so it's only sent to the webhook
+                let sink = WebhookSink::new(ore_client.http().clone(), url, config.notify.format);
+                Notifier::silent()
+                    .with_sink(sink)
+                    .notify(&digest.notification())
+                    .await;
+            }
+            self.print_res(digest)
+        }
+    }
+}
+
+mod index_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{
+        ore::ore_client::OreClient,
+        project_index::{IndexedProject, ProjectIndex},
+        query::Query,
+        query_builder,
+        sponge_schemas::ProjectSortingStrategy,
+        table::{Align, Table},
+    };
+
+    use super::core_command::OreCommand;
+
+    /// Keeps a local catalog of Ore's projects, searchable without asking Ore
+    #[derive(Parser)]
+    pub struct IndexCommand {
+        #[command(subcommand)]
+        command: IndexSubCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum IndexSubCommand {
+        /// Fetches the projects updated since the last update, unless the index is still fresh
+        Update(IndexUpdateCommand),
+        /// Searches the indexed projects by plugin id, name or owner
+        Search(IndexSearchCommand),
+    }
+
+    #[derive(Parser)]
+    struct IndexUpdateCommand {
+        /// Updates even when Ore's Cache-Control says the index is still fresh
+        #[arg(long)]
+        force: bool,
+        /// Rebuilds the index from every project, instead of only those updated since
+        #[arg(long)]
+        full: bool,
+    }
+
+    #[derive(Parser)]
+    struct IndexSearchCommand {
+        term: String,
+    }
+
+    impl IndexCommand {
+        /// Searching only reads the index, so it runs without a session
+        pub fn is_offline(&self) -> bool {
+            matches!(self.command, IndexSubCommand::Search(_))
+        }
+    }
+
+    impl IndexUpdateCommand {
+        /// Projects requested per page
+        const PAGE_SIZE: i64 = 25;
+
+        /// Pages through the most recently updated projects until reaching those already indexed
+        async fn update(&self, ore_client: &OreClient) -> Result<String> {
+            let now = ore_client.clock().now();
+            let mut index = match self.full {
+                true => ProjectIndex::default(),
+                false => ProjectIndex::load(),
+            };
+            if !self.force && !self.full && index.is_fresh(now) {
+                return Ok(format!(
+                    "The index of {} projects is still fresh, --force updates it anyway",
+                    index.len()
+                ));
+            }
+
+            let since = index.newest();
+            let mut offset = 0;
+            let mut max_age = None;
+            loop {
+                let query = query_builder!(
+                    "sort" : QueryType::Value(Some(ProjectSortingStrategy::Updated)),
+                    "limit" : QueryType::Value(Some(Self::PAGE_SIZE)),
+                    "offset" : QueryType::Value(Some(offset))
+                );
+                let (page, page_max_age) = ore_client.projects_page(query).await?;
+                // The first page is the one reused until it expires
+                if offset == 0 {
+                    max_age = page_max_age;
+                }
+
+                let count = page.pagination.count;
+                let fetched = page.result.len() as i64;
+                let page = page.result.into_iter().map(IndexedProject::from).collect();
+                let reached = index.merge(page, since);
+                offset += fetched;
+                if reached || fetched == 0 || offset >= count {
+                    break;
+                }
+            }
+
+            index.set_max_age(now, max_age);
+            index.save()?;
+            Ok(format!(
+                "Fetched {} projects, {} are indexed",
+                offset,
+                index.len()
+            ))
+        }
+    }
+
+    impl IndexSearchCommand {
+        fn search(&self) -> Result<String> {
+            let index = ProjectIndex::load();
+            if index.is_empty() {
+                return Err(anyhow::Error::msg(
+                    "The index is empty, build it with `index update`",
+                ));
+            }
+
+            let found = index.search(&self.term);
+            if found.is_empty() {
+                return Ok(format!("No indexed projects match '{}'", self.term));
+            }
+            let mut table = Table::new(vec![
+                ("Plugin ID", Align::Left),
+                ("Owner", Align::Left),
+                ("Category", Align::Left),
+                ("Downloads", Align::Right),
+                ("Stars", Align::Right),
+                ("Last Updated", Align::Left),
+            ]);
+            for project in found {
+                table.row(vec![
+                    project.plugin_id.to_string(),
+                    project.owner.to_string(),
+                    project.category.to_string(),
+                    project.downloads.to_string(),
+                    project.stars.to_string(),
+                    project.last_updated.format("%Y-%m-%d").to_string(),
+                ]);
+            }
+            Ok(table.to_string())
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for IndexCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let message = match &self.command {
+                IndexSubCommand::Update(cmd) => cmd.update(&ore_client).await?,
+                IndexSubCommand::Search(cmd) => cmd.search()?,
+            };
+            self.print_res(message)
+        }
+    }
+}
+
+mod install_command {
+    use std::{
+        collections::HashMap,
+        fs::{self, File},
+        io::{self, IsTerminal, Write},
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use futures::future::BoxFuture;
+    use human_bytes::human_bytes;
+    use md5::{Digest, Md5};
+    use ore_monitor::{
+        clock::Clock,
+        conflict::{Conflict, Resolution},
+        dependency_tree::{Dependency, DependencyStatus, DependencyTree},
+        discard::discard,
+        file_hash::{md5_hex, sha512_hex},
+        file_naming::{extract_filename, NameTemplate},
+        lockfile::{LockedPlugin, Lockfile, Provenance},
+        manifest::Manifest,
+        ore::ore_client::OreClient,
+        ore_mod_info::{OreModInfo, PluginDependency},
+        quarantine::Quarantine,
+        query::Query,
+        scan_filter::ScanFilter,
+        source::Source,
+        sponge_schemas::{Project, Version, VersionDependency},
+        update_order::{is_range, newest, satisfies},
+    };
+    use reqwest::Response;
+    use tracing::{error, info, warn, Level};
+
+    use crate::{
+        config::ore_config::Config,
+        modrinth::modrinth_client::ModrinthClient,
+        notify::notification::Notifier,
+        verify::jar_verification::{compare_or_quarantine, Verification},
+    };
+
+    use crate::commands::{
+        core_command::OreCommand, pagination::all_versions, prompt::ask,
+        version_check_command::scan,
+    };
+
+    /// A command to Install plugins
+    #[derive(Parser, Default)]
+    pub struct InstallCommand {
+        /// Directory to install into, otherwise the configured install or plugins directory
+        #[arg(short, long)]
+        dir: Option<PathBuf>,
+        /// How to name the installed file, supports `{id}`, `{version}` and `{file}`
+        #[arg(short, long)]
+        name_template: Option<NameTemplate>,
+        /// The plugin id to install
+        #[arg(required_unless_present = "manifest")]
+        plugin_id: Option<String>,
+        /// The version to install, the newest when omitted or `latest`, or the newest within a range such as `[2.0,3.0)`
+        version: Option<String>,
+        /// Installs every plugin listed in a manifest file instead, ex: `plugins.toml`
+        #[arg(long, conflicts_with_all = ["plugin_id", "version", "api"])]
+        manifest: Option<PathBuf>,
+        /// Only picks the newest version among those for this Sponge API major version, ex: `8`
+        #[arg(long)]
+        api: Option<u32>,
+        /// The file to install when the version has several, by a part of its name, ex: `api8`
+        /// Ore versions hold a single file, each API is picked with --api instead.
+        #[arg(long, conflicts_with = "manifest")]
+        file: Option<String>,
+        /// Installs the download without comparing it to the md5 published on Ore
+        #[arg(long)]
+        no_verify: bool,
+        /// Also installs the dependencies that are missing from the directory, otherwise they're only reported
+        #[arg(long, conflicts_with = "manifest")]
+        with_dependencies: bool,
+        /// Updates a plugin even though its Ore project now belongs to another owner than the one it was locked from
+        #[arg(long)]
+        accept_new_owner: bool,
+        /// How to resolve a plugin whose jar disagrees with the lockfile, otherwise asked for each
+        #[arg(long, requires = "manifest")]
+        strategy: Option<Resolution>,
+        /// Deletes the jars the manifest's versions replace, instead of moving them to the trash
+        #[arg(long, requires = "manifest")]
+        permanent: bool,
+        /// The id on the source, when it was resolved rather than configured
+        #[arg(skip)]
+        remote_id: Option<String>,
+    }
+
+    impl InstallCommand {
+        const DEFAULT_FILE_NAME: &'static str = "unknown_file";
+        const LATEST: &'static str = "latest";
+
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            Some(self.dir().to_path_buf())
+        }
+
+        fn dir(&self) -> &Path {
+            self.dir.as_deref().unwrap_or(Path::new("."))
+        }
+
+        fn plugin_id(&self) -> &str {
+            self.plugin_id.as_deref().unwrap_or_default()
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            self.dir = self
+                .dir
+                .take()
+                .or(config.install_dir.clone())
+                .or(config.plugins_dir.clone());
+        }
+
+        fn file_name(&self, version: &str, file_name: &str) -> String {
+            self.name_template.clone().unwrap_or_default().render(
+                self.plugin_id(),
+                version,
+                file_name,
+            )
+        }
+
+        /// The exact version asked for, none when the newest should be installed
+        fn requested_version(&self) -> Option<&str> {
+            self.version
+                .as_deref()
+                .filter(|version| !version.eq_ignore_ascii_case(Self::LATEST))
+                .filter(|version| !is_range(version))
+        }
+
+        /// Whether the version falls within the range asked for, any version does without one
+        fn in_range(&self, version: &str) -> bool {
+            self.version
+                .as_deref()
+                .filter(|range| is_range(range))
+                .is_none_or(|range| satisfies(version, range))
+        }
+
+        /// The file the version was already installed as, when it's still intact
+        fn existing(&self, version: &str) -> Result<Option<PathBuf>> {
+            let lockfile = Lockfile::load_or_default(self.dir())?;
+            let Some(locked) = lockfile
+                .get(self.plugin_id())
+                .filter(|locked| locked.version == version)
+            else {
+                return Ok(None);
+            };
+            let Some(path) = locked
+                .file
+                .as_ref()
+                .map(|file| self.dir().join(file))
+                .filter(|path| path.is_file())
+            else {
+                return Ok(None);
+            };
+            let intact = locked.md5.as_deref() == Some(md5_hex(&path)?.as_str());
+            Ok(intact.then_some(path))
+        }
+
+        /// The version to install from Ore, the newest matching the API version unless one was asked for
+        async fn ore_version(&self, ore_client: &OreClient, ore_id: &str) -> Result<String> {
+            if let Some(version) = self.requested_version() {
+                return Ok(version.to_string());
+            }
+
+            let versions = all_versions(ore_client, ore_id).await?;
+            let matching = versions
+                .iter()
+                .filter(|version| self.api.is_none_or(|api| version.sponge_major() == api))
+                .map(|version| version.name.as_str())
+                .filter(|version| self.in_range(version));
+
+            newest(matching).map(str::to_string).ok_or_else(|| {
+                anyhow::Error::msg(match self.api {
+                    Some(api) => format!("{} has no matching version for API {}", ore_id, api),
+                    None => format!("{} has no matching versions", ore_id),
+                })
+            })
+        }
+
+        /// Records the installed file in the lockfile of the directory
+        fn lock(
+            &self,
+            path: &Path,
+            version: &str,
+            file_name: String,
+            url: String,
+            owner: Option<String>,
+            provenance: Provenance,
+        ) -> Result<()> {
+            let mut lockfile = Lockfile::load_or_default(self.dir())?;
+            lockfile.insert(
+                self.plugin_id(),
+                LockedPlugin {
+                    version: version.to_string(),
+                    md5: Some(md5_hex(path)?),
+                    file: Some(file_name),
+                    url: Some(url),
+                    disabled: false,
+                    owner,
+                    provenance: Some(provenance),
+                },
+            );
+            lockfile.save(self.dir())
+        }
+
+        /// Refuses to install from a project that changed owner since it was locked, unless accepted
+        fn check_owner(&self, project: &Project) -> Result<()> {
+            let lockfile = Lockfile::load_or_default(self.dir())?;
+            let owner = &project.namespace.owner;
+            let Some(previous) = lockfile.owner_change(self.plugin_id(), owner) else {
+                return Ok(());
+            };
+
+            warn!(
+                "{} was installed from {}'s project, it now belongs to {}",
+                self.plugin_id(),
+                previous,
+                owner
+            );
+            match self.accept_new_owner {
+                true => Ok(()),
+                false => Err(anyhow::Error::msg(format!(
+                    "{} changed owner, add --accept-new-owner to install from {}",
+                    self.plugin_id(),
+                    owner
+                ))),
+            }
+        }
+
+        /// Installs from Modrinth, which serves its files directly so there's no workaround needed
+        async fn install_modrinth(
+            &self,
+            modrinth: ModrinthClient,
+            project_id: &str,
+            notifier: &Notifier,
+            clock: &dyn Clock,
+        ) -> Result<PathBuf> {
+            let version = match self.requested_version() {
+                Some(version) => modrinth.version(project_id, version).await?,
+                // Versions are listed newest first
+                None => modrinth
+                    .versions(project_id)
+                    .await?
+                    .into_iter()
+                    .find(|version| self.in_range(&version.version_number))
+                    .ok_or_else(|| {
+                        anyhow::Error::msg(format!("{} has no matching versions", project_id))
+                    })?,
+            };
+            if let Some(path) = self.existing(&version.version_number)? {
+                return Ok(path);
+            }
+            let remote_file = version.file(self.file.as_deref())?;
+            if self.file.is_none() && version.files.len() > 1 {
+                warn!(
+                    "Version {} has several files, installing {}, pick another with --file : {}",
+                    version.version_number,
+                    remote_file.filename,
+                    version.file_names()
+                );
+            }
+
+            let res = modrinth.download(&remote_file.url).await?;
+
+            let file_name = self.file_name(&version.version_number, &remote_file.filename);
+            let path = self.dir().join(&file_name);
+            let partial = self.dir().join(format!("{}.part", file_name));
+
+            Self::download(res, &partial).await?;
+
+            let expected_sha512 = match self.no_verify {
+                true => None,
+                false => {
+                    if remote_file.hashes.sha512.is_none() {
+                        warn!("No sha512 available from Modrinth, skipping verification")
+                    }
+                    remote_file.hashes.sha512.as_deref()
+                }
+            };
+            let actual_sha512 = match expected_sha512 {
+                Some(_) => sha512_hex(&partial)?,
+                None => String::new(),
+            };
+            let verification = compare_or_quarantine(
+                &partial,
+                "sha512",
+                expected_sha512,
+                &actual_sha512,
+                &Quarantine::for_dir(self.dir()),
+                notifier,
+            )
+            .await?;
+
+            if let Verification::Quarantined = verification {
+                return Err(anyhow::Error::msg(
+                    "Downloaded file failed verification and was quarantined",
+                ));
+            }
+
+            fs::rename(&partial, &path)?;
+
+            self.lock(
+                &path,
+                &version.version_number,
+                file_name,
+                remote_file.url.to_string(),
+                None,
+                // Modrinth serves files without a session
+                Provenance::now(None, clock),
+            )?;
+
+            Ok(path)
+        }
+
+        /// Writes the body to the file as it's received, returning its md5
+        /// Only a chunk is held in memory at a time, a truncated file is removed.
+        async fn download(res: Response, path: &Path) -> Result<String> {
+            let res = Self::write_chunks(res, path).await;
+            if res.is_err() {
+                let _ = fs::remove_file(path);
+            }
+            res
+        }
+
+        async fn write_chunks(mut res: Response, path: &Path) -> Result<String> {
+            let mut file = File::create(path)?;
+            let mut hasher = Md5::new();
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            let mut progress = Progress::new(&name, res.content_length());
+
+            while let Some(chunk) = res.chunk().await? {
+                hasher.update(&chunk);
+                file.write_all(&chunk)?;
+                progress.advance(chunk.len());
+            }
+            progress.finish();
+
+            Ok(format!("{:x}", hasher.finalize()))
+        }
+
+        pub(super) fn new(plugin_id: &str, version: &str, dir: &Path) -> InstallCommand {
+            InstallCommand {
+                dir: Some(dir.to_path_buf()),
+                name_template: None,
+                plugin_id: Some(plugin_id.to_string()),
+                version: Some(version.to_string()),
+                manifest: None,
+                api: None,
+                file: None,
+                no_verify: false,
+                with_dependencies: false,
+                accept_new_owner: false,
+                strategy: None,
+                permanent: false,
+                remote_id: None,
+            }
+        }
+
+        /// Installs the version into the configured install or plugins directory
+        pub(super) fn configured(plugin_id: &str, version: &str) -> Result<InstallCommand> {
+            let mut install = InstallCommand {
+                plugin_id: Some(plugin_id.to_string()),
+                version: Some(version.to_string()),
+                ..Default::default()
+            };
+            install.apply_config(&Config::load()?);
+            Ok(install)
+        }
+
+        /// Installs every plugin of the manifest into the directory
+        pub(super) fn from_manifest(manifest: &Path, dir: &Path) -> InstallCommand {
+            InstallCommand {
+                dir: Some(dir.to_path_buf()),
+                manifest: Some(manifest.to_path_buf()),
+                ..Default::default()
+            }
+        }
+
+        pub(super) fn with_remote_id(mut self, remote_id: &str) -> InstallCommand {
+            self.remote_id = Some(remote_id.to_string());
+            self
+        }
+
+        pub(super) fn with_accept_new_owner(mut self, accept_new_owner: bool) -> InstallCommand {
+            self.accept_new_owner = accept_new_owner;
+            self
+        }
+
+        /// What the installed version depends on, resolved against the plugins in the directory
+        /// Only Ore publishes dependencies, so there's no tree for plugins from other sources.
+        async fn dependency_tree(&self, ore_client: &OreClient) -> Result<Option<DependencyTree>> {
+            let (source, ore_id) = self.source()?;
+            let locked = Lockfile::load_or_default(self.dir())?;
+            let (Source::Ore, Some(locked)) = (source, locked.get(self.plugin_id())) else {
+                return Ok(None);
+            };
+
+            let installed = scan(self.dir(), &ScanFilter::default())?
+                .into_iter()
+                .map(|(_, info)| (info.modid, info.version))
+                .collect::<HashMap<String, String>>();
+            let version = ore_client.version(&ore_id, &locked.version).await?;
+
+            let mut path = vec![self.plugin_id().to_string()];
+            let dependencies =
+                Self::resolve(ore_client, &version.dependencies, &installed, &mut path).await;
+
+            Ok(Some(DependencyTree {
+                id: self.plugin_id().to_string(),
+                version: locked.version.to_string(),
+                dependencies,
+            }))
+        }
+
+        /// Resolves each dependency, and the dependencies of those that would be installed
+        /// `path` holds the plugins further up the tree, depending on one of them again is a cycle.
+        fn resolve<'a>(
+            ore_client: &'a OreClient,
+            dependencies: &'a [VersionDependency],
+            installed: &'a HashMap<String, String>,
+            path: &'a mut Vec<String>,
+        ) -> BoxFuture<'a, Vec<Dependency>> {
+            Box::pin(async move {
+                let mut resolved = vec![];
+                for dependency in dependencies.iter().map(PluginDependency::from) {
+                    if dependency.is_sponge_dep() {
+                        continue;
+                    }
+
+                    let mut children = vec![];
+                    let status = if path.contains(&dependency.id) {
+                        DependencyStatus::Cycle
+                    } else if let Some(version) = installed.get(&dependency.id) {
+                        DependencyStatus::Installed(version.to_string())
+                    } else {
+                        match Self::newest_within(ore_client, &dependency).await {
+                            Ok(version) => {
+                                path.push(dependency.id.to_string());
+                                children = Self::resolve(
+                                    ore_client,
+                                    &version.dependencies,
+                                    installed,
+                                    path,
+                                )
+                                .await;
+                                path.pop();
+                                DependencyStatus::Missing(version.name)
+                            }
+                            Err(e) => DependencyStatus::Unavailable(e.to_string()),
+                        }
+                    };
+
+                    resolved.push(Dependency {
+                        id: dependency.id,
+                        range: dependency.version,
+                        status,
+                        dependencies: children,
+                    })
+                }
+                resolved
+            })
+        }
+
+        /// The newest version of the dependency on Ore within its declared range
+        async fn newest_within(
+            ore_client: &OreClient,
+            dependency: &PluginDependency,
+        ) -> Result<Version> {
+            let versions = all_versions(ore_client, &dependency.id).await?;
+            let name = newest(
+                versions
+                    .iter()
+                    .map(|version| version.name.as_str())
+                    .filter(|version| satisfies(version, &dependency.version)),
+            )
+            .map(str::to_string);
+
+            versions
+                .into_iter()
+                .find(|version| Some(&version.name) == name.as_ref())
+                .ok_or_else(|| {
+                    anyhow::Error::msg(format!("no version within '{}'", dependency.version))
+                })
+        }
+
+        /// Prints the dependency tree, installing the missing dependencies when asked to
+        async fn install_dependencies(&self, ore_client: &OreClient) -> Result<()> {
+            let Some(tree) = self.dependency_tree(ore_client).await? else {
+                return Ok(());
+            };
+            if tree.dependencies.is_empty() {
+                return Ok(());
+            }
+
+            print!("{}", tree);
+            for problem in tree.problems() {
+                warn!("{}", problem);
+            }
+
+            let order = tree.install_order();
+            if order.is_empty() {
+                return Ok(());
+            }
+            if !self.with_dependencies {
+                warn!("Missing dependencies aren't installed, add --with-dependencies to install them");
+                return Ok(());
+            }
+
+            for (id, version) in order {
+                let install = InstallCommand {
+                    name_template: self.name_template.clone(),
+                    no_verify: self.no_verify,
+                    accept_new_owner: self.accept_new_owner,
+                    ..InstallCommand::new(id, version, self.dir())
+                };
+                let installed = install.install(ore_client).await?;
+                let file_name = installed.file_name().unwrap_or_default();
+                info!(
+                    "Installed dependency {} : {}",
+                    id,
+                    file_name.to_string_lossy()
+                );
+            }
+            Ok(())
+        }
+
+        /// How to resolve the conflict, the strategy when given, otherwise asked when there's someone to ask
+        fn resolution(&self, id: &str, conflict: &Conflict) -> Result<Resolution> {
+            if let Some(strategy) = self.strategy {
+                return Ok(strategy);
+            }
+            if !io::stdin().is_terminal() {
+                return Err(anyhow::Error::msg(format!(
+                    "{}, resolve it with --strategy",
+                    conflict
+                )));
+            }
+
+            let question = format!(
+                "{} : {}\nKeep [l]ocal, take [r]emote or [s]kip ? ",
+                id, conflict
+            );
+            loop {
+                // Nothing more to read, so nothing is changed
+                let Some(answer) = ask(&question)? else {
+                    return Ok(Resolution::Skip);
+                };
+                match answer.to_lowercase().as_str() {
+                    "l" | "local" => return Ok(Resolution::Local),
+                    "r" | "remote" => return Ok(Resolution::Remote),
+                    "s" | "skip" => return Ok(Resolution::Skip),
+                    _ => eprintln!("Answer l, r or s"),
+                }
+            }
+        }
+
+        /// Records the jar of the plugin found in the directory, or forgets the plugin when there's none
+        fn keep_local(&self, id: &str, found: Option<&(PathBuf, OreModInfo)>) -> Result<()> {
+            let mut lockfile = Lockfile::load_or_default(self.dir())?;
+            match found {
+                Some((path, info)) => {
+                    let owner = lockfile.get(id).and_then(|locked| locked.owner.clone());
+                    let file = path.file_name().map(|f| f.to_string_lossy().to_string());
+                    lockfile.insert(
+                        id,
+                        LockedPlugin {
+                            version: info.version.to_string(),
+                            md5: Some(md5_hex(path)?),
+                            file,
+                            url: None,
+                            disabled: false,
+                            owner,
+                            // Placed by hand, so nothing is known of where it came from
+                            provenance: None,
+                        },
+                    );
+                }
+                None => {
+                    lockfile.plugins.remove(id);
+                }
+            }
+            lockfile.save(self.dir())
+        }
+
+        /// Installs or updates every plugin of the manifest, replacing the previously locked jar
+        /// A plugin that fails doesn't stop the others, the failures are reported once all were tried
+        /// A plugin whose jar disagrees with the lockfile is first resolved, see [Conflict].
+        async fn install_manifest(&self, ore_client: &OreClient, path: &Path) -> Result<()> {
+            let manifest = Manifest::load(path)?;
+            let found = scan(self.dir(), &ScanFilter::default())?
+                .into_iter()
+                .map(|(path, info)| (info.modid.to_string(), (path, info)))
+                .collect::<HashMap<String, (PathBuf, OreModInfo)>>();
+
+            let mut failures = vec![];
+            for (id, entry) in &manifest.plugins {
+                let install = InstallCommand {
+                    name_template: self.name_template.clone(),
+                    api: entry.api(),
+                    no_verify: self.no_verify,
+                    accept_new_owner: self.accept_new_owner,
+                    remote_id: entry.id().map(str::to_string),
+                    ..InstallCommand::new(id, entry.version(), self.dir())
+                };
+                let lockfile = Lockfile::load_or_default(self.dir())?;
+                let found = found.get(id);
+
+                let conflict = Conflict::detect(
+                    self.dir(),
+                    lockfile.get(id),
+                    found.map(|(path, _)| path.as_path()),
+                )?;
+                // The unlocked jar is only known from the scan, it's replaced like a locked one
+                let mut unlocked = None;
+                if let Some(conflict) = conflict {
+                    match self.resolution(id, &conflict) {
+                        Ok(Resolution::Remote) => {
+                            if let Conflict::Unlocked(path) = conflict {
+                                unlocked = Some(path);
+                            }
+                        }
+                        Ok(Resolution::Local) => {
+                            self.keep_local(id, found)?;
+                            info!("Kept {} : {}", id, conflict);
+                            continue;
+                        }
+                        Ok(Resolution::Skip) => {
+                            info!("Skipped {} : {}", id, conflict);
+                            continue;
+                        }
+                        Err(e) => {
+                            error!("Failed {} : {}", id, e);
+                            failures.push(id.as_str());
+                            continue;
+                        }
+                    }
+                }
+
+                let old = lockfile
+                    .get(id)
+                    .and_then(|locked| locked.file.as_ref())
+                    .map(|file| self.dir().join(file))
+                    .or(unlocked);
+
+                match install.install(ore_client).await {
+                    Ok(installed) => {
+                        if let Some(old) = old.filter(|old| *old != installed && old.exists()) {
+                            if let Err(e) = discard(&old, self.permanent) {
+                                error!("Failed {} : {}", id, e);
+                                failures.push(id.as_str());
+                                continue;
+                            }
+                        }
+                        let file_name = installed.file_name().unwrap_or_default();
+                        info!("Installed {} : {}", id, file_name.to_string_lossy());
+                    }
+                    Err(e) => {
+                        error!("Failed {} : {}", id, e);
+                        failures.push(id.as_str());
+                    }
+                }
+            }
+
+            match failures.is_empty() {
+                true => Ok(()),
+                false => Err(anyhow::Error::msg(format!(
+                    "{} of {} plugins failed to install : {}",
+                    failures.len(),
+                    manifest.plugins.len(),
+                    failures.join(", ")
+                ))),
+            }
+        }
+
+        /// Where the plugin is installed from, and its id there
+        fn source(&self) -> Result<(Source, String)> {
+            let plugin_source = Config::load()?.plugin_source(self.plugin_id());
+            let remote_id = self
+                .remote_id
+                .as_deref()
+                .unwrap_or(plugin_source.id_or(self.plugin_id()));
+            Ok((plugin_source.source, remote_id.to_string()))
+        }
+
+        /// Downloads, verifies and locks the version, returning where it was installed
+        /// A version that's already installed and intact isn't downloaded again
+        pub(super) async fn install(&self, ore_client: &OreClient) -> Result<PathBuf> {
+            let (source, remote_id) = self.source()?;
+            let notifier = Config::load()?.notify.notifier(ore_client.http());
+            if source == Source::Modrinth {
+                let modrinth = ModrinthClient::new(ore_client.http().clone());
+                return self
+                    .install_modrinth(modrinth, &remote_id, &notifier, ore_client.clock())
+                    .await;
+            }
+            if self.file.is_some() {
+                return Err(anyhow::Error::msg(
+                    "Ore versions hold a single file, pick the version for an API with --api instead",
+                ));
+            }
+            let ore_id = remote_id.as_str();
+            let version = self.ore_version(ore_client, ore_id).await?;
+            if let Some(path) = self.existing(&version)? {
+                return Ok(path);
+            }
+
+            // The hash is known before downloading, so a bad download never replaces anything
+            let expected_md5 = match self.no_verify {
+                true => None,
+                false => {
+                    let version = ore_client.version(ore_id, &version).await?;
+                    if version.file_info.md_5_hash.is_none() {
+                        warn!("No md5 available from Ore, skipping verification")
+                    }
+                    version.file_info.md_5_hash
+                }
+            };
+
+            // The project's owner and slug make up the download link
+            let project = ore_client.project(ore_id).await?;
+            self.check_owner(&project)?;
+            let res = ore_client.download(&project, &version).await?;
+
+            // Because we don't install from the API, we have to retrieve the file name from where available.
+            let file_name = res
+                .headers()
+                .get(reqwest::header::CONTENT_DISPOSITION)
+                .map(|s| s.to_str())
+                .map(|f| f.unwrap_or(Self::DEFAULT_FILE_NAME))
+                .and_then(extract_filename)
+                .unwrap_or(Self::DEFAULT_FILE_NAME);
+
+            let file_name = self.file_name(&version, file_name);
+
+            let dir = self.dir();
+
+            let path = dir.join(&file_name);
+            let partial = dir.join(format!("{}.part", file_name));
+
+            let url = res.url().to_string();
+
+            let actual_md5 = Self::download(res, &partial).await?;
+
+            let verification = compare_or_quarantine(
+                &partial,
+                "md5",
+                expected_md5.as_deref(),
+                &actual_md5,
+                &Quarantine::for_dir(dir),
+                &notifier,
+            )
+            .await?;
+
+            if let Verification::Quarantined = verification {
+                return Err(anyhow::Error::msg(
+                    "Downloaded file failed verification and was quarantined",
+                ));
+            }
+
+            fs::rename(&partial, &path)?;
+
+            let owner = Some(project.namespace.owner);
+            let provenance = Provenance::now(ore_client.session_fingerprint(), ore_client.clock());
+            self.lock(&path, &version, file_name, url, owner, provenance)?;
+
+            Ok(path)
+        }
+    }
+
+    /// A progress bar of a download on stderr, only drawn when stderr is a terminal
+    struct Progress<'a> {
+        name: &'a str,
+        total: Option<u64>,
+        received: u64,
+        /// What was last drawn, so the bar is only redrawn when it changes
+        drawn: u64,
+        visible: bool,
+    }
+
+    impl<'a> Progress<'a> {
+        const WIDTH: u64 = 30;
+        /// How often the amount is redrawn when the size isn't known
+        const STEP: u64 = 256 * 1024;
+
+        fn new(name: &'a str, total: Option<u64>) -> Self {
+            Progress {
+                name,
+                total: total.filter(|total| *total > 0),
+                received: 0,
+                drawn: u64::MAX,
+                visible: io::stderr().is_terminal() && tracing::enabled!(Level::INFO),
+            }
+        }
+
+        fn advance(&mut self, len: usize) {
+            self.received += len as u64;
+            if !self.visible {
+                return;
+            }
+
+            let step = match self.total {
+                Some(total) => self.received.min(total) * 100 / total,
+                None => self.received / Self::STEP,
+            };
+            if step == self.drawn {
+                return;
+            }
+            self.drawn = step;
+
+            let received = human_bytes(self.received as f64);
+            match self.total {
+                Some(total) => {
+                    let filled = (Self::WIDTH * step / 100) as usize;
+                    eprint!(
+                        "\rDownloading {} [{}{}] {}% {} / {}",
+                        self.name,
+                        "#".repeat(filled),
+                        "-".repeat(Self::WIDTH as usize - filled),
+                        step,
+                        received,
+                        human_bytes(total as f64)
+                    )
+                }
+                None => eprint!("\rDownloading {} {}", self.name, received),
+            }
+            let _ = io::stderr().flush();
+        }
+
+        fn finish(&self) {
+            if self.visible && self.drawn != u64::MAX {
+                eprintln!();
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for InstallCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            if let Some(manifest) = &self.manifest {
+                return self.install_manifest(&ore_client, manifest).await;
+            }
+
+            let path = self.install(&ore_client).await?;
+
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            let message = format!("Installed '{}' into '{}'", file_name, self.dir().display());
+            self.print_res(message)?;
+
+            self.install_dependencies(&ore_client).await
+        }
+    }
+}
+
+pub mod version_check_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Args, Parser, ValueEnum};
+    use futures::{channel::mpsc, executor::block_on, stream, SinkExt, StreamExt};
+    use ore_monitor::{
+        clock::ago,
+        disabled_plugins::is_disabled,
+        discard::discard,
+        duration::parse_duration,
+        file_naming::NameTemplate,
+        file_reader::{is_jar, FileReader, SkipReason, UnreadableJar},
+        lockfile::Lockfile,
+        ore::ore_client::{OreClient, ORE_SITE},
+        ore_mod_info::{OreModInfo, PluginDependency},
+        platform_support::PlatformSupport,
+        quarantine::Quarantine,
+        query::Query,
+        query_builder,
+        report::{Cell, Report, ReportFormat},
+        response_cache::{Cache, ResponseCache},
+        scan_cache::ScanCache,
+        scan_filter::{expand_glob, NestedScan, RecursiveScan, ScanFilter},
+        source::Source,
+        sponge_schemas::{Project, ReleaseChannel, Version},
+        transaction::Transaction,
+    };
+    use ore_monitor_common::version_status::VersionStatus;
+
+    use std::{
+        collections::{BTreeSet, HashMap, HashSet},
+        fmt::Display,
+        fs,
+        io::{self, IsTerminal},
+        path::{Path, PathBuf},
+        time::Duration,
+    };
+    use tokio::{task, time};
+    use tracing::{debug, info, warn};
+
+    use crate::{
+        config::ore_config::Config,
+        modrinth::modrinth_client::{ModrinthClient, MODRINTH_SITE},
+        notify::notification::{Notification, Notifier, OutdatedPlugin, WebhookSink},
+        verify::jar_verification::{compare_or_quarantine, hash_jars, Verification},
+    };
+
+    use super::{
+        core_command::OreCommand,
+        install_command::InstallCommand,
+        pagination::{all_versions, follow},
+        prompt::ask,
+        update_plan::{PlannedUpdate, UpdatePlan},
+    };
+
+    #[derive(Parser, Default)]
+    pub struct VersionCheckCommand {
+        /// Jars, directories or globs of jars to check, ex: `mods/*.jar plugins/`, otherwise checks the configured plugins directory, or where it was ran from
+        paths: Vec<PathBuf>,
+        #[command(flatten)]
+        scan: ScanArgs,
+        /// Verifies jars against the md5 published on Ore, quarantining any that fail
+        #[arg(long)]
+        verify: bool,
+        /// Renames the jars to match a template, supports `{id}`, `{version}` and `{file}`
+        #[arg(long)]
+        normalize: Option<NameTemplate>,
+        /// Installs the remote version of each out of date plugin, replacing the local jar
+        #[arg(long)]
+        update: bool,
+        /// Lists what --update would replace without downloading anything
+        #[arg(long, requires = "update")]
+        dry_run: bool,
+        /// Updates without asking first, ex: in scripts, nothing is asked when stdin isn't a terminal either
+        #[arg(short, long, requires = "update")]
+        yes: bool,
+        /// Updates plugins whose Ore project now belongs to another owner than the one they were locked from
+        #[arg(long, requires = "update")]
+        accept_new_owner: bool,
+        /// Deletes the replaced jars, instead of moving them to the trash
+        #[arg(long, requires = "update")]
+        permanent: bool,
+        /// How to order the report, by plugin id unless given
+        #[arg(long)]
+        sort: Option<ReportOrder>,
+        /// How long a plugin's lookup may take before it's reported as an error, ex: 30s
+        #[arg(long, value_parser = parse_duration)]
+        timeout_per_plugin: Option<Duration>,
+        /// A webhook to POST out of date plugins to, otherwise the configured webhook
+        #[arg(long)]
+        notify_url: Option<String>,
+        /// Compares against the responses cached by `prefetch` however old they are, without contacting Ore
+        #[arg(long, conflicts_with_all = ["update", "notify_url"])]
+        offline: bool,
+        /// The least stable Ore channel compared against, ex: `release` skips promoted betas, any channel unless given
+        #[arg(long)]
+        channel: Option<ReleaseChannel>,
+        /// Compares against the newest Ore version tagged for the plugin's Sponge API, instead of the promoted one
+        /// Without it, this is only done for plugins with no promoted version for their API.
+        #[arg(long)]
+        all_versions: bool,
+        /// Also writes the results as a table to --out, ex: to paste into a ticket or wiki
+        #[arg(long, requires = "out")]
+        report: Option<ReportFormat>,
+        /// Where the --report is written
+        #[arg(long, requires = "report")]
+        out: Option<PathBuf>,
+    }
+
+    /// Which Ore version a plugin is compared against
+    #[derive(Clone, Copy, Default)]
+    pub(super) struct VersionSelection {
+        /// The least stable channel accepted, any unless given
+        pub(super) channel: Option<ReleaseChannel>,
+        /// Skips the promoted versions for the newest tagged one
+        pub(super) all_versions: bool,
+    }
+
+    /// Orders of the check report, ties are broken by plugin id
+    #[derive(ValueEnum, Clone, Copy, Default, PartialEq)]
+    enum ReportOrder {
+        #[default]
+        Id,
+        /// Out of date plugins first
+        Status,
+        Source,
+        /// The order jars are read in, each plugin is printed as soon as it's looked up
+        Scan,
+    }
+
+    impl ReportOrder {
+        fn sort(&self, displays: &mut [VersionDisplay]) {
+            match self {
+                ReportOrder::Id | ReportOrder::Scan => displays.sort_by(|a, b| a.id.cmp(&b.id)),
+                ReportOrder::Status => {
+                    displays.sort_by(|a, b| a.status.cmp(&b.status).then(a.id.cmp(&b.id)))
+                }
+                ReportOrder::Source => displays.sort_by(|a, b| {
+                    a.source
+                        .to_string()
+                        .cmp(&b.source.to_string())
+                        .then(a.id.cmp(&b.id))
+                }),
+            }
+        }
+    }
+
+    /// The directory holding the plugins, if the path is a single jar it's the parent directory
+    pub(super) fn plugins_dir(path: &Path) -> &Path {
+        match path.is_dir() {
+            true => path,
+            false => path.parent().unwrap_or(Path::new(".")),
+        }
+    }
+
+    /// Globs narrowing down which files of a directory are scanned
+    #[derive(Args, Default, Clone)]
+    pub(super) struct ScanArgs {
+        /// Only scans files matching the glob, relative to the directory, ex: `*.jar`, or `**/*.jar` to include subdirectories
+        #[arg(long)]
+        include: Vec<String>,
+        /// Skips files and directories matching the glob, ex: `disabled/*`
+        #[arg(long)]
+        exclude: Vec<String>,
+        /// Also looks inside of jars without plugin metadata for the plugin jars they embed, ex: shaded bundles
+        #[arg(long)]
+        nested: bool,
+        /// Also scans subdirectories, ex: per-world folders, hidden ones are skipped
+        #[arg(long)]
+        recursive: bool,
+        /// How many directories deep --recursive looks, 8 unless given
+        #[arg(long, requires = "recursive")]
+        max_depth: Option<u32>,
+        /// Walks into symlinked directories when scanning recursively
+        #[arg(long, requires = "recursive")]
+        follow_symlinks: bool,
+        /// How many jars are read at once, one per core unless given
+        #[arg(long)]
+        threads: Option<usize>,
+        /// Reads every jar again, instead of trusting the scan cache for the unchanged ones
+        #[arg(long)]
+        rescan: bool,
+    }
+
+    impl ScanArgs {
+        pub(super) fn filter(&self) -> ScanFilter {
+            let recursion = self.recursive.then(|| {
+                let default = RecursiveScan::default();
+                RecursiveScan {
+                    max_depth: self.max_depth.unwrap_or(default.max_depth),
+                    follow_symlinks: self.follow_symlinks,
+                }
+            });
+            ScanFilter::new(self.include.clone(), self.exclude.clone())
+                .with_nested(self.nested.then(NestedScan::default))
+                .with_recursion(recursion)
+                .with_threads(self.threads)
+                .with_rescan(self.rescan)
+        }
+    }
+
+    /// How many jars each scanning thread reads before they're handed over
+    const SCAN_BATCH: usize = 4;
+
+    /// Reads the jar, or each jar inside of the directory that passes the filter
+    /// Jars that can't be read are warned about.
+    pub(super) fn scan(path: &Path, filter: &ScanFilter) -> Result<Vec<(PathBuf, OreModInfo)>> {
+        let mut files = vec![];
+        let skipped = scan_each(path, filter, |path, info| {
+            files.push((path, info));
+            true
+        })?;
+        skipped
+            .iter()
+            .for_each(|unreadable| warn!("{}", unreadable));
+        Ok(files)
+    }
+
+    /// Same as [scan], handing over each jar as soon as it's read and returning the jars that couldn't be
+    /// Scanning stops early once `each` returns false.
+    pub(super) fn scan_each<F>(
+        path: &Path,
+        filter: &ScanFilter,
+        mut each: F,
+    ) -> Result<Vec<UnreadableJar>>
+    where
+        F: FnMut(PathBuf, OreModInfo) -> bool,
+    {
+        let reader = FileReader::from(path).with_filter(filter.clone());
+
+        if path.is_file() {
+            each(path.to_path_buf(), reader.handle_file(None)?);
+            return Ok(vec![]);
+        }
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        // Reading in name order keeps the scan, and anything streamed from it, reproducible
+        // Disabled jars are only listed, see [scan_disabled]
+        let jars = reader
+            .files()?
+            .into_iter()
+            .filter(|jar| !is_disabled(jar))
+            .collect::<Vec<PathBuf>>();
+
+        let mut cache = ScanCache::load().with_rescan(filter.rescan);
+        let mut skipped = vec![];
+        // Jars are read a few per thread at a time, so the first are handed over before the last are read
+        'scan: for batch in jars.chunks(filter.threads() * SCAN_BATCH) {
+            let read = cache.get_or_read_all(batch, |jars| reader.read_all(jars));
+            for (jar, info) in batch.iter().zip(read) {
+                let info = match info {
+                    Ok(info) => info,
+                    // Jars that can't be read are reported, anything else in the directory is ignored
+                    Err(e) if is_jar(jar) => {
+                        skipped.push(UnreadableJar {
+                            path: jar.to_path_buf(),
+                            reason: SkipReason::of(&e),
+                        });
+                        continue;
+                    }
+                    Err(_) => continue,
+                };
+                if !each(jar.to_path_buf(), info) {
+                    break 'scan;
+                }
+            }
+        }
+
+        // The cache only speeds up later scans, failing to save it isn't worth failing the scan
+        if let Err(e) = cache.save() {
+            warn!("Unable to save the scan cache : {}", e)
+        }
+        Ok(skipped)
+    }
+
+    /// Warns about each plugin that only supports an end-of-life SpongeAPI
+    /// Plugins that don't declare their API are skipped.
+    pub(super) fn warn_end_of_life(infos: &[OreModInfo]) {
+        let support = PlatformSupport::load().unwrap_or_else(|e| {
+            warn!(
+                "Unable to read the platform dataset, using the bundled one : {}",
+                e
+            );
+            PlatformSupport::bundled()
+        });
+        for info in infos {
+            if let Some(platform) = support.end_of_life(info.major_api_version) {
+                warn!(
+                    "{} only supports {}, which is end-of-life",
+                    info.modid, platform
+                );
+            }
+        }
+    }
+
+    /// Reads the jars of the directory that were disabled by renaming them
+    pub(super) fn scan_disabled(
+        path: &Path,
+        filter: &ScanFilter,
+    ) -> Result<Vec<(PathBuf, OreModInfo)>> {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+        Ok(FileReader::from(path)
+            .with_filter(filter.clone())
+            .scan_dir()?
+            .disabled)
+    }
+
+    /// The latest version available for a jar, and where it was found
+    pub(super) struct RemoteVersion {
+        /// The plugin id of the jar it was looked up for
+        pub(super) id: String,
+        /// The id the plugin was found under on its source
+        pub(super) remote_id: String,
+        pub(super) source: Source,
+        pub(super) version: String,
+        /// How many bytes updating to the version downloads, when the source said
+        pub(super) download_size: Option<f64>,
+        /// The project's page on its source
+        pub(super) url: Option<String>,
+        /// Where the version's file is downloaded from
+        pub(super) download_url: Option<String>,
+        /// What the version depends on, when the version was fetched, such as when it's an update
+        pub(super) dependencies: Option<Vec<PluginDependency>>,
+    }
+
+    /// Retrieves the latest version of each jar from its configured source
+    /// Lookups are sent concurrently, up to the client's concurrency limit
+    /// A plugin that couldn't be found doesn't stop the others, its error is kept with its id instead
+    pub(super) async fn fetch_remotes(
+        ore_client: &OreClient,
+        files: &[(PathBuf, OreModInfo)],
+    ) -> Result<Vec<(String, Result<RemoteVersion>)>> {
+        let config = Config::load()?;
+        let modrinth = ModrinthClient::new(ore_client.http().clone());
+        let lockfiles = files
+            .iter()
+            .map(|(path, _)| path.parent().unwrap_or(Path::new(".")))
+            .collect::<BTreeSet<&Path>>()
+            .into_iter()
+            .filter_map(|dir| Lockfile::load(dir).ok().flatten())
+            .collect::<Vec<Lockfile>>();
+        let owned = owned_projects(ore_client, &config, &lockfiles).await;
+
+        let lookups = files
+            .iter()
+            .map(|(_, info)| async {
+                let remote = fetch_remote(
+                    ore_client,
+                    &modrinth,
+                    &config,
+                    &owned,
+                    info,
+                    VersionSelection::default(),
+                )
+                .await;
+                (info.modid.to_string(), remote)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(stream::iter(lookups)
+            .buffered(ore_client.concurrency())
+            .collect()
+            .await)
+    }
+
+    /// The Ore projects of owners that several plugins were locked to, keyed by their lowercased plugin id
+    /// A page of an owner's projects covers many plugins at once, instead of a request per plugin.
+    /// Plugins it doesn't cover are looked up on their own, so an owner that can't be listed is skipped.
+    async fn owned_projects<'a>(
+        ore_client: &OreClient,
+        config: &Config,
+        lockfiles: impl IntoIterator<Item = &'a Lockfile>,
+    ) -> HashMap<String, Project> {
+        let mut plugins_by_owner = HashMap::<String, usize>::new();
+        for (id, locked) in lockfiles
+            .into_iter()
+            .flat_map(|lockfile| lockfile.plugins.iter())
+        {
+            if locked.disabled || config.plugin_source(id).source != Source::Ore {
+                continue;
+            }
+            if let Some(owner) = &locked.owner {
+                *plugins_by_owner.entry(owner.to_string()).or_default() += 1;
+            }
+        }
+
+        // Listing an owner with a single plugin costs at least as much as looking the plugin up
+        let owners = plugins_by_owner
+            .into_iter()
+            .filter(|(_, jars)| *jars > 1)
+            .map(|(owner, _)| owner)
+            .collect::<BTreeSet<String>>();
+
+        stream::iter(owners)
+            .map(|owner| async move {
+                follow(0, |offset| {
+                    let query = query_builder!(
+                        "owner" : QueryType::Value(Some(&owner)),
+                        "offset" : QueryType::Value(Some(offset)),
+                    );
+                    ore_client.projects(query)
+                })
+                .await
+            })
+            .buffer_unordered(ore_client.concurrency())
+            .filter_map(|page| async { page.ok() })
+            .flat_map(|page| stream::iter(page.result))
+            .map(|project| (project.plugin_id.to_lowercase(), project))
+            .collect()
+            .await
+    }
+
+    /// Retrieves the version a jar is compared against, as `check` selects it
+    /// Ore projects already looked up by their lowercased plugin id aren't requested again.
+    pub(super) async fn fetch_remote(
+        ore_client: &OreClient,
+        modrinth: &ModrinthClient,
+        config: &Config,
+        owned: &HashMap<String, Project>,
+        info: &OreModInfo,
+        selection: VersionSelection,
+    ) -> Result<RemoteVersion> {
+        let plugin_source = config.plugin_source(&info.modid);
+
+        let (remote_id, version, download_size, url, download_url, dependencies) =
+            match plugin_source.source {
+                Source::Ore => {
+                    let id = plugin_source.id_or(&info.modid).to_lowercase();
+                    let looked_up;
+                    let project = match owned.get(&id) {
+                        Some(project) => project,
+                        None => {
+                            looked_up =
+                                ore_project(ore_client, plugin_source.id.as_deref(), info).await?;
+                            &looked_up
+                        }
+                    };
+                    let promoted =
+                        project.version_in_channel(info.major_api_version, selection.channel);
+                    let newest = promoted.is_empty() || selection.all_versions;
+                    let fetched = match newest {
+                        true => {
+                            newest_tagged(ore_client, &project.plugin_id, info, selection).await?
+                        }
+                        // Updates are planned from the new version's dependencies and size, only it has them
+                        false
+                            if VersionStatus::new(&info.version, promoted)
+                                == VersionStatus::OutOfDate =>
+                        {
+                            ore_client
+                                .version(&project.plugin_id, promoted)
+                                .await
+                                .inspect_err(|e| debug!("Unable to fetch {} : {}", promoted, e))
+                                .ok()
+                        }
+                        false => None,
+                    };
+                    let (version, size, dependencies) = match fetched {
+                        Some(version) => (
+                            version.name,
+                            Some(version.file_info.size_bytes),
+                            Some(version.dependencies.iter().map(Into::into).collect()),
+                        ),
+                        None if newest => (String::new(), None, None),
+                        None => (promoted.to_string(), None, None),
+                    };
+                    let url = format!(
+                        "{}/{}/{}",
+                        ORE_SITE, project.namespace.owner, project.namespace.slug
+                    );
+                    let download_url =
+                        (!version.is_empty()).then(|| ore_client.download_url(project, &version));
+                    (
+                        project.plugin_id.to_string(),
+                        version,
+                        size,
+                        url,
+                        download_url,
+                        dependencies,
+                    )
+                }
+                Source::Modrinth if ore_client.is_offline() => {
+                    return Err(anyhow::Error::msg(
+                        "Modrinth lookups aren't cached, so they can't be checked offline",
+                    ))
+                }
+                Source::Modrinth => {
+                    let id = plugin_source.id_or(&info.modid);
+                    let latest = modrinth.latest_version(id).await?;
+                    let size = latest
+                        .primary_file()
+                        .and_then(|file| file.size)
+                        .map(|size| size as f64);
+                    let url = format!("{}/project/{}", MODRINTH_SITE, id);
+                    let download_url = latest.primary_file().map(|file| file.url.to_string());
+                    (
+                        id.to_string(),
+                        latest.version_number,
+                        size,
+                        url,
+                        download_url,
+                        None,
+                    )
+                }
+            };
+
+        Ok(RemoteVersion {
+            id: info.modid.to_string(),
+            remote_id,
+            source: plugin_source.source,
+            version,
+            download_size,
+            url: Some(url),
+            download_url,
+            dependencies,
+        })
+    }
+
+    /// The newest of all a project's versions tagged for the jar's Sponge API major version
+    /// None when there's none, the same as when nothing was promoted.
+    pub(super) async fn newest_tagged(
+        ore_client: &OreClient,
+        plugin_id: &str,
+        info: &OreModInfo,
+        selection: VersionSelection,
+    ) -> Result<Option<Version>> {
+        let newest = all_versions(ore_client, plugin_id)
+            .await?
+            .into_iter()
+            .filter(|version| version.sponge_major() == info.major_api_version)
+            .filter(|version| {
+                selection
+                    .channel
+                    .is_none_or(|channel| version.channel() <= channel)
+            })
+            .max_by_key(|version| version.created_at);
+        Ok(newest)
+    }
+
+    /// Finds the Ore project of a jar, by the configured id or else its modid
+    /// Ore ids don't always match the modid, so when there's no project under it one is searched for by name.
+    pub(super) async fn ore_project(
+        ore_client: &OreClient,
+        configured_id: Option<&str>,
+        info: &OreModInfo,
+    ) -> Result<Project> {
+        if let Some(id) = configured_id {
+            return ore_client.project(id).await;
+        }
+        if let Some(project) = ore_client.find_project(&info.modid).await? {
+            return Ok(project);
+        }
+
+        let query = query_builder!("q" : QueryType::Value(Some(&info.name)));
+        let res = ore_client.projects(query).await?;
+
+        res.result
+            .into_iter()
+            .find(|project| {
+                project.name.eq_ignore_ascii_case(&info.name)
+                    || project.plugin_id.eq_ignore_ascii_case(&info.modid)
+            })
+            .ok_or_else(|| {
+                anyhow::Error::msg(format!(
+                    "No project on Ore matches '{}', set its id under [plugins] in the config",
+                    info.name
+                ))
+            })
+    }
+
+    impl VersionCheckCommand {
+        /// How many read jars may wait on their lookup
+        const BUFFER: usize = 32;
+
+        pub fn is_offline(&self) -> bool {
+            self.offline
+        }
+
+        /// Only updating, renaming or quarantining jars changes the directory
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            match self.changes() {
+                true => self.dir().ok().flatten(),
+                false => None,
+            }
+        }
+
+        fn changes(&self) -> bool {
+            (self.update && !self.dry_run) || self.verify || self.normalize.is_some()
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            if self.paths.is_empty() {
+                self.paths.extend(config.plugins_dir.clone());
+            }
+            self.notify_url = self.notify_url.take().or(config.notify.webhook_url.clone());
+        }
+
+        /// The jars and directories to check, with each glob replaced by the jars it matches
+        fn paths(&self) -> Result<Vec<PathBuf>> {
+            if self.paths.is_empty() {
+                return Ok(vec![PathBuf::from(".")]);
+            }
+            let mut paths = vec![];
+            for path in &self.paths {
+                let expanded = expand_glob(path)?;
+                if expanded.is_empty() {
+                    return Err(anyhow::Error::msg(format!(
+                        "'{}' matches no files",
+                        path.display()
+                    )));
+                }
+                paths.extend(expanded);
+            }
+            Ok(paths)
+        }
+
+        /// The plugins directory every path is in, none when they're spread over several
+        fn dir(&self) -> Result<Option<PathBuf>> {
+            let dirs = self
+                .paths()?
+                .iter()
+                .map(|path| match plugins_dir(path) {
+                    dir if dir.as_os_str().is_empty() => PathBuf::from("."),
+                    dir => dir.to_path_buf(),
+                })
+                .collect::<BTreeSet<PathBuf>>();
+            Ok(match dirs.len() {
+                1 => dirs.into_iter().next(),
+                _ => None,
+            })
+        }
+
+        /// The directory updating, verifying or renaming acts on, they're done one directory at a time
+        fn changed_dir(&self) -> Result<PathBuf> {
+            self.dir()?.ok_or_else(|| {
+                anyhow::Error::msg(
+                    "--update, --verify and --normalize change one plugins directory at a time, the paths are in several",
+                )
+            })
+        }
+
+        /// Reads the jars of every path, a plugin found under several paths is only kept the first time
+        /// Scanning stops early once `each` returns false, the jars that couldn't be read are returned.
+        fn scan_paths<F>(
+            paths: &[PathBuf],
+            filter: &ScanFilter,
+            mut each: F,
+        ) -> Result<Vec<UnreadableJar>>
+        where
+            F: FnMut(PathBuf, OreModInfo) -> bool,
+        {
+            let mut seen = HashSet::new();
+            let mut skipped = vec![];
+            let mut open = true;
+            for path in paths {
+                skipped.extend(scan_each(path, filter, |jar, info| {
+                    if seen.insert(info.modid.to_string()) {
+                        open = each(jar, info);
+                    }
+                    open
+                })?);
+                if !open {
+                    break;
+                }
+            }
+            Ok(skipped)
+        }
+
+        /// A row for each plugin in the order it was printed, then those that couldn't be checked
+        /// Where there are groups, they're by status from the most to the least urgent.
+        pub(super) fn report(displays: &[VersionDisplay], failures: &[CheckFailure]) -> Report {
+            const UNABLE: &str = "Unable to check";
+            let label = |status: VersionStatus| match status {
+                VersionStatus::OutOfDate => "Out of date",
+                VersionStatus::UpToDate => "Up to date",
+                VersionStatus::Overdated => "Newer than remote",
+                VersionStatus::Unknown(_) => "Unknown",
+            };
+
+            let mut report = Report::new(vec![
+                "Plugin ID",
+                "Local Version",
+                "Remote Version",
+                "Status",
+                "URL",
+            ])
+            .with_title("Plugin Status");
+            let mut statuses = displays
+                .iter()
+                .map(|display| display.status)
+                .collect::<Vec<VersionStatus>>();
+            statuses.sort();
+            statuses
+                .iter()
+                .for_each(|status| report.group(label(*status)));
+            report.group(UNABLE);
+
+            for display in displays {
+                let status = match display.status {
+                    VersionStatus::Unknown(reason) => format!("Unknown, {}", reason),
+                    status => label(status).to_string(),
+                };
+                report.row_in(
+                    label(display.status),
+                    vec![
+                        Cell::linked(&display.id, display.url.clone()),
+                        Cell::from(display.local_version.as_str()),
+                        Cell::linked(&display.remote_version, display.download_url.clone()),
+                        Cell::from(status),
+                        Cell::linked(display.url.clone().unwrap_or_default(), display.url.clone()),
+                    ],
+                );
+            }
+            for failure in failures {
+                report.row_in(
+                    UNABLE,
+                    vec![
+                        failure.id.to_string(),
+                        failure.local_version.to_string(),
+                        String::new(),
+                        format!("{}, {}", UNABLE, failure.reason),
+                    ],
+                );
+            }
+            report
+        }
+
+        /// Sends a summary of the out of date plugins to the webhook, if there are any
+        async fn notify_outdated(
+            ore_client: &OreClient,
+            config: &Config,
+            url: &str,
+            displays: &[VersionDisplay],
+        ) {
+            let outdated = displays
+                .iter()
+                .filter(|display| display.status == VersionStatus::OutOfDate)
+                .map(VersionDisplay::outdated)
+                .collect::<Vec<OutdatedPlugin>>();
+            if outdated.is_empty() {
+                return;
+            }
+
+            let webhook = WebhookSink::new(
+                ore_client.http().clone(),
+                url.to_string(),
+                config.notify.format,
+            );
+            let body = format!("{} plugin(s) can be updated", outdated.len());
+            Notifier::silent()
+                .with_sink(webhook)
+                .notify(&Notification::new("Out of date plugins", body).with_plugins(outdated))
+                .await;
+        }
+
+        /// Renames each jar to match the template, jars that are already named correctly are left alone
+        fn normalize_files(
+            template: &NameTemplate,
+            files: Vec<(PathBuf, OreModInfo)>,
+        ) -> Result<Vec<(PathBuf, OreModInfo)>> {
+            files
+                .into_iter()
+                .map(|(path, info)| {
+                    let current = path
+                        .file_name()
+                        .and_then(|f| f.to_str())
+                        .unwrap_or_default();
+                    let renamed =
+                        path.with_file_name(template.render(&info.modid, &info.version, current));
+
+                    if renamed == path || renamed.exists() {
+                        return Ok((path, info));
+                    }
+
+                    fs::rename(&path, &renamed)?;
+                    info!("Renamed '{}' to '{}'", path.display(), renamed.display());
+                    Ok((renamed, info))
+                })
+                .collect()
+        }
+
+        /// Whether to apply the planned updates, asked once their summary is printed when there's someone to ask
+        fn confirmed(&self, plan: &UpdatePlan) -> Result<bool> {
+            if self.yes || self.dry_run || !io::stdin().is_terminal() {
+                return Ok(true);
+            }
+
+            let question = format!("Update {} plugin(s) ? [y/N] ", plan.updates.len());
+            // Nothing more to read, so nothing is changed
+            let confirmed = ask(&question)?
+                .is_some_and(|answer| matches!(answer.to_lowercase().as_str(), "y" | "yes"));
+            if !confirmed {
+                println!("No plugins were updated");
+            }
+            Ok(confirmed)
+        }
+
+        /// Installs each planned update in order
+        async fn apply_updates(
+            &self,
+            ore_client: &OreClient,
+            plan: &UpdatePlan,
+            jars: &[(String, PathBuf)],
+        ) -> Result<()> {
+            let dir = &self.changed_dir()?;
+
+            if self.dry_run {
+                for update in &plan.updates {
+                    println!(
+                        "Would update {} : {} -> {}",
+                        update.id, update.from, update.to
+                    );
+                }
+                return Ok(());
+            }
+
+            // A failed update undoes the ones before it, so the directory is never left half updated
+            let mut transaction = Transaction::begin(dir, &self.scan.filter())?;
+            for update in &plan.updates {
+                let replaced = Self::replace(
+                    ore_client,
+                    &mut transaction,
+                    dir,
+                    update,
+                    jars,
+                    self.accept_new_owner,
+                    self.permanent,
+                );
+                if let Err(e) = replaced.await {
+                    transaction.rollback()?;
+                    return Err(anyhow::Error::msg(format!(
+                        "Updating {} failed, nothing was changed : {}",
+                        update.id, e
+                    )));
+                }
+            }
+            transaction.commit()?;
+
+            for update in &plan.updates {
+                info!("Updated {} : {} -> {}", update.id, update.from, update.to);
+            }
+            Ok(())
+        }
+
+        /// Installs the update, discarding the replaced jar when the name changed
+        pub(super) async fn replace(
+            ore_client: &OreClient,
+            transaction: &mut Transaction,
+            dir: &Path,
+            update: &PlannedUpdate,
+            jars: &[(String, PathBuf)],
+            accept_new_owner: bool,
+            permanent: bool,
+        ) -> Result<()> {
+            let old = jars
+                .iter()
+                .find(|(id, _)| *id == update.id)
+                .map(|(_, path)| path);
+            if let Some(old) = old {
+                transaction.backup(old)?;
+            }
+
+            let installed = InstallCommand::new(&update.id, &update.to, dir)
+                .with_remote_id(&update.remote_id)
+                .with_accept_new_owner(accept_new_owner)
+                .install(ore_client)
+                .await?;
+
+            if let Some(old) = old.filter(|old| **old != installed && old.exists()) {
+                discard(old, permanent)?;
+            }
+            Ok(())
+        }
+
+        /// Scans every jar, then verifies and renames them as requested
+        async fn prepare_files(
+            &self,
+            ore_client: &OreClient,
+        ) -> Result<(Vec<(PathBuf, OreModInfo)>, Vec<UnreadableJar>)> {
+            let mut files = vec![];
+            let skipped = Self::scan_paths(&self.paths()?, &self.scan.filter(), |jar, info| {
+                files.push((jar, info));
+                true
+            })?;
+
+            let files = match self.verify {
+                true => self.verify_files(ore_client, files).await?,
+                false => files,
+            };
+
+            let files = match &self.normalize {
+                Some(template) => Self::normalize_files(template, files)?,
+                None => files,
+            };
+            Ok((files, skipped))
+        }
+
+        /// Quarantined jars are removed from the list as they're no longer installed
+        async fn verify_files(
+            &self,
+            ore_client: &OreClient,
+            files: Vec<(PathBuf, OreModInfo)>,
+        ) -> Result<Vec<(PathBuf, OreModInfo)>> {
+            let quarantine = Quarantine::for_dir(&self.changed_dir()?);
+            let config = Config::load()?;
+            let notifier = config.notify.notifier(ore_client.http());
+
+            let lookups = files
+                .iter()
+                .map(|(path, info)| {
+                    let plugin_source = config.plugin_source(&info.modid);
+                    async move {
+                        // Only Ore publishes an md5 to verify against
+                        if plugin_source.source != Source::Ore {
+                            debug!("Not verifying '{}', it's not from Ore", path.display());
+                            return None;
+                        }
+                        let id = plugin_source.id_or(&info.modid);
+                        match ore_client.version(id, &info.version).await {
+                            Ok(version) => version.file_info.md_5_hash,
+                            Err(e) => {
+                                warn!("Unable to verify '{}' : {}", path.display(), e);
+                                None
+                            }
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            let expected_hashes: Vec<Option<String>> = stream::iter(lookups)
+                .buffered(ore_client.concurrency())
+                .collect()
+                .await;
+
+            // Hashing is the slow part, so it's only done for jars that can be verified
+            let to_hash = files
+                .iter()
+                .zip(&expected_hashes)
+                .filter(|(_, expected)| expected.is_some())
+                .map(|((path, _), _)| path.clone())
+                .collect();
+            let mut hashes = hash_jars(to_hash).await.into_iter();
+
+            let mut verified = vec![];
+            for ((path, info), expected) in files.into_iter().zip(expected_hashes) {
+                let status = match &expected {
+                    Some(_) => {
+                        let actual = hashes.next().unwrap_or_else(|| {
+                            Err(anyhow::Error::msg("Missing hash, jar was not read"))
+                        })?;
+                        compare_or_quarantine(
+                            &path,
+                            "md5",
+                            expected.as_deref(),
+                            &actual,
+                            &quarantine,
+                            &notifier,
+                        )
+                        .await?
+                    }
+                    None => Verification::Unavailable,
+                };
+
+                if !matches!(status, Verification::Quarantined) {
+                    verified.push((path, info))
+                }
+            }
+            Ok(verified)
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for VersionCheckCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            if self.offline && ResponseCache::load().is_empty() {
+                return Err(anyhow::Error::msg(
+                    "Nothing is cached to check against, run `prefetch` while Ore can be reached, or copy its cache here",
+                ));
+            }
+            // Nothing is read when the changes asked for can't be made
+            if self.changes() {
+                self.changed_dir()?;
+            }
+            let (mut sender, receiver) = mpsc::channel::<(PathBuf, OreModInfo)>(Self::BUFFER);
+
+            // Verifying and renaming act on every jar first, otherwise jars are checked as they're read
+            let scanner = match self.verify || self.normalize.is_some() {
+                true => {
+                    let (files, skipped) = self.prepare_files(&ore_client).await?;
+                    task::spawn_blocking(move || {
+                        for file in files {
+                            if block_on(sender.send(file)).is_err() {
+                                break;
+                            }
+                        }
+                        Ok(skipped)
+                    })
+                }
+                false => {
+                    let paths = self.paths()?;
+                    let filter = self.scan.filter();
+                    task::spawn_blocking(move || {
+                        Self::scan_paths(&paths, &filter, |jar, info| {
+                            block_on(sender.send((jar, info))).is_ok()
+                        })
+                    })
+                }
+            };
+
+            let config = Config::load()?;
+            let modrinth = ModrinthClient::new(ore_client.http().clone());
+            let lockfile = match self.dir()? {
+                Some(dir) => Lockfile::load(&dir)?,
+                None => None,
+            };
+            let owned = owned_projects(&ore_client, &config, &lockfile).await;
+
+            let mut remotes = receiver
+                .map(|(jar, info)| async {
+                    let lookup = fetch_remote(
+                        &ore_client,
+                        &modrinth,
+                        &config,
+                        &owned,
+                        &info,
+                        VersionSelection {
+                            channel: self.channel,
+                            all_versions: self.all_versions,
+                        },
+                    );
+                    let remote = match self.timeout_per_plugin {
+                        Some(timeout) => {
+                            time::timeout(timeout, lookup).await.unwrap_or_else(|_| {
+                                Err(anyhow::Error::msg(format!("timed out after {:?}", timeout)))
+                            })
+                        }
+                        None => lookup.await,
+                    };
+                    (jar, info, remote)
+                })
+                .buffered(ore_client.concurrency());
+
+            // A plugin that fails is reported, the rest are still checked
+            let order = self.sort.unwrap_or_default();
+            let mut jars = vec![];
+            let mut infos = vec![];
+            let mut displays = vec![];
+            let mut failures = vec![];
+            while let Some(remote) = remotes.next().await {
+                let (jar, info, remote) = remote;
+                let remote = match remote {
+                    Ok(remote) => remote,
+                    Err(e) => {
+                        let failure = CheckFailure::new(&info, e);
+                        if order == ReportOrder::Scan {
+                            println!("{}", failure);
+                        }
+                        failures.push(failure);
+                        continue;
+                    }
+                };
+                let display = VersionDisplay::new((info.clone(), remote), lockfile.as_ref());
+                if order == ReportOrder::Scan {
+                    println!("{}", display);
+                }
+
+                jars.push((info.modid.to_string(), jar));
+                infos.push(info);
+                displays.push(display);
+            }
+            drop(remotes);
+            let skipped = scanner.await??;
+
+            // Any other order needs every plugin before the first can be printed
+            if order != ReportOrder::Scan {
+                order.sort(&mut displays);
+                failures.sort_by(|a, b| a.id.cmp(&b.id));
+                displays.iter().for_each(|display| println!("{}", display));
+                failures.iter().for_each(|failure| println!("{}", failure));
+            }
+
+            let mut disabled = vec![];
+            for path in self.paths()? {
+                disabled.extend(scan_disabled(&path, &self.scan.filter())?);
+            }
+            if !disabled.is_empty() {
+                println!("Disabled, not checked or updated :");
+                for (jar, info) in disabled {
+                    let file = jar.file_name().unwrap_or_default().to_string_lossy();
+                    println!("{} : {} ({})", info.modid, info.version, file);
+                }
+            }
+            if !skipped.is_empty() {
+                println!("Skipped, not a readable plugin jar :");
+                for unreadable in skipped {
+                    println!("{} : {}", unreadable.path.display(), unreadable.reason);
+                }
+            }
+            if let Some(since) = ore_client.offline_since() {
+                println!(
+                    "Offline : compared against Ore as it was {}",
+                    ago(since, ore_client.clock().now())
+                );
+            }
+            warn_end_of_life(&infos);
+
+            if let (Some(format), Some(out)) = (self.report, &self.out) {
+                let report =
+                    Self::report(&displays, &failures).with_timestamp(ore_client.clock().now());
+                fs::write(out, report.render(format))?;
+                info!("Report written to {}", out.display());
+            }
+
+            if let Some(url) = &self.notify_url {
+                Self::notify_outdated(&ore_client, &config, url, &displays).await;
+            }
+
+            let plan = UpdatePlan::new(&infos, &displays);
+            let mut updated = vec![];
+            if !plan.is_empty() {
+                self.print_res(&plan)?;
+
+                if self.update && self.confirmed(&plan)? {
+                    self.apply_updates(&ore_client, &plan, &jars).await?;
+                    if !self.dry_run {
+                        updated.extend(plan.updates.iter().map(|update| update.id.as_str()));
+                    }
+                }
+            }
+            CheckOutcome::of(&displays, &failures, &updated)
+        }
+    }
+
+    /// Returned once the report is printed when not every plugin is up to date, so scripts can tell from the exit code
+    #[derive(Debug, PartialEq)]
+    pub enum CheckOutcome {
+        /// How many plugins are out of date, and weren't updated
+        OutOfDate(usize),
+        /// How many plugins couldn't be checked, whatever the others are
+        Failed(usize),
+    }
+
+    impl CheckOutcome {
+        /// An error when a plugin failed or is still out of date, plugins failing take priority
+        fn of(
+            displays: &[VersionDisplay],
+            failures: &[CheckFailure],
+            updated: &[&str],
+        ) -> Result<()> {
+            let outdated = displays
+                .iter()
+                .filter(|display| display.status == VersionStatus::OutOfDate)
+                .filter(|display| !updated.contains(&display.id.as_str()))
+                .count();
+            match (failures.len(), outdated) {
+                (0, 0) => Ok(()),
+                (0, outdated) => Err(CheckOutcome::OutOfDate(outdated).into()),
+                (failed, _) => Err(CheckOutcome::Failed(failed).into()),
+            }
+        }
+
+        pub fn exit_code(&self) -> i32 {
+            match self {
+                CheckOutcome::OutOfDate(_) => 2,
+                CheckOutcome::Failed(_) => 10,
+            }
+        }
+    }
+
+    impl Display for CheckOutcome {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                CheckOutcome::OutOfDate(count) => write!(f, "{} plugin(s) out of date", count),
+                CheckOutcome::Failed(count) => write!(f, "{} plugin(s) couldn't be checked", count),
+            }
+        }
+    }
+
+    impl std::error::Error for CheckOutcome {}
+
+    pub(super) struct VersionDisplay {
+        pub(super) id: String,
+        pub(super) remote_id: String,
+        pub(super) local_version: String,
+        pub(super) remote_version: String,
+        pub(super) source: Source,
+        /// The locked version, only present when the jar has drifted from it
+        locked_version: Option<String>,
+        pub(super) status: VersionStatus,
+        pub(super) download_size: Option<f64>,
+        /// The project's page on its source
+        pub(super) url: Option<String>,
+        pub(super) download_url: Option<String>,
+        /// What the remote version depends on, when it was fetched along with the version
+        pub(super) dependencies: Option<Vec<PluginDependency>>,
+    }
+
+    impl VersionDisplay {
+        pub(super) fn new(
+            (local, remote): (OreModInfo, RemoteVersion),
+            lockfile: Option<&Lockfile>,
+        ) -> VersionDisplay {
+            let status = VersionStatus::new(&local.version, &remote.version);
+            let locked_version = lockfile
+                .and_then(|lock| lock.drift(&local.modid, &local.version))
+                .map(|locked| locked.version.to_string());
+            Self {
                 id: local.modid,
+                remote_id: remote.remote_id,
                 local_version: local.version,
-                remote_version: remote,
+                remote_version: remote.version,
+                source: remote.source,
+                locked_version,
                 status,
+                download_size: remote.download_size,
+                url: remote.url,
+                download_url: remote.download_url,
+                dependencies: remote.dependencies,
+            }
+        }
+
+        pub(super) fn outdated(&self) -> OutdatedPlugin {
+            OutdatedPlugin {
+                id: self.id.to_string(),
+                local_version: self.local_version.to_string(),
+                remote_version: self.remote_version.to_string(),
+            }
+        }
+
+        /// The jar was updated by hand to the version available on Ore
+        pub(super) fn manually_updated(&self) -> bool {
+            self.locked_version.as_deref().is_some_and(|locked| {
+                VersionStatus::new(locked, &self.local_version) == VersionStatus::OutOfDate
+                    && self.status == VersionStatus::UpToDate
+            })
+        }
+    }
+
+    /// A plugin that couldn't be checked, and why
+    pub(super) struct CheckFailure {
+        id: String,
+        local_version: String,
+        reason: String,
+    }
+
+    impl CheckFailure {
+        pub(super) fn new(local: &OreModInfo, reason: anyhow::Error) -> CheckFailure {
+            CheckFailure {
+                id: local.modid.to_string(),
+                local_version: local.version.to_string(),
+                reason: reason.to_string(),
+            }
+        }
+    }
+
+    impl Display for CheckFailure {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "ModID: {}", self.id)?;
+            writeln!(f, "Local Version : {}", self.local_version)?;
+            writeln!(f, "Version Status : error: {}", self.reason)
+        }
+    }
+
+    impl Display for VersionDisplay {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "ModID: {}", self.id)?;
+            if self.remote_id != self.id {
+                writeln!(f, "Matched as : {}", self.remote_id)?;
+            }
+            writeln!(f, "Local Version : {}", self.local_version)?;
+            writeln!(f, "Remote Version : {}", self.remote_version)?;
+            writeln!(f, "Source : {}", self.source)?;
+            if let Some(locked) = &self.locked_version {
+                writeln!(f, "Locked Version : {} (drifted)", locked)?;
+            }
+            if self.manually_updated() {
+                writeln!(f, "Jar was updated manually, run `reconcile` to adopt it")?;
+            }
+            writeln!(f, "Version Status : {}", self.status)
+        }
+    }
+}
+
+mod why_outdated_command {
+    use std::{
+        collections::HashMap,
+        fmt::Display,
+        fs::File,
+        io::BufReader,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        file_reader::FileReader,
+        ore::ore_client::OreClient,
+        ore_mod_info::OreModInfo,
+        query::Query,
+        source::Source,
+        sponge_schemas::{Project, ReleaseChannel},
+    };
+    use ore_monitor_common::version_status::VersionStatus;
+
+    use crate::{config::ore_config::Config, modrinth::modrinth_client::ModrinthClient};
+
+    use super::{
+        core_command::OreCommand,
+        version_check_command::{fetch_remote, ore_project, scan, ScanArgs, VersionSelection},
+    };
+
+    /// Explains how `check` reached its verdict for a single plugin
+    #[derive(Parser, Default)]
+    pub struct WhyOutdatedCommand {
+        /// The plugin id, as read from its jar
+        plugin_id: String,
+        /// The directory holding the plugin, or its jar, otherwise the configured plugins directory
+        path: Option<PathBuf>,
+        #[command(flatten)]
+        scan: ScanArgs,
+        /// The least stable Ore channel compared against, as given to `check`
+        #[arg(long)]
+        channel: Option<ReleaseChannel>,
+        /// Compares against the newest Ore version tagged for the plugin's Sponge API, as given to `check`
+        #[arg(long)]
+        all_versions: bool,
+    }
+
+    impl WhyOutdatedCommand {
+        pub fn apply_config(&mut self, config: &Config) {
+            self.path = self.path.take().or(config.plugins_dir.clone());
+        }
+
+        fn path(&self) -> &Path {
+            self.path.as_deref().unwrap_or(Path::new("."))
+        }
+
+        /// The jar holding the plugin, by the id read from its metadata
+        fn find_jar(&self) -> Result<PathBuf> {
+            scan(self.path(), &self.scan.filter())?
+                .into_iter()
+                .find(|(_, info)| info.modid.eq_ignore_ascii_case(&self.plugin_id))
+                .map(|(jar, _)| jar)
+                .ok_or_else(|| {
+                    anyhow::Error::msg(format!(
+                        "No jar in {} holds the plugin {}",
+                        self.path().display(),
+                        self.plugin_id
+                    ))
+                })
+        }
+    }
+
+    /// Where the remote version was looked up, and what was found there
+    pub(super) enum Lookup {
+        Ore {
+            project: Box<Project>,
+            /// How the project was found, ex: `its plugin id`
+            found_by: String,
+        },
+        Modrinth {
+            project_id: String,
+        },
+    }
+
+    /// Every step `check` takes for a plugin, from its jar to the version status
+    pub(super) struct Explanation {
+        pub(super) jar: PathBuf,
+        /// The file of the jar the metadata was parsed from
+        pub(super) metadata: String,
+        pub(super) info: OreModInfo,
+        pub(super) lookup: Lookup,
+        pub(super) selection: VersionSelection,
+        /// The version compared against, empty when Ore has none for the API
+        pub(super) remote_version: String,
+    }
+
+    impl Explanation {
+        fn status(&self) -> VersionStatus {
+            VersionStatus::new(&self.info.version, &self.remote_version)
+        }
+    }
+
+    impl Display for Explanation {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let api = self.info.major_api_version;
+            writeln!(f, "Jar : {}", self.jar.display())?;
+            writeln!(f, "Metadata : {}", self.metadata)?;
+            writeln!(f, "ModID : {}", self.info.modid)?;
+            writeln!(f, "Local Version : {}", self.info.version)?;
+            match api {
+                0 => writeln!(
+                    f,
+                    "Sponge API : none declared, only versions without a Sponge tag can match"
+                )?,
+                api => writeln!(f, "Sponge API : {}", api)?,
+            }
+
+            match &self.lookup {
+                Lookup::Ore { project, found_by } => {
+                    writeln!(f, "Source : Ore")?;
+                    writeln!(
+                        f,
+                        "Project : {} ({}/{}), found by {}",
+                        project.plugin_id,
+                        project.namespace.owner,
+                        project.namespace.slug,
+                        found_by
+                    )?;
+                    writeln!(f, "Promoted Versions :")?;
+                    let matched = project
+                        .promoted_in_channel(api, self.selection.channel)
+                        .filter(|_| !self.selection.all_versions)
+                        .map(|p| p.version.as_str());
+                    for promoted in &project.promoted_versions {
+                        let marker = match Some(promoted.version.as_str()) == matched {
+                            true => " <- matched",
+                            false => "",
+                        };
+                        writeln!(
+                            f,
+                            "  {} : API {}{}",
+                            promoted.version,
+                            promoted.sponge_major(),
+                            marker
+                        )?;
+                    }
+                    match (matched, self.remote_version.as_str()) {
+                        (Some(version), _) => writeln!(f, "Remote Version : {}", version)?,
+                        (None, "") => writeln!(
+                            f,
+                            "Remote Version : none, no version is tagged for API {}",
+                            api
+                        )?,
+                        (None, newest) => writeln!(
+                            f,
+                            "Remote Version : {}, the newest version tagged for API {}",
+                            newest, api
+                        )?,
+                    }
+                }
+                Lookup::Modrinth { project_id } => {
+                    writeln!(f, "Source : Modrinth ({})", project_id)?;
+                    writeln!(
+                        f,
+                        "Remote Version : {}, the latest listed",
+                        self.remote_version
+                    )?;
+                }
+            }
+
+            let status = self.status();
+            let comparison = match status {
+                VersionStatus::OutOfDate => "<",
+                VersionStatus::UpToDate => "=",
+                VersionStatus::Overdated => ">",
+                VersionStatus::Unknown(_) => "?",
+            };
+            let remote = match self.remote_version.as_str() {
+                "" => "(none)",
+                remote => remote,
+            };
+            writeln!(
+                f,
+                "Comparison : {} {} {}",
+                self.info.version, comparison, remote
+            )?;
+            writeln!(f, "Version Status : {}", status)
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for WhyOutdatedCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let jar = self.find_jar()?;
+            let file = File::open(&jar).map(BufReader::new)?;
+            let (info, metadata) = FileReader::read_jar_source(file, self.scan.filter().nested)?;
+
+            let config = Config::load()?;
+            let plugin_source = config.plugin_source(&info.modid);
+            let modrinth = ModrinthClient::new(ore_client.http().clone());
+            let selection = VersionSelection {
+                channel: self.channel,
+                all_versions: self.all_versions,
+            };
+
+            let (lookup, remote) = match plugin_source.source {
+                Source::Ore => {
+                    let configured = plugin_source.id.as_deref();
+                    let project = ore_project(&ore_client, configured, &info).await?;
+                    let found_by = match configured {
+                        Some(id) => format!("the id configured for it, {}", id),
+                        None if project.plugin_id.eq_ignore_ascii_case(&info.modid) => {
+                            "its plugin id".to_string()
+                        }
+                        None => format!("searching Ore for its name, {}", info.name),
+                    };
+                    // Handed over as already looked up, so check's lookup doesn't request it again
+                    let id = plugin_source.id_or(&info.modid).to_lowercase();
+                    let mut owned = HashMap::from([(id.to_string(), project)]);
+                    let remote =
+                        fetch_remote(&ore_client, &modrinth, &config, &owned, &info, selection)
+                            .await?;
+                    let lookup = Lookup::Ore {
+                        project: Box::new(owned.remove(&id).expect("the project was just added")),
+                        found_by,
+                    };
+                    (lookup, remote)
+                }
+                Source::Modrinth => {
+                    let project_id = plugin_source.id_or(&info.modid).to_string();
+                    let remote = fetch_remote(
+                        &ore_client,
+                        &modrinth,
+                        &config,
+                        &HashMap::new(),
+                        &info,
+                        selection,
+                    )
+                    .await?;
+                    (Lookup::Modrinth { project_id }, remote)
+                }
+            };
+
+            self.print_res(Explanation {
+                jar,
+                metadata,
+                info,
+                lookup,
+                selection,
+                remote_version: remote.version,
+            })
+        }
+    }
+}
+
+mod update_plan {
+    use human_bytes::human_bytes;
+    use ore_monitor::{
+        ore_mod_info::{OreModInfo, PluginDependency},
+        pairing::pair_by_id,
+        update_order::{order, satisfies},
+    };
+    use ore_monitor_common::version_status::VersionStatus;
+    use std::fmt::Display;
+
+    use super::version_check_command::VersionDisplay;
+
+    /// An out of date plugin, and what the version it's updated to depends on
+    pub(super) struct PlannedUpdate {
+        pub(super) id: String,
+        /// The id on the plugin's source
+        pub(super) remote_id: String,
+        pub(super) from: String,
+        pub(super) to: String,
+        /// How many bytes the update downloads, unknown when the source didn't say
+        pub(super) size: Option<f64>,
+        dependencies: Vec<PluginDependency>,
+    }
+
+    /// The updates of out of date plugins, ordered so dependencies are updated before their dependents
+    #[derive(Default)]
+    pub(super) struct UpdatePlan {
+        pub(super) updates: Vec<PlannedUpdate>,
+        /// Installed plugins whose declared version range an update would break
+        pub(super) warnings: Vec<String>,
+    }
+
+    impl PlannedUpdate {
+        /// An update to the display's remote version, without looking up what it depends on
+        pub(super) fn of(display: &VersionDisplay) -> PlannedUpdate {
+            PlannedUpdate {
+                id: display.id.to_string(),
+                remote_id: display.remote_id.to_string(),
+                from: display.local_version.to_string(),
+                to: display.remote_version.to_string(),
+                size: display.download_size,
+                dependencies: vec![],
+            }
+        }
+    }
+
+    impl UpdatePlan {
+        /// Plans updates from what was fetched when checking, without another request per plugin
+        pub(super) fn new(installed: &[OreModInfo], displays: &[VersionDisplay]) -> UpdatePlan {
+            let paired = pair_by_id(
+                installed,
+                displays,
+                |info| info.modid.as_str(),
+                |display| display.id.as_str(),
+            );
+
+            let mut updates = vec![];
+            for (info, display) in paired.pairs {
+                if display.status != VersionStatus::OutOfDate {
+                    continue;
+                }
+
+                // The new version's dependencies when they were fetched, otherwise the jar's are the best guess
+                let dependencies = display
+                    .dependencies
+                    .clone()
+                    .unwrap_or_else(|| info.dependencies.clone());
+
+                updates.push(PlannedUpdate {
+                    id: info.modid.to_string(),
+                    remote_id: display.remote_id.to_string(),
+                    from: info.version.to_string(),
+                    to: display.remote_version.to_string(),
+                    size: display.download_size,
+                    dependencies,
+                })
+            }
+
+            let warnings = Self::broken_ranges(installed, &updates);
+
+            let graph = updates
+                .iter()
+                .map(|update| {
+                    let local = installed.iter().find(|i| i.modid == update.id);
+                    let deps = update
+                        .dependencies
+                        .iter()
+                        .chain(local.map(|i| i.dependencies.as_slice()).unwrap_or_default())
+                        .map(|dep| dep.id.to_string())
+                        .collect();
+                    (update.id.to_string(), deps)
+                })
+                .collect::<Vec<(String, Vec<String>)>>();
+
+            let mut ordered = vec![];
+            for id in order(&graph) {
+                if let Some(pos) = updates.iter().position(|u| u.id == id) {
+                    ordered.push(updates.remove(pos))
+                }
+            }
+
+            UpdatePlan {
+                updates: ordered,
+                warnings,
+            }
+        }
+
+        /// Checks every installed plugin depending on an updated plugin still accepts the new version
+        /// A dependent that's also updated is checked against its new version's ranges
+        fn broken_ranges(installed: &[OreModInfo], updates: &[PlannedUpdate]) -> Vec<String> {
+            let mut warnings = vec![];
+            for update in updates {
+                for dependent in installed.iter().filter(|i| i.modid != update.id) {
+                    let deps = updates
+                        .iter()
+                        .find(|u| u.id == dependent.modid)
+                        .map(|u| &u.dependencies)
+                        .unwrap_or(&dependent.dependencies);
+
+                    let Some(dep) = deps.iter().find(|d| d.id == update.id) else {
+                        continue;
+                    };
+
+                    if !satisfies(&update.to, &dep.version) {
+                        warnings.push(format!(
+                            "Updating {} to {} would break {}, which requires {} {}",
+                            update.id, update.to, dependent.modid, update.id, dep.version
+                        ))
+                    }
+                }
+            }
+            warnings
+        }
+
+        pub(super) fn is_empty(&self) -> bool {
+            self.updates.is_empty()
+        }
+
+        /// The bytes the known sizes add up to, and how many updates are of an unknown size
+        pub(super) fn download_size(&self) -> (f64, usize) {
+            self.updates
+                .iter()
+                .fold((0.0, 0), |(total, unknown), update| match update.size {
+                    Some(size) => (total + size, unknown),
+                    None => (total, unknown + 1),
+                })
+        }
+    }
+
+    impl Display for UpdatePlan {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "Update Order :")?;
+            for update in &self.updates {
+                let size = update.size.map_or("size unknown".to_string(), human_bytes);
+                writeln!(
+                    f,
+                    "{} : {} -> {} ({})",
+                    update.id, update.from, update.to, size
+                )?;
+            }
+            let (total, unknown) = self.download_size();
+            match unknown {
+                0 => writeln!(f, "Download Size : {}", human_bytes(total))?,
+                _ => writeln!(
+                    f,
+                    "Download Size : {}, and {} of an unknown size",
+                    human_bytes(total),
+                    unknown
+                )?,
+            }
+            for warning in &self.warnings {
+                writeln!(f, "Warning : {}", warning)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+mod reconcile_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        file_hash::md5_hex,
+        lockfile::{LockedPlugin, Lockfile},
+        ore::ore_client::OreClient,
+        pairing::pair_by_id,
+        query::Query,
+    };
+    use std::path::{Path, PathBuf};
+    use tracing::warn;
+
+    use crate::config::ore_config::Config;
+
+    use super::{
+        core_command::OreCommand,
+        version_check_command::{fetch_remotes, scan, ScanArgs, VersionDisplay},
+    };
+
+    /// Adopts jars that were updated by hand into the lockfile
+    #[derive(Parser, Default)]
+    pub struct ReconcileCommand {
+        /// Directory containing the lockfile, otherwise the configured plugins directory or where it was ran from
+        dir: Option<PathBuf>,
+        #[command(flatten)]
+        scan: ScanArgs,
+    }
+
+    impl ReconcileCommand {
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            Some(self.dir().to_path_buf())
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            self.dir = self.dir.take().or(config.plugins_dir.clone());
+        }
+
+        fn dir(&self) -> &Path {
+            self.dir.as_deref().unwrap_or(Path::new("."))
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for ReconcileCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let mut lockfile = Lockfile::load(self.dir())?.ok_or_else(|| {
+                anyhow::Error::msg(format!("No lockfile found in '{}'", self.dir().display()))
+            })?;
+
+            let files = scan(self.dir(), &self.scan.filter())?;
+            let mut remotes = vec![];
+            for (id, remote) in fetch_remotes(&ore_client, &files).await? {
+                match remote {
+                    Ok(remote) => remotes.push(remote),
+                    Err(e) => warn!("Skipped {} : {}", id, e),
+                }
+            }
+            let paired = pair_by_id(
+                files,
+                remotes,
+                |(_, info)| info.modid.as_str(),
+                |remote| remote.id.as_str(),
+            );
+
+            let mut adopted = vec![];
+            for ((path, info), remote) in paired.pairs {
+                let display = VersionDisplay::new((info, remote), Some(&lockfile));
+                if !display.manually_updated() {
+                    continue;
+                }
+
+                let Some(locked) = lockfile.get(&display.id).cloned() else {
+                    continue;
+                };
+                let file = path.file_name().map(|f| f.to_string_lossy().to_string());
+                lockfile.insert(
+                    &display.id,
+                    LockedPlugin {
+                        version: display.local_version.to_string(),
+                        md5: Some(md5_hex(&path)?),
+                        file,
+                        url: None,
+                        disabled: false,
+                        owner: locked.owner.clone(),
+                        // Replaced by hand, so nothing is known of where it came from
+                        provenance: None,
+                    },
+                );
+                adopted.push(format!(
+                    "{} : {} -> {}",
+                    display.id, locked.version, display.local_version
+                ));
+            }
+
+            if adopted.is_empty() {
+                return self.print_res("Nothing to reconcile");
+            }
+
+            lockfile.save(self.dir())?;
+            self.print_res(format!("Adopted into the lockfile\n{}", adopted.join("\n")))
+        }
+    }
+}
+
+mod freeze_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        file_hash::md5_hex,
+        lockfile::{LockedPlugin, Lockfile},
+        manifest::{Manifest, ManifestEntry},
+        ore::ore_client::OreClient,
+        query::Query,
+        source::Source,
+    };
+    use std::path::{Path, PathBuf};
+    use tracing::warn;
+
+    use crate::{config::ore_config::Config, modrinth::modrinth_client::ModrinthClient};
+
+    use super::{
+        core_command::OreCommand,
+        version_check_command::{fetch_remotes, scan, RemoteVersion, ScanArgs},
+    };
+
+    /// Records the installed plugins in the lockfile, and optionally a manifest to install them elsewhere
+    #[derive(Parser, Default)]
+    pub struct FreezeCommand {
+        /// Directory of the plugins, otherwise the configured plugins directory or where it was ran from
+        dir: Option<PathBuf>,
+        /// Also writes a manifest pinning the exact versions, for `install --manifest`
+        #[arg(short, long)]
+        manifest: Option<PathBuf>,
+        #[command(flatten)]
+        scan: ScanArgs,
+    }
+
+    /// Where the installed version of a plugin is downloaded from, and the md5 its source publishes
+    struct Resolved {
+        url: String,
+        md5: Option<String>,
+        /// The owner of the Ore project
+        owner: Option<String>,
+    }
+
+    impl FreezeCommand {
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            Some(self.dir().to_path_buf())
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            self.dir = self.dir.take().or(config.plugins_dir.clone());
+        }
+
+        fn dir(&self) -> &Path {
+            self.dir.as_deref().unwrap_or(Path::new("."))
+        }
+
+        /// Looks up the installed version on the plugin's source
+        async fn resolve(
+            ore_client: &OreClient,
+            modrinth: &ModrinthClient,
+            remote: &RemoteVersion,
+            local_version: &str,
+        ) -> Result<Resolved> {
+            match remote.source {
+                Source::Ore => {
+                    let version = ore_client.version(&remote.remote_id, local_version).await?;
+                    let project = ore_client.project(&remote.remote_id).await?;
+                    Ok(Resolved {
+                        url: ore_client.download_url(&project, local_version),
+                        md5: version.file_info.md_5_hash,
+                        owner: Some(project.namespace.owner),
+                    })
+                }
+                Source::Modrinth => {
+                    let version = modrinth.version(&remote.remote_id, local_version).await?;
+                    let file = version.primary_file().ok_or_else(|| {
+                        anyhow::Error::msg(format!("Version {} has no files", local_version))
+                    })?;
+                    Ok(Resolved {
+                        url: file.url.to_string(),
+                        md5: None,
+                        owner: None,
+                    })
+                }
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for FreezeCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let modrinth = ModrinthClient::new(ore_client.http().clone());
+            let files = scan(self.dir(), &self.scan.filter())?;
+            let remotes = fetch_remotes(&ore_client, &files).await?;
+
+            // Entries of plugins that aren't scanned, like disabled ones, are kept as they were
+            let mut lockfile = Lockfile::load_or_default(self.dir())?;
+            let mut manifest = Manifest::default();
+            for ((path, info), (id, remote)) in files.iter().zip(remotes) {
+                let md5 = md5_hex(path)?;
+                let resolved = match remote {
+                    Ok(remote) => {
+                        match Self::resolve(&ore_client, &modrinth, &remote, &info.version).await {
+                            Ok(resolved) => {
+                                let entry = ManifestEntry::exact(
+                                    id.as_str(),
+                                    &info.version,
+                                    &remote.remote_id,
+                                );
+                                manifest.plugins.insert(id.to_string(), entry);
+                                Some(resolved)
+                            }
+                            Err(e) => {
+                                warn!("Unresolved {} {} : {}", id, info.version, e);
+                                None
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Unresolved {} : {}", id, e);
+                        None
+                    }
+                };
+
+                let published = resolved.as_ref().and_then(|r| r.md5.as_deref());
+                if published.is_some_and(|published| !published.eq_ignore_ascii_case(&md5)) {
+                    warn!(
+                        "{} doesn't match the md5 published for {} {}",
+                        path.display(),
+                        id,
+                        info.version
+                    );
+                }
+
+                // The provenance still holds while the jar is the one it was recorded for
+                let provenance = lockfile
+                    .get(&id)
+                    .filter(|locked| locked.md5.as_deref() == Some(md5.as_str()))
+                    .and_then(|locked| locked.provenance.clone());
+                lockfile.insert(
+                    &id,
+                    LockedPlugin {
+                        version: info.version.to_string(),
+                        md5: Some(md5),
+                        file: path.file_name().map(|f| f.to_string_lossy().to_string()),
+                        url: resolved.as_ref().map(|r| r.url.to_string()),
+                        disabled: false,
+                        owner: resolved.and_then(|r| r.owner),
+                        provenance,
+                    },
+                );
+            }
+
+            lockfile.save(self.dir())?;
+            let mut message = format!(
+                "Froze {} plugins into '{}'",
+                lockfile.plugins.len(),
+                Lockfile::path_in(self.dir()).display()
+            );
+            if let Some(path) = &self.manifest {
+                manifest.save(path)?;
+                message.push_str(&format!(
+                    "\nPinned {} plugins in '{}'",
+                    manifest.plugins.len(),
+                    path.display()
+                ));
+            }
+            self.print_res(message)
+        }
+    }
+}
+
+mod init_server_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, ValueEnum};
+    use ore_monitor::{
+        manifest::{Manifest, ManifestEntry},
+        ore::ore_client::OreClient,
+        query::Query,
+        query_builder,
+        sponge_schemas::{Category, Project, ProjectSortingStrategy},
+    };
+    use std::{
+        fs,
+        io::{self, IsTerminal},
+        path::PathBuf,
+    };
+    use tracing::info;
+
+    use super::{core_command::OreCommand, install_command::InstallCommand, prompt::ask};
+
+    /// Guides setting up a new server, picking plugins by category, then writing their manifest and installing it
+    #[derive(Parser)]
+    pub struct InitServerCommand {
+        /// The Sponge API major version of the server, only plugins promoting a version for it are offered, ex: `8`
+        #[arg(long)]
+        api: u32,
+        /// Directory of the server, the manifest is written to it and the plugins installed into its `mods` folder
+        #[arg(short, long, default_value = ".")]
+        dir: PathBuf,
+        /// A comma separated list of the categories to pick from, otherwise every category is offered
+        #[arg(short, long, value_delimiter = ',')]
+        category: Option<Vec<Category>>,
+        /// How many of the most downloaded projects of each category are looked at
+        #[arg(short, long, default_value_t = 10)]
+        limit: i64,
+    }
+
+    impl InitServerCommand {
+        const MANIFEST: &'static str = "plugins.toml";
+        const PLUGINS_DIR: &'static str = "mods";
+
+        /// The plugins are installed into the `mods` folder, the manifest is only written beside it
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            Some(self.dir.join(Self::PLUGINS_DIR))
+        }
+
+        /// The most downloaded projects of the category that promote a version for the API
+        async fn candidates(
+            &self,
+            ore_client: &OreClient,
+            category: &Category,
+        ) -> Result<Vec<Project>> {
+            let query = query_builder!(
+                "categories" : QueryType::Vec(Some(vec![category])),
+                "sort" : QueryType::Value(Some(ProjectSortingStrategy::Downloads)),
+                "limit" : QueryType::Value(Some(self.limit))
+            );
+            let projects = ore_client.projects(query).await?.result;
+            Ok(projects
+                .into_iter()
+                .filter(|project| !project.version_from_tag(self.api).is_empty())
+                .collect())
+        }
+
+        /// Lists the candidates and asks which to install, none are picked once there's nothing left to read
+        fn pick(&self, category: &Category, candidates: Vec<Project>) -> Result<Vec<Project>> {
+            eprintln!("\n{}", category);
+            for (i, project) in candidates.iter().enumerate() {
+                eprintln!(
+                    "{:>3}. {} ({}) {} - {} downloads",
+                    i + 1,
+                    project.name,
+                    project.plugin_id,
+                    project.version_from_tag(self.api),
+                    project.stats.downloads
+                );
+            }
+
+            loop {
+                let Some(answer) = ask("Install which ? ex: 1,3, nothing for none : ")? else {
+                    return Ok(vec![]);
+                };
+                match picked(&answer, candidates.len()) {
+                    Some(picked) => {
+                        return Ok(candidates
+                            .into_iter()
+                            .enumerate()
+                            .filter(|(i, _)| picked.contains(i))
+                            .map(|(_, project)| project)
+                            .collect())
+                    }
+                    None => eprintln!(
+                        "Answer the numbers of the list, from 1 to {}",
+                        candidates.len()
+                    ),
+                }
+            }
+        }
+    }
+
+    /// The indexes picked by their number in a list of `count` items, ex: `1,3` or `1 3`
+    /// None when a number isn't in the list.
+    fn picked(answer: &str, count: usize) -> Option<Vec<usize>> {
+        answer
+            .split([',', ' '])
+            .filter(|number| !number.is_empty())
+            .map(|number| {
+                number
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|number| (1..=count).contains(number))
+                    .map(|number| number - 1)
+            })
+            .collect()
+    }
+
+    #[async_trait]
+    impl OreCommand for InitServerCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            if !io::stdin().is_terminal() {
+                return Err(anyhow::Error::msg(
+                    "Plugins are picked interactively, write a manifest for `install --manifest` instead",
+                ));
+            }
+            let manifest_path = self.dir.join(Self::MANIFEST);
+            if manifest_path.exists() {
+                return Err(anyhow::Error::msg(format!(
+                    "'{}' already exists, install it with `install --manifest`",
+                    manifest_path.display()
+                )));
+            }
+
+            let categories = match &self.category {
+                Some(categories) => categories.clone(),
+                None => Category::value_variants().to_vec(),
+            };
+            let mut manifest = Manifest::default();
+            for category in &categories {
+                let candidates = self.candidates(&ore_client, category).await?;
+                if candidates.is_empty() {
+                    info!("{} has no plugins for API {}", category, self.api);
+                    continue;
+                }
+                for project in self.pick(category, candidates)? {
+                    let entry = ManifestEntry::Detailed {
+                        version: None,
+                        api: Some(self.api),
+                        id: None,
+                    };
+                    manifest.plugins.insert(project.plugin_id, entry);
+                }
+            }
+
+            if manifest.plugins.is_empty() {
+                return self.print_res("No plugins picked, nothing was written");
+            }
+
+            let plugins_dir = self.dir.join(Self::PLUGINS_DIR);
+            fs::create_dir_all(&plugins_dir)?;
+            manifest.save(&manifest_path)?;
+            info!(
+                "Wrote {} plugins to '{}'",
+                manifest.plugins.len(),
+                manifest_path.display()
+            );
+
+            InstallCommand::from_manifest(&manifest_path, &plugins_dir)
+                .handle(ore_client, None)
+                .await
+        }
+    }
+}
+
+mod lock_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{
+        lock_signature::{self, LockSignature},
+        lockfile::Lockfile,
+        ore::ore_client::OreClient,
+        query::Query,
+        table::{Align, Table},
+    };
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::config::ore_config::Config;
+
+    use super::core_command::OreCommand;
+
+    /// Signs lockfiles and verifies where their jars came from
+    #[derive(Parser)]
+    pub struct LockCommand {
+        #[command(subcommand)]
+        command: LockSubCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum LockSubCommand {
+        /// Generates the key lockfiles are signed with from then on, printing its public key
+        Keygen,
+        /// Signs the lockfile as it is, ex: after editing it by hand
+        Sign {
+            /// Directory of the lockfile, otherwise the configured plugins directory or where it was ran from
+            dir: Option<PathBuf>,
+        },
+        /// Verifies the lockfile's signature, then lists who installed each jar
+        Verify {
+            /// Directory of the lockfile, otherwise the configured plugins directory or where it was ran from
+            dir: Option<PathBuf>,
+            /// The base64 public key the lockfile must be signed with, otherwise the configured `lock_key`
+            #[arg(long)]
+            key: Option<String>,
+        },
+    }
+
+    impl LockCommand {
+        /// Only signing writes to the directory
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            match &self.command {
+                LockSubCommand::Sign { dir: given } => Some(dir(given).to_path_buf()),
+                _ => None,
+            }
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            match &mut self.command {
+                LockSubCommand::Keygen => (),
+                LockSubCommand::Sign { dir } => *dir = dir.take().or(config.plugins_dir.clone()),
+                LockSubCommand::Verify { dir, key } => {
+                    *dir = dir.take().or(config.plugins_dir.clone());
+                    *key = key.take().or(config.lock_key.clone());
+                }
+            }
+        }
+    }
+
+    fn dir(dir: &Option<PathBuf>) -> &Path {
+        dir.as_deref().unwrap_or(Path::new("."))
+    }
+
+    fn no_lockfile(dir: &Path) -> anyhow::Error {
+        anyhow::Error::msg(format!("No lockfile found in '{}'", dir.display()))
+    }
+
+    /// Lists the provenance of each locked jar, jars adopted or frozen have none
+    fn provenance(lockfile: &Lockfile) -> Table {
+        let mut table = Table::new(vec![
+            ("Plugin ID", Align::Left),
+            ("Version", Align::Left),
+            ("Installed", Align::Left),
+            ("Operator", Align::Left),
+            ("Session", Align::Left),
+            ("Tool", Align::Left),
+        ]);
+        for (id, locked) in &lockfile.plugins {
+            let mut row = vec![id.to_string(), locked.version.to_string()];
+            match &locked.provenance {
+                Some(provenance) => row.extend([
+                    provenance.installed_at.format("%Y-%m-%d %H:%M").to_string(),
+                    provenance.operator.clone().unwrap_or("-".to_string()),
+                    provenance.session.clone().unwrap_or("-".to_string()),
+                    provenance.tool.to_string(),
+                ]),
+                None => row.extend(["-", "-", "-", "-"].map(str::to_string)),
+            }
+            table.row(row);
+        }
+        table
+    }
+
+    #[async_trait]
+    impl OreCommand for LockCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            match &self.command {
+                LockSubCommand::Keygen => {
+                    let key = lock_signature::generate_key()?;
+                    self.print_res(format!(
+                        "Lockfiles are now signed when saved, verify them with\nore_monitor lock verify --key {}",
+                        key
+                    ))
+                }
+                LockSubCommand::Sign { dir: given } => {
+                    let dir = dir(given);
+                    if lock_signature::load_key()?.is_none() {
+                        return Err(anyhow::Error::msg(
+                            "No signing key, generate one with `lock keygen`",
+                        ));
+                    }
+                    Lockfile::load(dir)?
+                        .ok_or_else(|| no_lockfile(dir))?
+                        .save(dir)?;
+                    self.print_res(format!(
+                        "Signed '{}'",
+                        LockSignature::path_in(dir).display()
+                    ))
+                }
+                LockSubCommand::Verify { dir: given, key } => {
+                    let dir = dir(given);
+                    // The signature carries its own key, anyone editing the lockfile could re-sign it
+                    let key = key.as_deref().ok_or_else(|| {
+                        anyhow::Error::msg(
+                            "No trusted key, give the signer's public key with --key or `lock_key` in the config",
+                        )
+                    })?;
+                    let path = Lockfile::path_in(dir);
+                    if !path.is_file() {
+                        return Err(no_lockfile(dir));
+                    }
+                    let signature = LockSignature::load(dir)?.ok_or_else(|| {
+                        anyhow::Error::msg(format!("'{}' isn't signed", path.display()))
+                    })?;
+                    let contents = fs::read(&path)?;
+                    signature.verify(&contents, key)?;
+
+                    let lockfile = toml::from_str(&String::from_utf8(contents)?)?;
+                    self.print_res(format!(
+                        "Signed by {}\n\n{}",
+                        signature.key,
+                        provenance(&lockfile)
+                    ))
+                }
+            }
+        }
+    }
+}
+
+mod manifest_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{
+        file_hash::{checksum_lines, sha256_hex},
+        ore::ore_client::OreClient,
+        query::Query,
+    };
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::config::ore_config::Config;
+
+    use super::core_command::OreCommand;
+
+    /// Describes the plugins directory for tools other than ore-monitor
+    #[derive(Parser)]
+    pub struct ManifestCommand {
+        #[command(subcommand)]
+        command: ManifestSubCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum ManifestSubCommand {
+        /// Writes the sha256 of every jar in the format of `sha256sum`, for verifying backups
+        Checksums(ChecksumsCommand),
+    }
+
+    #[derive(Parser)]
+    struct ChecksumsCommand {
+        /// Directory of the plugins, otherwise the configured plugins directory or where it was ran from
+        dir: Option<PathBuf>,
+        /// File to write the checksums to, otherwise they're printed
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    }
+
+    impl ManifestCommand {
+        pub fn apply_config(&mut self, config: &Config) {
+            let ManifestSubCommand::Checksums(cmd) = &mut self.command;
+            cmd.dir = cmd.dir.take().or(config.plugins_dir.clone());
+        }
+    }
+
+    impl ChecksumsCommand {
+        fn dir(&self) -> &Path {
+            self.dir.as_deref().unwrap_or(Path::new("."))
+        }
+
+        /// The sha256 of each jar directly inside of the directory, named relative to it
+        fn checksums(&self) -> Result<Vec<(String, String)>> {
+            let mut sums = vec![];
+            for entry in fs::read_dir(self.dir())? {
+                let path = entry?.path();
+                if !path.is_file() || path.extension().is_none_or(|ext| ext != "jar") {
+                    continue;
+                }
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                sums.push((name.to_string(), sha256_hex(&path)?));
+            }
+            Ok(sums)
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for ManifestCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let ManifestSubCommand::Checksums(cmd) = &self.command;
+
+            let sums = cmd.checksums()?;
+            let count = sums.len();
+            let lines = checksum_lines(sums);
+
+            match &cmd.out {
+                Some(out) => {
+                    fs::write(out, lines)?;
+                    self.print_res(format!("Wrote {} checksums to '{}'", count, out.display()))
+                }
+                None => {
+                    print!("{}", lines);
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+mod toggle_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Args, Parser};
+    use ore_monitor::{
+        disabled_plugins::{disabled_path, enabled_path},
+        file_reader::FileReader,
+        lockfile::Lockfile,
+        ore::ore_client::OreClient,
+        ore_mod_info::OreModInfo,
+        query::Query,
+    };
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use crate::config::ore_config::Config;
+
+    use super::core_command::OreCommand;
+
+    /// Enables a disabled plugin, renaming its jar back so the server loads it
+    #[derive(Parser, Default)]
+    pub struct EnableCommand {
+        #[command(flatten)]
+        target: ToggleArgs,
+    }
+
+    /// Disables a plugin by renaming its jar, ex: `nucleus.jar` to `nucleus.jar.disabled`
+    #[derive(Parser, Default)]
+    pub struct DisableCommand {
+        #[command(flatten)]
+        target: ToggleArgs,
+    }
+
+    #[derive(Args, Default)]
+    struct ToggleArgs {
+        /// The plugin id of the plugin
+        plugin_id: String,
+        /// Directory of the plugins, otherwise the configured plugins directory or where it was ran from
+        #[arg(short, long)]
+        dir: Option<PathBuf>,
+    }
+
+    impl EnableCommand {
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            Some(self.target.dir().to_path_buf())
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            self.target.apply_config(config)
+        }
+    }
+
+    impl DisableCommand {
+        pub fn changes_dir(&self) -> Option<PathBuf> {
+            Some(self.target.dir().to_path_buf())
+        }
+
+        pub fn apply_config(&mut self, config: &Config) {
+            self.target.apply_config(config)
+        }
+    }
+
+    impl ToggleArgs {
+        fn apply_config(&mut self, config: &Config) {
+            self.dir = self.dir.take().or(config.plugins_dir.clone());
+        }
+
+        fn dir(&self) -> &Path {
+            self.dir.as_deref().unwrap_or(Path::new("."))
+        }
+
+        /// Finds the jar of the plugin, failing if it's missing or already in the wanted state
+        fn find(&self, disabled: bool) -> Result<(PathBuf, OreModInfo)> {
+            let scan = FileReader::from(self.dir()).scan_dir()?;
+            let find = |jars: Vec<(PathBuf, OreModInfo)>| {
+                jars.into_iter()
+                    .find(|(_, info)| info.modid.eq_ignore_ascii_case(&self.plugin_id))
+            };
+            let (found, other) = match disabled {
+                true => (scan.disabled, scan.entries),
+                false => (scan.entries, scan.disabled),
+            };
+            if let Some(jar) = find(found) {
+                return Ok(jar);
+            }
+            let state = if disabled { "enabled" } else { "disabled" };
+            let reason = match find(other) {
+                Some(_) => format!("{} is already {}", self.plugin_id, state),
+                None => format!(
+                    "No jar of {} found in '{}'",
+                    self.plugin_id,
+                    self.dir().display()
+                ),
+            };
+            Err(anyhow::Error::msg(reason))
+        }
+
+        /// Renames the jar, recording its new file and state in the lockfile when the plugin is locked
+        fn rename(&self, id: &str, from: &Path, to: &Path, disabled: bool) -> Result<String> {
+            if to.exists() {
+                return Err(anyhow::Error::msg(format!(
+                    "Unable to rename '{}', '{}' already exists",
+                    from.display(),
+                    to.display()
+                )));
+            }
+            fs::rename(from, to)?;
+
+            let file = to
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            if let Some(mut lockfile) = Lockfile::load(self.dir())? {
+                if let Some(locked) = lockfile.plugins.get_mut(id) {
+                    locked.file = Some(file.to_string());
+                    locked.disabled = disabled;
+                    lockfile.save(self.dir())?;
+                }
+            }
+            Ok(file)
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for EnableCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let (jar, info) = self.target.find(true)?;
+            let enabled = enabled_path(&jar).unwrap_or(jar.to_path_buf());
+            let file = self.target.rename(&info.modid, &jar, &enabled, false)?;
+            self.print_res(format!("Enabled {} : {}", info.modid, file))
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for DisableCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let (jar, info) = self.target.find(false)?;
+            let file = self
+                .target
+                .rename(&info.modid, &jar, &disabled_path(&jar), true)?;
+            self.print_res(format!("Disabled {} : {}", info.modid, file))
+        }
+    }
+}
+
+mod audit_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        ore::ore_client::OreClient, platform_support::PlatformSupport, query::Query,
+        scan_filter::ScanFilter,
+    };
+    use std::{fs, path::PathBuf};
+
+    use crate::config::ore_config::Config;
+
+    use super::{core_command::OreCommand, version_check_command::scan};
+
+    /// Flags a server on an end-of-life platform, and plugins that won't run on it
+    #[derive(Parser, Default)]
+    pub struct AuditCommand {
+        /// Directory of the plugins, otherwise the configured plugins directory or where it was ran from
+        dir: Option<PathBuf>,
+        /// The SpongeAPI of the server, otherwise detected from the Sponge jar next to the plugins directory
+        #[arg(long)]
+        api: Option<u32>,
+    }
+
+    impl AuditCommand {
+        pub fn apply_config(&mut self, config: &Config) {
+            self.dir = self.dir.take().or(config.plugins_dir.clone());
+        }
+
+        fn dir(&self) -> PathBuf {
+            self.dir.clone().unwrap_or(PathBuf::from("."))
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for AuditCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let support = PlatformSupport::load()?;
+            let dir = self.dir();
+
+            // The server jar sits next to the plugins directory, though some keep it alongside the plugins
+            let server_dir = fs::canonicalize(&dir)?
+                .parent()
+                .map(|parent| parent.to_path_buf());
+            let server = match self.api {
+                Some(api) => support.api(api),
+                None => server_dir
+                    .iter()
+                    .chain([&dir])
+                    .find_map(|dir| support.detect_server(dir)),
+            };
+
+            let mut report = vec![];
+            match (server, self.api) {
+                (Some(platform), _) if platform.is_end_of_life() => {
+                    report.push(format!("Server : {}, which is end-of-life", platform))
+                }
+                (Some(platform), _) => report.push(format!("Server : {}", platform)),
+                (None, Some(api)) => {
+                    report.push(format!("Server : API {}, unknown to the dataset", api))
+                }
+                (None, None) => report.push("Server : unable to detect, pass --api".to_string()),
+            }
+
+            let mut plugins = scan(&dir, &ScanFilter::default())?;
+            plugins.sort_by(|a, b| a.1.modid.cmp(&b.1.modid));
+            for (_, info) in plugins {
+                let api = info.major_api_version;
+                // Plugins that don't declare their API can't be audited
+                if api == 0 {
+                    continue;
+                }
+                if let Some(platform) = support.end_of_life(api) {
+                    report.push(format!(
+                        "{} only supports {}, which is end-of-life",
+                        info.modid, platform
+                    ));
+                }
+                if let Some(server) = server.filter(|server| server.api != api) {
+                    report.push(format!(
+                        "{} is built for API {}, the server runs API {}",
+                        info.modid, api, server.api
+                    ));
+                }
+            }
+
+            self.print_res(report.join("\n"))
+        }
+    }
+}
+
+mod identify_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        file_hash::md5_hex, file_reader::FileReader, ore::ore_client::OreClient,
+        ore_mod_info::OreModInfo, query::Query, query_builder, sponge_schemas::Project,
+    };
+    use std::{fmt::Display, path::PathBuf};
+    use tracing::info;
+
+    use super::core_command::OreCommand;
+
+    /// Identifies an unknown jar by its metadata and file hash
+    #[derive(Parser, Default)]
+    pub struct IdentifyCommand {
+        /// The jar to identify
+        file: PathBuf,
+        /// The maximum amount of search results to compare hashes against
+        #[arg(short, long)]
+        #[clap(default_value_t = 5)]
+        candidates: usize,
+    }
+
+    /// A project that may have produced the jar
+    struct Candidate {
+        plugin_id: String,
+        name: String,
+        matched_by: &'static str,
+        /// The version whose file hash matches the jar
+        version: Option<String>,
+    }
+
+    impl Display for Candidate {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{} ({}) matched by {}",
+                self.plugin_id, self.name, self.matched_by
+            )?;
+            match &self.version {
+                Some(version) => write!(f, " - file hash matches version {}", version),
+                None => write!(f, " - no version with a matching file hash"),
+            }
+        }
+    }
+
+    impl IdentifyCommand {
+        /// Looks the project up by the id found in the jar's metadata
+        async fn by_id(&self, ore_client: &OreClient, id: &str) -> Result<Option<Project>> {
+            ore_client.find_project(id).await
+        }
+
+        /// Searches Ore with the name of the plugin
+        async fn by_name(&self, ore_client: &OreClient, name: &str) -> Result<Vec<Project>> {
+            let query = query_builder!(
+                "q" : QueryType::Value(Some(name)),
+                "limit" : QueryType::Value(Some(self.candidates))
+            );
+            Ok(ore_client.projects(query).await?.result)
+        }
+
+        /// Walks every version of the project looking for a file with the same hash
+        async fn version_by_hash(
+            &self,
+            ore_client: &OreClient,
+            plugin_id: &str,
+            md5: &str,
+        ) -> Result<Option<String>> {
+            let mut offset = 0;
+
+            loop {
+                let query = query_builder!("offset" : QueryType::Value(Some(offset)));
+                let page = ore_client.versions(plugin_id, query).await?;
+
+                let found = page.result.iter().find(|version| {
+                    version
+                        .file_info
+                        .md_5_hash
+                        .as_deref()
+                        .is_some_and(|hash| hash.eq_ignore_ascii_case(md5))
+                });
+                if let Some(version) = found {
+                    return Ok(Some(version.name.to_string()));
+                }
+
+                offset += page.result.len() as i64;
+                if page.result.is_empty() || offset >= page.pagination.count {
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for IdentifyCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let md5 = md5_hex(&self.file)?;
+            let info: Option<OreModInfo> = FileReader::from(&self.file).handle_file(None).ok();
+
+            println!("File : {}", self.file.display());
+            println!("md_5 : {}", md5);
+
+            let name = match &info {
+                Some(info) => {
+                    println!("ModID : {}", info.modid);
+                    println!("Name : {}", info.name);
+                    println!("Version : {}", info.version);
+                    info.name.to_string()
+                }
+                None => {
+                    info!("No plugin metadata found, searching by file name");
+                    self.file
+                        .file_stem()
+                        .map(|f| f.to_string_lossy().to_string())
+                        .unwrap_or_default()
+                }
+            };
+
+            let mut projects: Vec<(Project, &'static str)> = vec![];
+            if let Some(info) = &info {
+                if let Some(project) = self.by_id(&ore_client, &info.modid).await? {
+                    projects.push((project, "id"))
+                }
+            }
+            for project in self.by_name(&ore_client, &name).await? {
+                if !projects
+                    .iter()
+                    .any(|(p, _)| p.plugin_id == project.plugin_id)
+                {
+                    projects.push((project, "name"))
+                }
+            }
+
+            let mut candidates = vec![];
+            for (project, matched_by) in projects.into_iter().take(self.candidates) {
+                let version = self
+                    .version_by_hash(&ore_client, &project.plugin_id, &md5)
+                    .await?;
+                candidates.push(Candidate {
+                    plugin_id: project.plugin_id,
+                    name: project.name,
+                    matched_by,
+                    version,
+                });
+            }
+
+            // Candidates with a matching file are the most trustworthy, so they're listed first
+            candidates.sort_by_key(|c| c.version.is_none());
+
+            if candidates.is_empty() {
+                return self.print_res("No matching projects found on Ore");
+            }
+
+            self.print_res(
+                candidates
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )
+        }
+    }
+}
+
+mod compare_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{ore::ore_client::OreClient, query::Query, sponge_schemas::Project};
+    use std::fmt::Display;
+
+    use super::core_command::OreCommand;
+
+    /// Compares several projects side by side
+    #[derive(Parser, Default)]
+    pub struct CompareCommand {
+        /// The plugin IDs to compare
+        #[arg(required = true, num_args = 2..)]
+        plugin_ids: Vec<String>,
+    }
+
+    #[async_trait]
+    impl OreCommand for CompareCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let responses = ore_client.projects_by_id(self.plugin_ids.clone()).await;
+
+            let mut comparison = Comparison::default();
+            for (id, res) in responses {
+                match res {
+                    Ok(project) => comparison.projects.push(project),
+                    Err(e) => comparison.errors.push((id, e.to_string())),
+                }
+            }
+
+            self.print_res(comparison)
+        }
+    }
+
+    #[derive(Default)]
+    struct Comparison {
+        projects: Vec<Project>,
+        errors: Vec<(String, String)>,
+    }
+
+    impl Comparison {
+        /// Each row is a label followed by the value of each project
+        fn rows(&self) -> Vec<(&'static str, Vec<String>)> {
+            let row = |label, value: &dyn Fn(&Project) -> String| {
+                (label, self.projects.iter().map(value).collect())
+            };
+            vec![
+                row("Plugin ID", &|p| p.plugin_id.to_string()),
+                row("Author", &|p| p.namespace.owner.to_string()),
+                row("Category", &|p| p.category.to_string()),
+                row("Downloads", &|p| p.stats.downloads.to_string()),
+                row("Recent Downloads", &|p| {
+                    p.stats.recent_downloads.to_string()
+                }),
+                row("Views", &|p| p.stats.views.to_string()),
+                row("Stars", &|p| p.stats.stars.to_string()),
+                row("Watchers", &|p| p.stats.watchers.to_string()),
+                row("Last Updated", &|p| {
+                    p.last_updated.format("%Y-%m-%d").to_string()
+                }),
+                row("API Versions", &|p| {
+                    p.api_versions()
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                }),
+                row("License", &|p| {
+                    p.settings
+                        .license
+                        .name
+                        .as_deref()
+                        .unwrap_or("Unknown")
+                        .to_string()
+                }),
+            ]
+        }
+    }
+
+    impl Display for Comparison {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let rows = self.rows();
+
+            let label_width = rows.iter().map(|(l, _)| l.len()).max().unwrap_or_default();
+            let widths: Vec<usize> = (0..self.projects.len())
+                .map(|i| {
+                    rows.iter()
+                        .map(|(_, v)| v[i].len())
+                        .max()
+                        .unwrap_or_default()
+                })
+                .collect();
+
+            for (label, values) in rows {
+                write!(f, "{:<label_width$} ", label)?;
+                for (value, width) in values.iter().zip(&widths) {
+                    write!(f, "| {:<width$} ", value)?;
+                }
+                writeln!(f)?;
+            }
+
+            if !self.errors.is_empty() {
+                writeln!(f, "\nErrors :")?;
+            }
+            self.errors
+                .iter()
+                .try_for_each(|(id, e)| writeln!(f, "{} : {}", id, e))
+        }
+    }
+}
+
+mod watch_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use clap::Parser;
+    use ore_monitor::{
+        clock::Clock,
+        duration::parse_interval,
+        instance_lock::DirLock,
+        ore::ore_client::OreClient,
+        pairing::pair_by_id,
+        query::Query,
+        query_builder,
+        scan_filter::ScanFilter,
+        source::Source,
+        sponge_schemas::{is_awaiting_review, Category, Project, ProjectSortingStrategy},
+        transaction::Transaction,
+    };
+    use ore_monitor_common::version_status::VersionStatus;
+    use std::{
+        collections::{HashMap, HashSet},
+        path::{Path, PathBuf},
+        sync::Arc,
+        time::Duration,
+    };
+    use tokio::task;
+    use tracing::{error, info, warn};
+
+    use crate::{
+        config::ore_config::{AutoUpdateConfig, Config, UpdatePolicy},
+        notify::notification::{Notification, Notifier},
+    };
+
+    use super::{
+        activity_command::{tracked_ids, Digest},
+        core_command::OreCommand,
+        pagination::all_versions,
+        update_plan::PlannedUpdate,
+        version_check_command::{
+            fetch_remotes, plugins_dir, scan, ScanArgs, VersionCheckCommand, VersionDisplay,
+        },
+    };
+
+    /// Keeps running, periodically polling Ore
+    #[derive(Parser, Default)]
+    pub struct WatchCommand {
+        /// How long to wait between polls, ex: 30m, 6h, 1d
+        #[arg(short, long, default_value = "6h", value_parser = parse_interval)]
+        interval: Duration,
+        /// A search to watch for new projects, can be repeated
+        #[arg(short, long)]
+        search: Vec<String>,
+        /// A comma separated list of Categories to watch for new projects
+        #[arg(short, long, value_delimiter = ',')]
+        category: Vec<Category>,
+        /// A plugin id whose versions are watched until they're reviewed, can be repeated
+        #[arg(short, long)]
+        review: Vec<String>,
+        /// A file or directory of jars to check each poll, notifying when a plugin becomes out of date
+        #[arg(short, long)]
+        plugins: Option<PathBuf>,
+        #[command(flatten)]
+        scan: ScanArgs,
+        /// Sends a digest of the plugins' activity this often, ex: 7d, see the activity command
+        #[arg(long, value_parser = parse_interval, requires = "plugins")]
+        digest: Option<Duration>,
+        /// Tells when digests are due, the client's clock unless given
+        #[arg(skip)]
+        pub(super) clock: Option<Arc<dyn Clock>>,
+    }
+
+    /// What has been seen by previous polls
+    pub(super) struct WatchState {
+        /// Projects created after this are new
+        /// It follows the newest project Ore returned, so changes to the local clock don't skip or repeat any.
+        since: DateTime<Utc>,
+        /// The review state of each version, keyed by `plugin_id:version`
+        review_states: HashMap<String, String>,
+        /// The status of each checked plugin, keyed by plugin id
+        statuses: HashMap<String, VersionStatus>,
+        /// When the last activity digest was sent, the first is sent a full period after starting
+        pub(super) last_digest: DateTime<Utc>,
+    }
+
+    impl WatchState {
+        /// Nothing seen yet, as of when watching started
+        pub(super) fn new(started: DateTime<Utc>) -> Self {
+            WatchState {
+                since: started,
+                review_states: HashMap::new(),
+                statuses: HashMap::new(),
+                last_digest: started,
+            }
+        }
+    }
+
+    impl WatchCommand {
+        /// The amount of newest projects inspected per search
+        const POLL_LIMIT: i64 = 25;
+
+        fn clock<'a>(&'a self, ore_client: &'a OreClient) -> &'a dyn Clock {
+            self.clock.as_deref().unwrap_or(ore_client.clock())
+        }
+
+        /// Every search, or a single search over the categories when no search is given
+        fn saved_searches(&self) -> Vec<Option<&String>> {
+            match self.search.is_empty() {
+                true => vec![None],
+                false => self.search.iter().map(Some).collect(),
+            }
+        }
+
+        /// Projects of the saved searches that were created after `since`
+        async fn new_projects(
+            &self,
+            ore_client: &OreClient,
+            since: DateTime<Utc>,
+        ) -> Result<Vec<Project>> {
+            let mut seen = HashSet::new();
+            let mut found = vec![];
+
+            for search in self.saved_searches() {
+                let query = query_builder!(
+                    "q" : QueryType::Value(search),
+                    "categories" : QueryType::Vec(Some(self.category.clone())),
+                    "sort" : QueryType::Value(Some(ProjectSortingStrategy::Newest)),
+                    "limit" : QueryType::Value(Some(Self::POLL_LIMIT))
+                );
+
+                let res = ore_client.projects(query).await?;
+
+                found.extend(
+                    res.result
+                        .into_iter()
+                        .filter(|p| p.created_at > since)
+                        .filter(|p| seen.insert(p.plugin_id.to_string())),
+                );
+            }
+
+            Ok(found)
+        }
+    }
+
+    impl WatchCommand {
+        async fn poll_projects(
+            &self,
+            ore_client: &OreClient,
+            notifier: &Notifier,
+            state: &mut WatchState,
+        ) -> Result<()> {
+            let projects = self.new_projects(ore_client, state.since).await?;
+
+            for project in &projects {
+                let body = format!(
+                    "{} by {} was created at {}",
+                    project.plugin_id, project.namespace.owner, project.created_at
+                );
+                notifier
+                    .notify(&Notification::new("New project", body))
+                    .await;
+            }
+
+            // Only advanced on success, so a failed poll's window is covered by the next one
+            if let Some(newest) = projects.iter().map(|p| p.created_at).max() {
+                state.since = state.since.max(newest);
+            }
+            Ok(())
+        }
+
+        /// Notifies when a version that was seen awaiting review becomes reviewed
+        async fn poll_reviews(
+            &self,
+            ore_client: &OreClient,
+            notifier: &Notifier,
+            state: &mut WatchState,
+        ) -> Result<()> {
+            for plugin_id in &self.review {
+                for version in all_versions(ore_client, plugin_id).await? {
+                    let key = format!("{}:{}", plugin_id, version.name);
+                    let previous = state
+                        .review_states
+                        .insert(key, version.review_state.to_string());
+
+                    if previous.is_some_and(|p| is_awaiting_review(&p))
+                        && !version.awaiting_review()
+                    {
+                        let body =
+                            format!("Version {} of {} was reviewed", version.name, plugin_id);
+                        notifier
+                            .notify(&Notification::new("Version reviewed", body))
+                            .await;
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Notifies of the plugins' activity once the window has passed since the last digest
+        pub(super) async fn send_digest(
+            &self,
+            ore_client: &OreClient,
+            notifier: &Notifier,
+            state: &mut WatchState,
+            path: &Path,
+            window: Duration,
+        ) -> Result<()> {
+            let clock = self.clock(ore_client);
+            let now = clock.now();
+            if now - state.last_digest < chrono::Duration::from_std(window)? {
+                return Ok(());
+            }
+            let ids = tracked_ids(path, self.scan.filter(), &[]).await?;
+            let digest = Digest::new(ore_client, ids, window, clock).await?;
+            notifier.notify(&digest.notification()).await;
+            state.last_digest = now;
+            Ok(())
+        }
+
+        /// Checks the jars, notifying of plugins that weren't out of date on the previous poll
+        /// Plugins already out of date on the first poll are notified as well.
+        /// Those whose category's policy is to update are updated first, see [AutoUpdateConfig].
+        async fn poll_versions(
+            &self,
+            ore_client: &OreClient,
+            notifier: &Notifier,
+            state: &mut WatchState,
+            path: &Path,
+            auto_update: &AutoUpdateConfig,
+        ) -> Result<()> {
+            let scanned = path.to_path_buf();
+            let filter = self.scan.filter();
+            let scan_filter = filter.clone();
+            let files = task::spawn_blocking(move || scan(&scanned, &scan_filter)).await??;
+            let remotes = fetch_remotes(ore_client, &files)
+                .await?
+                .into_iter()
+                .filter_map(|(id, remote)| match remote {
+                    Ok(remote) => Some(remote),
+                    Err(e) => {
+                        warn!("Unable to check {} : {}", id, e);
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let paired = pair_by_id(
+                files,
+                remotes,
+                |(_, info)| info.modid.as_str(),
+                |remote| remote.id.as_str(),
+            );
+
+            for ((jar, info), remote) in paired.pairs {
+                let display = VersionDisplay::new((info, remote), None);
+                let previous = state
+                    .statuses
+                    .insert(display.id.to_string(), display.status);
+
+                if display.status != VersionStatus::OutOfDate
+                    || previous == Some(VersionStatus::OutOfDate)
+                {
+                    continue;
+                }
+
+                let id = &display.id;
+                let updated = match Self::policy(ore_client, auto_update, &display).await {
+                    UpdatePolicy::Update => Self::update(ore_client, &display, &jar, path, &filter)
+                        .await
+                        .inspect_err(|e| {
+                            error!("Updating {} failed, it was left as it was : {}", id, e)
+                        })
+                        .is_ok(),
+                    UpdatePolicy::Notify => false,
+                };
+
+                if updated {
+                    state
+                        .statuses
+                        .insert(display.id.to_string(), VersionStatus::UpToDate);
+                    let body = format!(
+                        "{} was updated from {} to {}",
+                        display.id, display.local_version, display.remote_version
+                    );
+                    let notification = Notification::new("Plugin updated", body)
+                        .with_plugins(vec![display.outdated()]);
+                    notifier.notify(&notification).await;
+                } else {
+                    let body = format!(
+                        "{} can be updated from {} to {}",
+                        display.id, display.local_version, display.remote_version
+                    );
+                    let notification = Notification::new("Plugin out of date", body)
+                        .with_plugins(vec![display.outdated()]);
+                    notifier.notify(&notification).await;
+                }
+            }
+            Ok(())
+        }
+
+        /// The policy of the plugin's Ore category, plugins from elsewhere or whose project can't be fetched follow the default
+        pub(super) async fn policy(
+            ore_client: &OreClient,
+            auto_update: &AutoUpdateConfig,
+            display: &VersionDisplay,
+        ) -> UpdatePolicy {
+            // Without a policy by category, looking up the project wouldn't change anything
+            if auto_update.categories.is_empty() {
+                return auto_update.default;
+            }
+            let project = match display.source {
+                Source::Ore => ore_client.project(&display.remote_id).await.ok(),
+                Source::Modrinth => None,
+            };
+            auto_update.policy(project.as_ref().map(|project| &project.category))
+        }
+
+        /// Replaces the jar with its remote version, the same way `check --update` does
+        /// The directory is only locked while updating, so commands can still change it between polls.
+        pub(super) async fn update(
+            ore_client: &OreClient,
+            display: &VersionDisplay,
+            jar: &Path,
+            plugins: &Path,
+            filter: &ScanFilter,
+        ) -> Result<()> {
+            let dir = plugins_dir(plugins);
+            let _lock = DirLock::acquire(dir)?;
+
+            let update = PlannedUpdate::of(display);
+            let jars = [(display.id.to_string(), jar.to_path_buf())];
+            let mut transaction = Transaction::begin(dir, filter)?;
+            let replaced = VersionCheckCommand::replace(
+                ore_client,
+                &mut transaction,
+                dir,
+                &update,
+                &jars,
+                false,
+                false,
+            );
+            match replaced.await {
+                Ok(()) => transaction.commit(),
+                Err(e) => {
+                    transaction.rollback()?;
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for WatchCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let watch_projects = !self.search.is_empty() || !self.category.is_empty();
+            if !watch_projects && self.review.is_empty() && self.plugins.is_none() {
+                return Err(anyhow::Error::msg(
+                    "Nothing to watch, provide a search, category, plugin to review or plugins to check",
+                ));
+            }
+
+            let config = Config::load()?;
+            let notifier = config.notify.notifier(ore_client.http());
+            let mut interval = tokio::time::interval(self.interval);
+            let mut state = WatchState::new(self.clock(&ore_client).now());
+
+            info!("Watching Ore every {:?}", self.interval);
+
+            loop {
+                interval.tick().await;
+
+                if watch_projects {
+                    if let Err(e) = self.poll_projects(&ore_client, &notifier, &mut state).await {
+                        error!("Poll for new projects failed : {}", e)
+                    }
+                }
+                if let Err(e) = self.poll_reviews(&ore_client, &notifier, &mut state).await {
+                    error!("Poll for reviews failed : {}", e)
+                }
+                if let Some(plugins) = &self.plugins {
+                    if let Err(e) = self
+                        .poll_versions(
+                            &ore_client,
+                            &notifier,
+                            &mut state,
+                            plugins,
+                            &config.auto_update,
+                        )
+                        .await
+                    {
+                        error!("Poll for plugin versions failed : {}", e)
+                    }
+                }
+                if let (Some(window), Some(plugins)) = (self.digest, &self.plugins) {
+                    if let Err(e) = self
+                        .send_digest(&ore_client, &notifier, &mut state, plugins, window)
+                        .await
+                    {
+                        error!("Activity digest failed : {}", e)
+                    }
+                }
+            }
+        }
+    }
+}
+
+mod owner_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use futures::{stream, StreamExt};
+    use ore_monitor::{
+        ore::ore_client::OreClient, query::Query, query_builder, sponge_schemas::Project,
+    };
+    use std::fmt::Display;
+
+    use super::core_command::OreCommand;
+
+    /// Displays a dashboard of every project an owner maintains
+    #[derive(Parser, Default)]
+    pub struct OwnerCommand {
+        /// The name of the owner
+        owner: String,
+    }
+
+    impl OwnerCommand {
+        /// Follows the pages of the search until every project of the owner is retrieved
+        async fn projects(&self, ore_client: &OreClient) -> Result<Vec<Project>> {
+            let mut projects = vec![];
+
+            loop {
+                let query = query_builder!(
+                    "owner" : QueryType::Value(Some(&self.owner)),
+                    "offset" : QueryType::Value(Some(projects.len()))
+                );
+                let page = ore_client.projects(query).await?;
+
+                let done = page.result.is_empty();
+                projects.extend(page.result);
+                if done || projects.len() as i64 >= page.pagination.count {
+                    return Ok(projects);
+                }
+            }
+        }
+
+        /// The most recently published version of the project
+        async fn latest_version(&self, ore_client: &OreClient, plugin_id: &str) -> Result<String> {
+            let query = query_builder!("limit" : QueryType::Value(Some(1)));
+            let page = ore_client.versions(plugin_id, query).await?;
+            Ok(page
+                .result
+                .first()
+                .map(|v| v.name.to_string())
+                .unwrap_or_default())
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for OwnerCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let projects = self.projects(&ore_client).await?;
+
+            let client = &ore_client;
+            let rows = stream::iter(projects)
+                .map(|project| async move {
+                    let latest = self
+                        .latest_version(client, &project.plugin_id)
+                        .await
+                        .unwrap_or_else(|e| format!("error: {}", e));
+                    (project, latest)
+                })
+                .buffered(ore_client.concurrency())
+                .collect()
+                .await;
+
+            self.print_res(Portfolio {
+                owner: &self.owner,
+                rows,
+            })
+        }
+    }
+
+    struct Portfolio<'a> {
+        owner: &'a str,
+        rows: Vec<(Project, String)>,
+    }
+
+    impl Display for Portfolio<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.rows.is_empty() {
+                return write!(f, "No projects found for '{}'", self.owner);
+            }
+
+            writeln!(
+                f,
+                "{:<24} {:>10} {:>8} {:>6} {:>12} {:>12}  Latest Version",
+                "Plugin ID", "Downloads", "Recent", "Stars", "Views", "Recent Views"
+            )?;
+            for (p, latest) in &self.rows {
+                writeln!(
+                    f,
+                    "{:<24} {:>10} {:>8} {:>6} {:>12} {:>12}  {}",
+                    p.plugin_id,
+                    p.stats.downloads,
+                    p.stats.recent_downloads,
+                    p.stats.stars,
+                    p.stats.views,
+                    p.stats.recent_views,
+                    latest
+                )?;
+            }
+
+            let sum =
+                |stat: fn(&Project) -> i64| self.rows.iter().map(|(p, _)| stat(p)).sum::<i64>();
+            write!(
+                f,
+                "{} projects, {} downloads, {} stars",
+                self.rows.len(),
+                sum(|p| p.stats.downloads),
+                sum(|p| p.stats.stars)
+            )
+        }
+    }
+}
+
+mod publish_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        file_reader::FileReader, one_or_many::OneOrMany, ore::ore_client::OreClient, query::Query,
+        sponge_schemas::DeployVersionInfo,
+    };
+    use reqwest::multipart::{Form, Part};
+    use std::{collections::HashMap, fs, path::PathBuf};
+    use tracing::info;
+
+    use super::{core_command::OreCommand, project_inference::plugin_id_or_detect};
+
+    /// Uploads a new version of a project, requires a key with the `create_version` permission
+    #[derive(Parser, Default)]
+    pub struct PublishCommand {
+        /// The plugin id of the project to publish to, inferred from the jar or current directory if omitted
+        #[arg(short, long)]
+        project: Option<String>,
+        /// The plugin file to upload
+        #[arg(short, long)]
+        file: PathBuf,
+        /// A markdown file used as the description of the version
+        #[arg(short, long)]
+        changelog: Option<PathBuf>,
+        /// Creates a forum post announcing the version
+        #[arg(long)]
+        forum_post: bool,
+        /// A tag of the version as `name=value`, repeat a name to give it several values
+        #[arg(short, long, value_parser = parse_tag)]
+        tag: Vec<(String, String)>,
+    }
+
+    fn parse_tag(tag: &str) -> Result<(String, String), String> {
+        tag.split_once('=')
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .ok_or(format!("'{}' is not formatted as name=value", tag))
+    }
+
+    impl PublishCommand {
+        fn form(&self) -> Result<Form> {
+            let description = match &self.changelog {
+                Some(path) => fs::read_to_string(path)?,
+                None => String::new(),
+            };
+            let mut tags: HashMap<String, OneOrMany<String>> = HashMap::new();
+            for (name, value) in self.tag.iter().cloned() {
+                match tags.get_mut(&name) {
+                    Some(values) => values.push(value),
+                    None => {
+                        tags.insert(name, OneOrMany::One(value));
+                    }
+                }
+            }
+            let info = DeployVersionInfo::new(self.forum_post, description, tags);
+
+            let file_name = self
+                .file
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let file = Part::bytes(fs::read(&self.file)?)
+                .file_name(file_name)
+                .mime_str("application/java-archive")?;
+
+            Ok(Form::new()
+                .text("plugin-info", serde_json::to_string(&info)?)
+                .part("plugin-file", file))
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for PublishCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            // The jar being published knows its own id best
+            let project = match &self.project {
+                Some(project) => project.to_string(),
+                None => match FileReader::from(&self.file).handle_file(None) {
+                    Ok(info) => info.modid,
+                    Err(_) => plugin_id_or_detect(None)?,
+                },
+            };
+
+            let version = ore_client.publish_version(&project, self.form()?).await?;
+            info!("Published version {} of {}", version.name, project);
+            self.print_res(version.as_of(ore_client.clock().now()))
+        }
+    }
+}
+
+mod pagination {
+    use anyhow::Result;
+    use ore_monitor::{
+        ore::ore_client::OreClient,
+        query_builder,
+        sponge_schemas::{Paginated, Pagination, Version},
+    };
+    use std::future::Future;
+
+    /// Requests pages from the offset until every result is retrieved, joining them into one
+    /// `page` requests the page starting at the offset it's given.
+    pub(super) async fn follow<P, F, Fut>(offset: i64, mut page: F) -> Result<P>
+    where
+        P: Paginated,
+        F: FnMut(i64) -> Fut,
+        Fut: Future<Output = Result<P>>,
+    {
+        let mut results = vec![];
+
+        loop {
+            let (pagination, items) = page(offset + results.len() as i64).await?.into_parts();
+
+            let done = items.is_empty();
+            results.extend(items);
+            if done || offset + results.len() as i64 >= pagination.count {
+                let pagination = Pagination {
+                    limit: results.len() as i64,
+                    offset,
+                    count: pagination.count,
+                };
+                return Ok(P::from_parts(pagination, results));
+            }
+        }
+    }
+
+    /// Follows the pages of a project's versions until all of them are retrieved
+    pub(super) async fn all_versions(
+        ore_client: &OreClient,
+        plugin_id: &str,
+    ) -> Result<Vec<Version>> {
+        let versions = follow(0, |offset| {
+            let query = query_builder!("offset" : QueryType::Value(Some(offset)));
+            ore_client.versions(plugin_id, query)
+        })
+        .await?;
+        Ok(versions.into_parts().1)
+    }
+}
+
+mod release_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{file_reader::FileReader, ore::ore_client::OreClient, query::Query};
+    use ore_monitor_common::version_status::VersionStatus;
+    use std::path::PathBuf;
+
+    use super::{core_command::OreCommand, pagination::all_versions};
+
+    /// Tools for plugin authors releasing a new version
+    #[derive(Parser)]
+    pub struct ReleaseCommand {
+        #[command(subcommand)]
+        command: ReleaseSubCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum ReleaseSubCommand {
+        /// Fails if the jar's version already exists on Ore, or is lower than an existing version
+        Check(ReleaseCheckCommand),
+    }
+
+    #[derive(Parser)]
+    struct ReleaseCheckCommand {
+        /// The built jar to release
+        file: PathBuf,
+        /// The plugin id of the project, defaults to the id in the jar's metadata
+        #[arg(short, long)]
+        project: Option<String>,
+    }
+
+    #[async_trait]
+    impl OreCommand for ReleaseCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let ReleaseSubCommand::Check(cmd) = &self.command;
+
+            let info = FileReader::from(&cmd.file).handle_file(None)?;
+            let project = cmd.project.as_deref().unwrap_or(&info.modid);
+
+            let versions = all_versions(&ore_client, project).await?;
+
+            if let Some(existing) = versions
+                .iter()
+                .find(|v| VersionStatus::new(&info.version, &v.name) == VersionStatus::UpToDate)
+            {
+                return Err(anyhow::Error::msg(format!(
+                    "Version {} of {} already exists on Ore",
+                    existing.name, project
+                )));
+            }
+
+            if let Some(newer) = versions
+                .iter()
+                .find(|v| VersionStatus::new(&info.version, &v.name) == VersionStatus::OutOfDate)
+            {
+                return Err(anyhow::Error::msg(format!(
+                    "Version {} is lower than version {} already on Ore",
+                    info.version, newer.name
+                )));
+            }
+
+            self.print_res(format!(
+                "Version {} of {} is ready to be released",
+                info.version, project
+            ))
+        }
+    }
+}
+
+mod project_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{ore::ore_client::OreClient, query::Query};
+
+    use super::{core_command::OreCommand, pagination::all_versions};
+
+    /// Tools for authors managing their project
+    #[derive(Parser)]
+    pub struct ProjectCommand {
+        /// The plugin id of the project
+        plugin_id: String,
+        #[command(subcommand)]
+        command: ProjectSubCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum ProjectSubCommand {
+        /// Lists the versions still awaiting review
+        ReviewStatus,
+    }
+
+    #[async_trait]
+    impl OreCommand for ProjectCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let ProjectSubCommand::ReviewStatus = self.command;
+
+            let awaiting = all_versions(&ore_client, &self.plugin_id)
+                .await?
+                .into_iter()
+                .filter(|v| v.awaiting_review())
+                .map(|v| format!("{} : {}", v.name, v.review_state))
+                .collect::<Vec<String>>();
+
+            if awaiting.is_empty() {
+                return self.print_res("Every version has been reviewed");
+            }
+            self.print_res(awaiting.join("\n"))
+        }
+    }
+}
+
+mod version_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{
+        ore::ore_client::OreClient,
+        query::Query,
+        sponge_schemas::{EditableVersion, VersionStability},
+    };
+    use std::{fs, path::PathBuf};
+
+    use super::core_command::OreCommand;
+
+    /// Tools for authors managing published versions
+    #[derive(Parser)]
+    pub struct VersionCommand {
+        #[command(subcommand)]
+        command: VersionSubCommand,
+    }
+
+    #[derive(Subcommand)]
+    enum VersionSubCommand {
+        /// Edits the metadata of a published version
+        Edit(VersionEditCommand),
+    }
+
+    #[derive(Parser)]
+    struct VersionEditCommand {
+        /// The plugin id of the project
+        plugin_id: String,
+        /// The name of the version
+        version: String,
+        /// A markdown file replacing the description of the version
+        #[arg(short, long)]
+        description_file: Option<PathBuf>,
+        /// The stability channel of the version
+        #[arg(short, long)]
+        stability: Option<VersionStability>,
+    }
+
+    #[async_trait]
+    impl OreCommand for VersionCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let VersionSubCommand::Edit(cmd) = &self.command;
+
+            let edit = EditableVersion {
+                description: cmd
+                    .description_file
+                    .as_ref()
+                    .map(fs::read_to_string)
+                    .transpose()?,
+                stability: cmd.stability.clone(),
+            };
+
+            if edit.description.is_none() && edit.stability.is_none() {
+                return Err(anyhow::Error::msg(
+                    "Nothing to edit, provide a description file or stability",
+                ));
             }
+
+            let version = ore_client
+                .edit_version(&cmd.plugin_id, &cmd.version, &edit)
+                .await?;
+            self.print_res(version.as_of(ore_client.clock().now()))
         }
     }
+}
 
-    impl Display for VersionDisplay {
+mod project_inference {
+    use anyhow::Result;
+    use ore_monitor::project_detect::detect_plugin_id;
+    use std::path::Path;
+
+    /// Uses the given plugin id, otherwise infers it from the plugin project in the current directory
+    pub(super) fn plugin_id_or_detect(plugin_id: Option<&str>) -> Result<String> {
+        match plugin_id {
+            Some(id) => Ok(id.to_string()),
+            None => detect_plugin_id(Path::new(".")).ok_or_else(|| {
+                anyhow::Error::msg(
+                    "No plugin id given, and none could be inferred from the current directory",
+                )
+            }),
+        }
+    }
+}
+
+mod prompt {
+    use anyhow::Result;
+    use std::io::{self, Write};
+
+    /// Asks a question on the terminal, returning the trimmed answer or none once there's nothing left to read
+    /// The question is written to stderr, like other messages that aren't a command's result.
+    pub(super) fn ask(question: &str) -> Result<Option<String>> {
+        eprint!("{}", question);
+        io::stderr().flush()?;
+        let mut answer = String::new();
+        if io::stdin().read_line(&mut answer)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(answer.trim().to_string()))
+    }
+}
+
+mod open_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        ore::ore_client::{OreClient, ORE_SITE},
+        query::Query,
+    };
+    use std::process::Command;
+    use tracing::warn;
+
+    use super::{core_command::OreCommand, project_inference::plugin_id_or_detect};
+
+    /// Opens the Ore page of a project in the browser
+    #[derive(Parser, Default)]
+    pub struct OpenCommand {
+        /// The plugin id, inferred from the plugin project in the current directory if omitted
+        plugin_id: Option<String>,
+    }
+
+    impl OpenCommand {
+        #[cfg(target_os = "windows")]
+        const OPENER: (&'static str, &'static [&'static str]) = ("cmd", &["/C", "start", ""]);
+        #[cfg(target_os = "macos")]
+        const OPENER: (&'static str, &'static [&'static str]) = ("open", &[]);
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        const OPENER: (&'static str, &'static [&'static str]) = ("xdg-open", &[]);
+    }
+
+    #[async_trait]
+    impl OreCommand for OpenCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let plugin_id = plugin_id_or_detect(self.plugin_id.as_deref())?;
+
+            let project = ore_client.project(&plugin_id).await?;
+
+            let url = format!(
+                "{}/{}/{}",
+                ORE_SITE, project.namespace.owner, project.namespace.slug
+            );
+
+            let (program, args) = Self::OPENER;
+            // The link is still printed so it can be followed when no browser is available
+            if let Err(e) = Command::new(program).args(args).arg(&url).spawn() {
+                warn!("Unable to open a browser : {}", e)
+            }
+            self.print_res(url)
+        }
+    }
+}
+
+mod user_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        ore::ore_client::OreClient, query::Query, query_builder,
+        sponge_schemas::ProjectSortingStrategy,
+    };
+
+    use super::core_command::OreCommand;
+
+    /// Displays a user and the projects they're a member of
+    #[derive(Parser, Default)]
+    pub struct UserCommand {
+        /// The name of the user
+        user: String,
+        /// How to sort the projects
+        #[arg(short, long)]
+        sort: Option<ProjectSortingStrategy>,
+        /// The maximum amount of projects to display
+        #[arg(short, long)]
+        limit: Option<i64>,
+        /// Where to begin displaying the list from
+        #[arg(long)]
+        #[clap(default_value_t = 0)]
+        offset: u64,
+    }
+
+    #[async_trait]
+    impl OreCommand for UserCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let user = ore_client.user(&self.user).await?;
+            self.print_res(user)?;
+
+            let query = query_builder!(
+                "sort" : QueryType::Value(self.sort.as_ref()),
+                "limit" : QueryType::Value(self.limit),
+                "offset" : QueryType::Value(Some(self.offset))
+            );
+
+            let projects = ore_client.user_projects(&self.user, query).await?;
+
+            self.print_res(format!(
+                "\nProjects : {}\n{}",
+                projects.pagination.count, projects
+            ))
+        }
+    }
+}
+
+mod prefetch_command {
+    use std::path::{Path, PathBuf};
+
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use futures::{stream, StreamExt};
+    use ore_monitor::{ore::ore_client::OreClient, query::Query, source::Source};
+    use tracing::warn;
+
+    use crate::config::ore_config::Config;
+
+    use super::{
+        core_command::OreCommand,
+        version_check_command::{fetch_remotes, scan, ScanArgs},
+    };
+
+    /// Looks up everything `check`, `plugin` and `install` need for the installed plugins
+    /// The client refreshes the response cache as it goes, reading the jars fills the scan cache.
+    #[derive(Parser)]
+    pub struct PrefetchCommand {
+        /// A jar, or directory of jars, otherwise the configured plugins directory
+        #[arg(short, long)]
+        path: Option<PathBuf>,
+        #[command(flatten)]
+        scan: ScanArgs,
+    }
+
+    impl PrefetchCommand {
+        pub fn apply_config(&mut self, config: &Config) {
+            self.path = self.path.take().or(config.plugins_dir.clone());
+        }
+
+        fn path(&self) -> &Path {
+            self.path.as_deref().unwrap_or(Path::new("."))
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for PrefetchCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let files = scan(self.path(), &self.scan.filter())?;
+            let remotes = fetch_remotes(&ore_client, &files).await?;
+
+            // The installed version is looked up when verifying, the latest when planning and installing updates
+            let mut versions = vec![];
+            for ((_, info), (id, remote)) in files.iter().zip(remotes) {
+                match remote {
+                    Ok(remote) if remote.source == Source::Ore => {
+                        versions.push((info.modid.to_string(), info.version.to_string()));
+                        versions.push((remote.remote_id, remote.version));
+                    }
+                    Ok(_) => (),
+                    Err(e) => warn!("Skipped {} : {}", id, e),
+                }
+            }
+
+            // A version missing from Ore is only missing from the cache as well
+            stream::iter(versions)
+                .map(|(id, version)| {
+                    let ore_client = &ore_client;
+                    async move { ore_client.version(&id, &version).await }
+                })
+                .buffered(ore_client.concurrency())
+                .for_each(|_| async {})
+                .await;
+
+            let cached = ore_client.save_responses()?;
+            let message = format!(
+                "Prefetched {} plugins, {} responses cached",
+                files.len(),
+                cached
+            );
+            self.print_res(message)
+        }
+    }
+}
+
+mod cache_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{ore::ore_client::OreClient, query::Query, response_cache::ResponseCache};
+
+    use super::core_command::OreCommand;
+
+    /// Manages the cached responses of Ore
+    #[derive(Parser)]
+    pub struct CacheCommand {
+        #[command(subcommand)]
+        action: CacheAction,
+    }
+
+    #[derive(Subcommand)]
+    enum CacheAction {
+        /// Forgets every cached response, so the next commands ask Ore again
+        Clear,
+    }
+
+    #[async_trait]
+    impl OreCommand for CacheCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            match &self.action {
+                CacheAction::Clear => {
+                    let cleared = ResponseCache::clear()?;
+                    self.print_res(format!("Cleared {} cached responses", cleared))
+                }
+            }
+        }
+    }
+}
+
+mod config_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use ore_monitor::{ore::ore_client::OreClient, query::Query};
+
+    use crate::config::ore_config::Config;
+
+    use super::core_command::OreCommand;
+
+    /// Reads and changes the config file
+    #[derive(Parser)]
+    pub struct ConfigCommand {
+        #[command(subcommand)]
+        action: ConfigAction,
+    }
+
+    #[derive(Subcommand)]
+    enum ConfigAction {
+        /// Displays the value of a key, nested keys are separated by dots
+        Get { key: String },
+        /// Sets the value of a key, nested keys are separated by dots
+        Set { key: String, value: String },
+        /// Displays where the config file is located
+        Path,
+    }
+
+    #[async_trait]
+    impl OreCommand for ConfigCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            match &self.action {
+                ConfigAction::Get { key } => match Config::get_value(key)? {
+                    Some(value) => self.print_res(value),
+                    None => self.print_res(format!("'{}' is not set", key)),
+                },
+                ConfigAction::Set { key, value } => {
+                    Config::set_value(key, value)?;
+                    self.print_res(format!("Set '{}'", key))
+                }
+                ConfigAction::Path => match Config::path() {
+                    Some(path) => self.print_res(path.display()),
+                    None => Err(anyhow::Error::msg("No config directory on this platform")),
+                },
+            }
+        }
+    }
+}
+
+mod debug_bundle_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        ore::{ore_auth::ORE_API, ore_client::OreClient},
+        query::Query,
+        request_context::RequestContext,
+        storage,
+    };
+
+    use crate::config::ore_config::Config;
+
+    use super::core_command::OreCommand;
+
+    /// Gathers what's needed to look into a problem, leaving out the api key and session
+    #[derive(Parser)]
+    pub struct DebugBundleCommand {}
+
+    #[async_trait]
+    impl OreCommand for DebugBundleCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let config = Config::load()?;
+            let path = |path: Option<std::path::PathBuf>| {
+                path.map(|path| path.display().to_string())
+                    .unwrap_or("none".to_string())
+            };
+
+            let mut bundle = vec![
+                format!("ore-monitor {}", env!("CARGO_PKG_VERSION")),
+                format!(
+                    "Platform : {} {}",
+                    std::env::consts::OS,
+                    std::env::consts::ARCH
+                ),
+                format!(
+                    "Base URL : {}",
+                    config.base_url.as_deref().unwrap_or(ORE_API)
+                ),
+                format!("Config   : {}", path(Config::path())),
+                format!(
+                    "Storage  : {:?} in {}",
+                    config.storage,
+                    path(storage::dir())
+                ),
+                String::new(),
+            ];
+            match RequestContext::load() {
+                Ok(Some(context)) => bundle.push(format!("Last request\n{}", context)),
+                Ok(None) => bundle.push("No request was sent yet".to_string()),
+                Err(e) => bundle.push(format!("Unable to read the last request : {}", e)),
+            }
+            self.print_res(bundle.join("\n"))
+        }
+    }
+}
+
+pub mod ping_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::Parser;
+    use ore_monitor::{
+        ore::{
+            ore_auth::{request_session, ORE_API},
+            ore_client::OreClient,
+        },
+        query::Query,
+    };
+    use reqwest::{header::AUTHORIZATION, Client, StatusCode};
+    use std::{error::Error, fmt::Display, time::Instant};
+
+    use crate::config::ore_config::Config;
+
+    use super::core_command::OreCommand;
+
+    /// Checks Ore can be reached and authenticated with
+    #[derive(Parser, Default)]
+    pub struct PingCommand {}
+
+    /// Where a probe failed, each has its own exit code
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ProbeStage {
+        Dns,
+        Tls,
+        Auth,
+        Http,
+    }
+
+    /// Returned when Ore couldn't be reached, so the process exits with the stage's code
+    #[derive(Debug)]
+    pub struct ProbeFailed {
+        stage: ProbeStage,
+        reason: String,
+    }
+
+    impl ProbeFailed {
+        /// Classifies the error by its status, otherwise by what the connection failed at
+        fn new(error: reqwest::Error) -> ProbeFailed {
+            let mut causes = vec![error.to_string()];
+            let mut source = error.source();
+            while let Some(cause) = source {
+                causes.push(cause.to_string());
+                source = cause.source();
+            }
+            let lower = causes.join(" : ").to_lowercase();
+
+            let stage = match error.status() {
+                Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => ProbeStage::Auth,
+                Some(_) => ProbeStage::Http,
+                None if lower.contains("dns error") || lower.contains("lookup address") => {
+                    ProbeStage::Dns
+                }
+                None if ["certificate", "tls", "ssl", "handshake"]
+                    .iter()
+                    .any(|tls| lower.contains(tls)) =>
+                {
+                    ProbeStage::Tls
+                }
+                None => ProbeStage::Http,
+            };
+
+            ProbeFailed {
+                stage,
+                reason: error.to_string(),
+            }
+        }
+
+        pub fn exit_code(&self) -> i32 {
+            match self.stage {
+                ProbeStage::Dns => 2,
+                ProbeStage::Tls => 3,
+                ProbeStage::Auth => 4,
+                ProbeStage::Http => 5,
+            }
+        }
+    }
+
+    impl Display for ProbeFailed {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            writeln!(f, "ModID: {}", self.id)?;
-            writeln!(f, "Local Version : {}", self.local_version)?;
-            writeln!(f, "Remote Version : {}", self.remote_version)?;
-            writeln!(f, "Version Status : {}", self.status)
+            let stage = match self.stage {
+                ProbeStage::Dns => "DNS lookup",
+                ProbeStage::Tls => "TLS handshake",
+                ProbeStage::Auth => "Authentication",
+                ProbeStage::Http => "HTTP request",
+            };
+            write!(f, "{} failed : {}", stage, self.reason)
+        }
+    }
+
+    impl Error for ProbeFailed {}
+
+    impl PingCommand {
+        /// Requests the first project, as a sample of what other commands send
+        async fn sample(
+            client: &Client,
+            base_url: &str,
+            session: &str,
+        ) -> Result<reqwest::Response, reqwest::Error> {
+            client
+                .get(format!("{}/projects", base_url))
+                .query(&[("limit", "1")])
+                .header(AUTHORIZATION, format!("OreApi session={}", session))
+                .send()
+                .await?
+                .error_for_status()
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for PingCommand {
+        async fn handle(&self, _ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let config = Config::load()?;
+            let client = config.http.client()?;
+            let base_url = config.base_url.clone().unwrap_or(ORE_API.to_string());
+            let api_key = config
+                .api_key()
+                .ok_or_else(|| anyhow::Error::msg("An api key is required to ping Ore"))?;
+
+            println!("Pinging {}", base_url);
+
+            // The session isn't taken from the cache, as authenticating is part of what's measured
+            let start = Instant::now();
+            let session = request_session(&client, &base_url, &api_key)
+                .await
+                .map_err(|e| match e.downcast::<reqwest::Error>() {
+                    Ok(e) => ProbeFailed::new(e).into(),
+                    Err(e) => e,
+                })?;
+            println!("Authenticated in {:?}", start.elapsed());
+
+            let start = Instant::now();
+            let res = Self::sample(&client, &base_url, &session.session)
+                .await
+                .map_err(ProbeFailed::new)?;
+            println!("Sample request took {:?}", start.elapsed());
+
+            println!("Status : {}", res.status());
+            println!("Version : {:?}", res.version());
+            for (name, value) in res.headers() {
+                println!("{} : {}", name, value.to_str().unwrap_or("<binary>"));
+            }
+            Ok(())
+        }
+    }
+}
+
+mod watchlist_command {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use clap::{Parser, Subcommand};
+    use futures::{stream, StreamExt};
+    use ore_monitor::{
+        ore::ore_client::OreClient,
+        query::Query,
+        query_builder,
+        watchlist::{SeenVersion, Watchlist},
+    };
+
+    use super::core_command::OreCommand;
+
+    /// Follows plugins that aren't installed, reporting the versions they publish
+    #[derive(Parser)]
+    pub struct WatchlistCommand {
+        #[command(subcommand)]
+        action: WatchlistAction,
+    }
+
+    #[derive(Subcommand)]
+    enum WatchlistAction {
+        /// Starts watching plugins by their plugin id
+        Add {
+            #[arg(required = true)]
+            plugin_ids: Vec<String>,
+        },
+        /// Stops watching plugins
+        Remove {
+            #[arg(required = true)]
+            plugin_ids: Vec<String>,
+        },
+        /// Lists the watched plugins, with the newest version seen of each
+        List,
+        /// Reports the versions each watched plugin published since the last check
+        Check,
+    }
+
+    impl WatchlistCommand {
+        /// How many of the latest versions are looked at for new ones
+        const VERSION_LIMIT: i64 = 25;
+
+        /// Only checking contacts Ore, the rest manage the list
+        pub fn is_local(&self) -> bool {
+            !matches!(self.action, WatchlistAction::Check)
+        }
+
+        /// A line for each watched plugin, saving the newest versions seen
+        pub(super) async fn check(
+            ore_client: &OreClient,
+            watchlist: &mut Watchlist,
+        ) -> Vec<String> {
+            let ids = watchlist
+                .plugins()
+                .map(|(id, _)| id.to_string())
+                .collect::<Vec<String>>();
+            let lookups = ids
+                .into_iter()
+                .map(|id| async {
+                    let query =
+                        query_builder!("limit" : QueryType::Value(Some(Self::VERSION_LIMIT)));
+                    let versions = ore_client.versions(&id, query).await;
+                    (id, versions)
+                })
+                .collect::<Vec<_>>();
+            let responses = stream::iter(lookups)
+                .buffered(ore_client.concurrency())
+                .collect::<Vec<_>>()
+                .await;
+
+            let mut lines = vec![];
+            for (id, versions) in responses {
+                let versions = match versions {
+                    Ok(page) => page
+                        .result
+                        .into_iter()
+                        .map(|version| SeenVersion {
+                            name: version.name,
+                            published: version.created_at,
+                        })
+                        .collect(),
+                    Err(e) => {
+                        lines.push(format!("{} : Unable to check, {}", id, e));
+                        continue;
+                    }
+                };
+
+                let previous = watchlist.last_seen(&id).map(|seen| seen.name.to_string());
+                let new = watchlist.observe(&id, versions);
+                let line = match (previous, watchlist.last_seen(&id)) {
+                    (_, None) => format!("{} : No versions published yet", id),
+                    (None, Some(seen)) => format!("{} : Watching from {}", id, seen.name),
+                    (Some(previous), _) if new.is_empty() => {
+                        format!("{} : No new versions since {}", id, previous)
+                    }
+                    (Some(previous), _) => format!(
+                        "{} : {} new version(s) since {} : {}",
+                        id,
+                        new.len(),
+                        previous,
+                        new.iter()
+                            .map(|version| version.name.as_str())
+                            .collect::<Vec<&str>>()
+                            .join(", ")
+                    ),
+                };
+                lines.push(line);
+            }
+            lines
+        }
+    }
+
+    #[async_trait]
+    impl OreCommand for WatchlistCommand {
+        async fn handle(&self, ore_client: OreClient, _link_query: Option<Query>) -> Result<()> {
+            let mut watchlist = Watchlist::load()?;
+            match &self.action {
+                WatchlistAction::Add { plugin_ids } => {
+                    for plugin_id in plugin_ids {
+                        match watchlist.add(plugin_id) {
+                            true => println!("Watching {}", plugin_id),
+                            false => println!("Already watching {}", plugin_id),
+                        }
+                    }
+                    watchlist.save()
+                }
+                WatchlistAction::Remove { plugin_ids } => {
+                    for plugin_id in plugin_ids {
+                        match watchlist.remove(plugin_id) {
+                            true => println!("Stopped watching {}", plugin_id),
+                            false => println!("{} wasn't watched", plugin_id),
+                        }
+                    }
+                    watchlist.save()
+                }
+                WatchlistAction::List => {
+                    if watchlist.plugins().next().is_none() {
+                        return self.print_res("Nothing is watched");
+                    }
+                    for (plugin_id, seen) in watchlist.plugins() {
+                        match seen {
+                            Some(seen) => println!("{} : {}", plugin_id, seen.name),
+                            None => println!("{} : not checked yet", plugin_id),
+                        }
+                    }
+                    Ok(())
+                }
+                WatchlistAction::Check => {
+                    if watchlist.plugins().next().is_none() {
+                        return self.print_res(
+                            "Nothing is watched, add plugins with `watchlist add <plugin_id>`",
+                        );
+                    }
+                    let lines = Self::check(&ore_client, &mut watchlist).await;
+                    watchlist.save()?;
+                    self.print_res(lines.join("\n"))
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+#[path = "../tests/fixtures/mod.rs"]
+mod fixtures;
+#[cfg(test)]
+mod handler_tests;
+#[cfg(test)]
+mod render_tests;
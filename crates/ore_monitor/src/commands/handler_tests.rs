@@ -0,0 +1,363 @@
+//! Commands handled against a mock Ore serving the recorded responses in `tests/fixtures`
+
+use std::{collections::HashMap, fs, path::PathBuf, sync::Arc, time::Duration};
+
+use chrono::{TimeZone, Utc};
+use clap::Parser;
+use md5::{Digest, Md5};
+use ore_monitor::{
+    clock::FixedClock,
+    lockfile::{LockedPlugin, Lockfile},
+    ore_mod_info::OreModInfo,
+    scan_filter::ScanFilter,
+    source::Source,
+    sponge_schemas::{Category, ReleaseChannel},
+    watchlist::{SeenVersion, Watchlist},
+};
+use wiremock::ResponseTemplate;
+
+use crate::{
+    config::ore_config::{AutoUpdateConfig, UpdatePolicy},
+    notify::notification::Notifier,
+};
+
+use super::{
+    core_command::OreCommand,
+    fixtures::{MockOre, PROJECT, SEARCH, VERSION, VERSIONS},
+    freeze_command::FreezeCommand,
+    install_command::InstallCommand,
+    search_command::SearchCommand,
+    update_plan::UpdatePlan,
+    version_check_command::{
+        fetch_remotes, newest_tagged, RemoteVersion, VersionDisplay, VersionSelection,
+    },
+    watch_command::{WatchCommand, WatchState},
+    watchlist_command::WatchlistCommand,
+};
+
+/// The md5 Ore publishes for the recorded version
+const PUBLISHED_MD5: &str = "0f343b0931126a20f133d67c2b018a3b";
+const DOWNLOAD: &str = "/NucleusPowered/Nucleus/versions/2.1.4/download";
+
+/// An empty directory to install into, unique to the test
+fn plugins_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ore_monitor_{}", test));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Nucleus 2.1.3 for Sponge API 7
+fn nucleus_info() -> OreModInfo {
+    OreModInfo {
+        modid: "nucleus".to_string(),
+        name: "Nucleus".to_string(),
+        version: "2.1.3".to_string(),
+        major_api_version: 7,
+        dependencies: vec![],
+    }
+}
+
+/// Nucleus 2.1.4 on Ore, without what was fetched along with it
+fn outdated_remote() -> RemoteVersion {
+    RemoteVersion {
+        id: "nucleus".to_string(),
+        remote_id: "nucleus".to_string(),
+        source: Source::Ore,
+        version: "2.1.4".to_string(),
+        download_size: None,
+        url: None,
+        download_url: None,
+        dependencies: None,
+    }
+}
+
+/// Nucleus 2.1.3 installed, with 2.1.4 on Ore
+fn outdated_nucleus() -> VersionDisplay {
+    VersionDisplay::new((nucleus_info(), outdated_remote()), None)
+}
+
+/// Serves the recorded version of nucleus, its project, and the jar as its file
+async fn serve_nucleus(ore: &MockOre, jar: &[u8], published_md5: &str) {
+    let version = VERSION.replace(PUBLISHED_MD5, published_md5);
+    ore.api("/projects/nucleus/versions/2.1.4", 200, &version)
+        .await;
+    ore.api("/projects/nucleus", 200, PROJECT).await;
+    let download = ResponseTemplate::new(200)
+        .insert_header(
+            "Content-Disposition",
+            "attachment; filename=\"Nucleus.jar\"",
+        )
+        .set_body_bytes(jar);
+    ore.respond(DOWNLOAD, download).await;
+}
+
+#[tokio::test]
+async fn install_downloads_verifies_and_locks() {
+    let ore = MockOre::start().await;
+    let jar = b"not really a jar";
+    serve_nucleus(&ore, jar, &format!("{:x}", Md5::digest(jar))).await;
+    let dir = plugins_dir("install_handler");
+
+    let installed = InstallCommand::new("nucleus", "2.1.4", &dir)
+        .install(&ore.client().await)
+        .await
+        .unwrap();
+
+    assert_eq!(fs::read(&installed).unwrap(), jar);
+    let lockfile = Lockfile::load_or_default(&dir).unwrap();
+    let locked = lockfile.get("nucleus").unwrap();
+    assert_eq!(locked.version, "2.1.4");
+    assert_eq!(locked.owner.as_deref(), Some("NucleusPowered"));
+    assert_eq!(locked.url.as_deref(), Some(ore.url(DOWNLOAD).as_str()));
+}
+
+#[tokio::test]
+async fn install_refuses_a_download_not_matching_ore() {
+    let ore = MockOre::start().await;
+    serve_nucleus(&ore, b"tampered", PUBLISHED_MD5).await;
+    let dir = plugins_dir("install_handler_mismatch");
+
+    let err = InstallCommand::new("nucleus", "2.1.4", &dir)
+        .install(&ore.client().await)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("quarantined"), "{err}");
+    assert!(Lockfile::load_or_default(&dir)
+        .unwrap()
+        .get("nucleus")
+        .is_none());
+}
+
+#[tokio::test]
+async fn freeze_keeps_the_entries_of_plugins_it_doesnt_scan() {
+    let ore = MockOre::start().await;
+    let dir = plugins_dir("freeze_handler");
+    let mut lockfile = Lockfile::default();
+    let disabled = LockedPlugin {
+        version: "2.1.3".to_string(),
+        disabled: true,
+        ..Default::default()
+    };
+    lockfile.insert("nucleus", disabled.clone());
+    lockfile.save(&dir).unwrap();
+
+    FreezeCommand::parse_from(["freeze", dir.to_str().unwrap()])
+        .handle(ore.client().await, None)
+        .await
+        .unwrap();
+
+    let lockfile = Lockfile::load_or_default(&dir).unwrap();
+    assert_eq!(lockfile.get("nucleus"), Some(&disabled));
+}
+
+#[tokio::test]
+async fn watch_follows_the_policy_of_the_category() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, PROJECT).await;
+    let client = ore.client().await;
+    let display = outdated_nucleus();
+
+    let policies = |category, policy| AutoUpdateConfig {
+        default: UpdatePolicy::Notify,
+        categories: HashMap::from([(category, policy)]),
+    };
+    let admin_tools = policies(Category::AdminTools, UpdatePolicy::Update);
+    let gameplay = policies(Category::Gameplay, UpdatePolicy::Update);
+
+    assert_eq!(
+        WatchCommand::policy(&client, &admin_tools, &display).await,
+        UpdatePolicy::Update
+    );
+    assert_eq!(
+        WatchCommand::policy(&client, &gameplay, &display).await,
+        UpdatePolicy::Notify
+    );
+}
+
+#[tokio::test]
+async fn watch_replaces_the_outdated_jar() {
+    let ore = MockOre::start().await;
+    let jar = b"nucleus 2.1.4";
+    serve_nucleus(&ore, jar, &format!("{:x}", Md5::digest(jar))).await;
+    let dir = plugins_dir("watch_update");
+    let installed = dir.join("Nucleus.jar");
+    fs::write(&installed, b"nucleus 2.1.3").unwrap();
+
+    let filter = ScanFilter::default();
+    WatchCommand::update(
+        &ore.client().await,
+        &outdated_nucleus(),
+        &installed,
+        &dir,
+        &filter,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(fs::read(&installed).unwrap(), jar);
+    let locked = Lockfile::load_or_default(&dir).unwrap();
+    assert_eq!(locked.get("nucleus").unwrap().version, "2.1.4");
+}
+
+#[tokio::test]
+async fn watch_sends_the_digest_once_a_window_passed_on_its_clock() {
+    let ore = MockOre::start().await;
+    let client = ore.client().await;
+    let dir = plugins_dir("watch_digest");
+    let started = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+    let clock = Arc::new(FixedClock::new(started));
+    let mut watch = WatchCommand::parse_from(["watch", "--plugins", dir.to_str().unwrap()]);
+    watch.clock = Some(clock.clone());
+    let mut state = WatchState::new(started);
+    let window = Duration::from_secs(24 * 60 * 60);
+
+    clock.advance(chrono::Duration::hours(23));
+    watch
+        .send_digest(&client, &Notifier::silent(), &mut state, &dir, window)
+        .await
+        .unwrap();
+    assert_eq!(state.last_digest, started);
+
+    clock.advance(chrono::Duration::hours(1));
+    watch
+        .send_digest(&client, &Notifier::silent(), &mut state, &dir, window)
+        .await
+        .unwrap();
+    assert_eq!(state.last_digest, started + chrono::Duration::hours(24));
+}
+
+#[tokio::test]
+async fn check_falls_back_to_the_newest_version_tagged_for_the_api() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus/versions", 200, VERSIONS).await;
+    let client = ore.client().await;
+    let any = VersionSelection::default();
+
+    let version = newest_tagged(&client, "nucleus", &nucleus_info(), any)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(version.name, "2.1.4");
+
+    let sponge_9 = OreModInfo {
+        major_api_version: 9,
+        ..nucleus_info()
+    };
+    let release = VersionSelection {
+        channel: Some(ReleaseChannel::Release),
+        all_versions: true,
+    };
+    let version = newest_tagged(&client, "nucleus", &sponge_9, release)
+        .await
+        .unwrap();
+    assert!(version.is_none());
+}
+
+#[tokio::test]
+async fn check_orders_updates_by_what_the_new_version_depends_on() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, PROJECT).await;
+    ore.api("/projects/nucleus/versions/2.1.4", 200, VERSION)
+        .await;
+    let dir = plugins_dir("check_update_order");
+
+    // Only Nucleus 2.1.4 depends on LuckPerms, the installed 2.1.3 doesn't
+    let nucleus = nucleus_info();
+    let files = vec![(dir.join("Nucleus.jar"), nucleus.clone())];
+    let (_, remote) = fetch_remotes(&ore.client().await, &files)
+        .await
+        .unwrap()
+        .remove(0);
+    let remote = remote.unwrap();
+    assert_eq!(remote.download_size, Some(2874931.0));
+    let nucleus_update = VersionDisplay::new((nucleus.clone(), remote), None);
+
+    let luckperms = OreModInfo {
+        modid: "luckperms".to_string(),
+        name: "LuckPerms".to_string(),
+        version: "5.0.0".to_string(),
+        ..nucleus_info()
+    };
+    let luckperms_remote = RemoteVersion {
+        id: "luckperms".to_string(),
+        remote_id: "luckperms".to_string(),
+        version: "5.1.0".to_string(),
+        ..outdated_remote()
+    };
+    let luckperms_update = VersionDisplay::new((luckperms.clone(), luckperms_remote), None);
+
+    let plan = UpdatePlan::new(&[nucleus, luckperms], &[nucleus_update, luckperms_update]);
+    let order = plan
+        .updates
+        .iter()
+        .map(|update| update.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(order, ["luckperms", "nucleus"]);
+}
+
+#[tokio::test]
+async fn search_filters_pages_it_fetches_until_it_has_enough() {
+    let ore = MockOre::start().await;
+    // Each page has nucleus and huskycrates, of 142 projects in total
+    ore.api("/projects", 200, SEARCH).await;
+    let client = ore.client().await;
+    let search = |args: &[&str]| SearchCommand::parse_from([&["search"], args].concat());
+    let ids = |results: ore_monitor::sponge_schemas::PaginatedProjectResult| {
+        results
+            .result
+            .into_iter()
+            .map(|project| project.plugin_id)
+            .collect::<Vec<String>>()
+    };
+
+    let results = search(&["--has-sources", "--limit", "3"])
+        .results(&client, 0)
+        .await
+        .unwrap();
+    assert_eq!(ids(results), ["nucleus", "huskycrates", "nucleus"]);
+
+    let results = search(&["--mc-version", "1.16", "--limit", "1"])
+        .results(&client, 0)
+        .await
+        .unwrap();
+    assert_eq!(ids(results), ["nucleus"]);
+
+    let results = search(&["--license", "gpl"])
+        .results(&client, 0)
+        .await
+        .unwrap();
+    assert!(results.result.is_empty());
+}
+
+#[tokio::test]
+async fn watchlist_reports_versions_published_since_the_last_check() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus/versions", 200, VERSIONS).await;
+    let client = ore.client().await;
+
+    let mut watchlist = Watchlist::default();
+    watchlist.add("nucleus");
+    watchlist.add("missing");
+    let published = "2020-01-01T00:00:00Z".parse().unwrap();
+    let seen = SeenVersion {
+        name: "2.1.3".to_string(),
+        published,
+    };
+    watchlist.observe("nucleus", vec![seen]);
+
+    let lines = WatchlistCommand::check(&client, &mut watchlist).await;
+    assert!(
+        lines[0].starts_with("missing : Unable to check"),
+        "{}",
+        lines[0]
+    );
+    assert_eq!(
+        lines[1],
+        "nucleus : 2 new version(s) since 2.1.3 : 3.0.0-BETA1, 2.1.4"
+    );
+
+    let lines = WatchlistCommand::check(&client, &mut watchlist).await;
+    assert_eq!(lines[1], "nucleus : No new versions since 3.0.0-BETA1");
+}
@@ -0,0 +1,233 @@
+//! Snapshots of what commands print, rendered from recorded Ore responses in `tests/fixtures`
+//! Review changes with `cargo insta review`, or accept them with `INSTA_UPDATE=always cargo test`
+
+use anyhow::Error;
+use chrono::{TimeZone, Utc};
+use ore_monitor::{
+    ore_mod_info::{OreModInfo, PluginDependency},
+    report::ReportFormat,
+    source::Source,
+    sponge_schemas::{PaginatedProjectResult, Project, ProjectColumn, Version},
+};
+use ratatui::{backend::TestBackend, Terminal};
+use std::path::PathBuf;
+
+use crate::tui::browser::Browser;
+
+use super::{
+    fixtures::{MEMBERS, PROJECT, SEARCH, STATS, VERSION, VERSION_STATS},
+    plugin_command::{ProjectMembers, ProjectStats, VersionStats},
+    update_plan::UpdatePlan,
+    version_check_command::{
+        CheckFailure, RemoteVersion, VersionCheckCommand, VersionDisplay, VersionSelection,
+    },
+    why_outdated_command::{Explanation, Lookup},
+};
+
+/// Relative dates depend on when the tests run, so they're redacted
+macro_rules! assert_rendered {
+    ($name:expr, $value:expr) => {
+        insta::with_settings!({filters => vec![(r"\((just now|\d+ \w+ ago)\)", "([ago])")]}, {
+            insta::assert_snapshot!($name, $value.to_string());
+        })
+    };
+}
+
+fn installed(modid: &str, version: &str, dependencies: Vec<PluginDependency>) -> OreModInfo {
+    OreModInfo {
+        modid: modid.to_string(),
+        name: modid.to_string(),
+        version: version.to_string(),
+        major_api_version: 7,
+        dependencies,
+    }
+}
+
+fn remote(id: &str, remote_id: &str, source: Source, version: &str) -> RemoteVersion {
+    RemoteVersion {
+        id: id.to_string(),
+        remote_id: remote_id.to_string(),
+        source,
+        version: version.to_string(),
+        download_size: None,
+        url: None,
+        download_url: None,
+        dependencies: None,
+    }
+}
+
+#[test]
+fn project() {
+    let project: Project = serde_json::from_str(PROJECT).unwrap();
+    let now = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+    assert_rendered!("project", project.as_of(now));
+}
+
+#[test]
+fn version() {
+    let version: Version = serde_json::from_str(VERSION).unwrap();
+    let now = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+    assert_rendered!("version", version.as_of(now));
+}
+
+#[test]
+fn search_results() {
+    let results: PaginatedProjectResult = serde_json::from_str(SEARCH).unwrap();
+    assert_rendered!("search_results", results);
+}
+
+#[test]
+fn search_table() {
+    let results: PaginatedProjectResult = serde_json::from_str(SEARCH).unwrap();
+    assert_rendered!(
+        "search_table",
+        ProjectColumn::table(&ProjectColumn::ALL, &results.result)
+    );
+    assert_rendered!(
+        "search_table_columns",
+        ProjectColumn::table(
+            &[ProjectColumn::PluginId, ProjectColumn::Downloads],
+            &results.result
+        )
+    );
+    assert_rendered!(
+        "search_table_fields",
+        ProjectColumn::table(
+            &[
+                ProjectColumn::PluginId,
+                ProjectColumn::Owner,
+                ProjectColumn::Views,
+                ProjectColumn::Created
+            ],
+            &results.result
+        )
+    );
+}
+
+#[test]
+fn search_browser() {
+    let results = serde_json::from_str(SEARCH).unwrap();
+    let mut browser = Browser::new(results);
+    let mut terminal = Terminal::new(TestBackend::new(80, 8)).unwrap();
+    terminal.draw(|frame| browser.draw(frame)).unwrap();
+    assert_rendered!("search_browser", terminal.backend());
+}
+
+#[test]
+fn project_members() {
+    let members = serde_json::from_str(MEMBERS).unwrap();
+    assert_rendered!("project_members", ProjectMembers(members));
+}
+
+#[test]
+fn project_stats() {
+    let stats = ProjectStats(serde_json::from_str(STATS).unwrap());
+    assert_rendered!("project_stats", stats);
+    assert_rendered!("project_stats_csv", stats.csv());
+}
+
+#[test]
+fn version_stats() {
+    let stats = VersionStats(serde_json::from_str(VERSION_STATS).unwrap());
+    assert_rendered!("version_stats", stats);
+}
+
+#[test]
+fn check_report() {
+    let luckperms = installed("luckperms", "5.3.0", vec![]);
+    let nucleus = installed(
+        "nucleus",
+        "2.1.3",
+        vec![PluginDependency {
+            id: "luckperms".to_string(),
+            version: "[5.3,5.4)".to_string(),
+        }],
+    );
+    let huskycrates = installed("huskycrates", "2.0.0", vec![]);
+    let broken = installed("griefprevention", "16.18", vec![]);
+
+    let displays = vec![
+        VersionDisplay::new(
+            (
+                luckperms.clone(),
+                RemoteVersion {
+                    download_size: Some(1_447_826.0),
+                    ..remote("luckperms", "Vebnzrzj", Source::Modrinth, "5.4.102")
+                },
+            ),
+            None,
+        ),
+        VersionDisplay::new(
+            (
+                nucleus.clone(),
+                remote("nucleus", "nucleus", Source::Modrinth, "2.1.4"),
+            ),
+            None,
+        ),
+        VersionDisplay::new(
+            (
+                huskycrates.clone(),
+                RemoteVersion {
+                    url: Some("https://ore.spongepowered.org/codeHusky/HuskyCrates".to_string()),
+                    download_url: Some(
+                        "https://ore.spongepowered.org/codeHusky/HuskyCrates/versions/2.0.0/download"
+                            .to_string(),
+                    ),
+                    ..remote("huskycrates", "huskycrates", Source::Ore, "2.0.0")
+                },
+            ),
+            None,
+        ),
+    ];
+    let failure = CheckFailure::new(&broken, Error::msg("griefprevention was not found on Ore"));
+
+    let plan = UpdatePlan::new(&[luckperms, nucleus, huskycrates], &displays);
+
+    let mut report = String::new();
+    displays
+        .iter()
+        .for_each(|display| report.push_str(&format!("{}\n", display)));
+    report.push_str(&format!("{}\n", failure));
+    report.push_str(&plan.to_string());
+    assert_rendered!("check_report", report);
+
+    let table = VersionCheckCommand::report(&displays, std::slice::from_ref(&failure));
+    assert_rendered!("check_report_md", table.render(ReportFormat::Md));
+
+    let generated = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+    let table = table.with_timestamp(generated);
+    assert_rendered!("check_report_html", table.render(ReportFormat::Html));
+}
+
+#[test]
+fn why_outdated() {
+    let project: Project = serde_json::from_str(PROJECT).unwrap();
+    let explanation = Explanation {
+        jar: PathBuf::from("plugins/Nucleus-2.1.3.jar"),
+        metadata: "mcmod.info".to_string(),
+        info: installed("nucleus", "2.1.3", vec![]),
+        lookup: Lookup::Ore {
+            project: Box::new(project),
+            found_by: "its plugin id".to_string(),
+        },
+        selection: VersionSelection::default(),
+        remote_version: "2.1.4".to_string(),
+    };
+    assert_rendered!("why_outdated", explanation);
+
+    let explanation = Explanation {
+        info: OreModInfo {
+            major_api_version: 9,
+            ..installed("nucleus", "3.0.0", vec![])
+        },
+        remote_version: String::new(),
+        ..explanation
+    };
+    assert_rendered!("why_outdated_no_promoted", explanation);
+
+    let explanation = Explanation {
+        remote_version: "3.0.1".to_string(),
+        ..explanation
+    };
+    assert_rendered!("why_outdated_newest_tagged", explanation);
+}
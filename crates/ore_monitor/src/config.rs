@@ -0,0 +1,263 @@
+pub mod ore_config {
+    use anyhow::Result;
+    use ore_monitor::{
+        duration::parse_duration, response_cache::ResponseCache, retry::RetryPolicy,
+        source::PluginSource, sponge_schemas::Category, storage::Backend,
+    };
+    use reqwest::Client;
+    use serde::Deserialize;
+    use std::{collections::HashMap, env, fs, path::PathBuf, time::Duration};
+    use toml::{Table, Value};
+
+    use crate::notify::notification::{Notifier, WebhookFormat, WebhookSink};
+
+    /// User configuration, read from `config.toml` in the platform's config directory
+    /// Flags given on the command line take priority over it.
+    #[derive(Deserialize, Debug, Default)]
+    pub struct Config {
+        /// Used when `ORE_API_KEY` isn't set
+        pub api_key: Option<String>,
+        /// Root of the Ore API, for mirrors or a local instance
+        pub base_url: Option<String>,
+        /// Where `check` and `reconcile` look when no directory is given
+        pub plugins_dir: Option<PathBuf>,
+        /// Where `install` saves plugins when no directory is given, otherwise the plugins directory
+        pub install_dir: Option<PathBuf>,
+        /// Command aliases, ex: `up = "check --update --yes"`
+        #[serde(default)]
+        pub alias: HashMap<String, String>,
+        /// Where plugins come from when not Ore, ex: `luckperms = { source = "modrinth", id = "Vebnzrzj" }`
+        #[serde(default)]
+        pub plugins: HashMap<String, PluginSource>,
+        /// How many requests bulk operations like `check` send at once
+        pub concurrency: Option<usize>,
+        /// Tuning of the connections shared by every request
+        #[serde(default)]
+        pub http: HttpConfig,
+        /// How requests are retried when Ore is rate limiting, erroring or times out
+        #[serde(default)]
+        pub retry: RetryConfig,
+        /// Where notifications are sent besides the console
+        #[serde(default)]
+        pub notify: NotifyConfig,
+        /// Which out of date plugins `watch` updates by itself, by their Ore category
+        #[serde(default)]
+        pub auto_update: AutoUpdateConfig,
+        /// How long responses warmed by `prefetch` are used, ex: `12h`
+        pub cache_max_age: Option<String>,
+        /// How long lookups keep their responses, ex: `10m`, `0s` keeps none
+        pub cache_ttl: Option<String>,
+        /// Where caches and the activity log are kept, `json` files or a `sqlite` database
+        #[serde(default)]
+        pub storage: Backend,
+        /// The base64 public key `lock verify` requires lockfiles to be signed with, unless `--key` is given
+        pub lock_key: Option<String>,
+    }
+
+    /// A webhook receiving notifications, such as out of date plugins found by `check`
+    #[derive(Deserialize, Debug, Default)]
+    pub struct NotifyConfig {
+        pub webhook_url: Option<String>,
+        /// Detected from the URL unless given
+        pub format: Option<WebhookFormat>,
+    }
+
+    impl NotifyConfig {
+        /// Notifies the console, and the webhook when one is configured
+        pub fn notifier(&self, client: &Client) -> Notifier {
+            match &self.webhook_url {
+                Some(url) => Notifier::default().with_sink(WebhookSink::new(
+                    client.clone(),
+                    url.to_string(),
+                    self.format,
+                )),
+                None => Notifier::default(),
+            }
+        }
+    }
+
+    /// What `watch --plugins` does with a plugin once it's out of date
+    #[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum UpdatePolicy {
+        /// Only sends a notification
+        #[default]
+        Notify,
+        /// Installs the new version, then notifies that it was updated
+        Update,
+    }
+
+    /// Update policies by Ore category, ex: `categories = { admin_tools = "update", gameplay = "notify" }`
+    #[derive(Deserialize, Debug, Default)]
+    pub struct AutoUpdateConfig {
+        /// For plugins whose category has no policy, or that aren't from Ore
+        #[serde(default)]
+        pub default: UpdatePolicy,
+        #[serde(default)]
+        pub categories: HashMap<Category, UpdatePolicy>,
+    }
+
+    impl AutoUpdateConfig {
+        pub fn policy(&self, category: Option<&Category>) -> UpdatePolicy {
+            category
+                .and_then(|category| self.categories.get(category))
+                .copied()
+                .unwrap_or(self.default)
+        }
+    }
+
+    /// Connection settings of the HTTP client, unset values keep reqwest's defaults
+    #[derive(Deserialize, Debug, Default)]
+    pub struct HttpConfig {
+        /// Idle connections kept open per host, ready for the next request
+        pub pool_max_idle_per_host: Option<usize>,
+        /// How long an idle connection is kept, ex: `90s`
+        pub pool_idle_timeout: Option<String>,
+        /// Interval of TCP keep-alive probes, ex: `60s`
+        pub tcp_keepalive: Option<String>,
+        /// Speak HTTP/2 without negotiating it first
+        #[serde(default)]
+        pub http2: bool,
+    }
+
+    /// Retries of requests to Ore, unset values keep the defaults of [RetryPolicy]
+    #[derive(Deserialize, Debug, Default)]
+    pub struct RetryConfig {
+        /// Attempts of a request including the first, `1` disables retrying
+        pub max_attempts: Option<u32>,
+        /// Longest wait between attempts, ex: `30s`
+        pub max_delay: Option<String>,
+    }
+
+    impl RetryConfig {
+        pub fn policy(&self) -> Result<RetryPolicy> {
+            let mut policy = RetryPolicy::default();
+            if let Some(max_attempts) = self.max_attempts {
+                policy.max_attempts = max_attempts.max(1);
+            }
+            if let Some(max_delay) = &self.max_delay {
+                policy.max_delay = parse_duration(max_delay).map_err(anyhow::Error::msg)?;
+            }
+            Ok(policy)
+        }
+    }
+
+    impl HttpConfig {
+        /// Builds the client used for every host, so connections are reused between requests
+        pub fn client(&self) -> Result<Client> {
+            // Ore compresses its responses when asked to
+            let mut builder = Client::builder().gzip(true).brotli(true);
+
+            if let Some(max) = self.pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max);
+            }
+            if let Some(timeout) = &self.pool_idle_timeout {
+                builder =
+                    builder.pool_idle_timeout(parse_duration(timeout).map_err(anyhow::Error::msg)?);
+            }
+            if let Some(interval) = &self.tcp_keepalive {
+                builder =
+                    builder.tcp_keepalive(parse_duration(interval).map_err(anyhow::Error::msg)?);
+            }
+            if self.http2 {
+                builder = builder.http2_prior_knowledge();
+            }
+
+            Ok(builder.build()?)
+        }
+    }
+
+    impl Config {
+        /// The api key, `ORE_API_KEY` takes priority so a key can be used once without editing the config
+        pub fn api_key(&self) -> Option<String> {
+            env::var("ORE_API_KEY").ok().or(self.api_key.clone())
+        }
+
+        /// How long cached responses are used, a day unless configured
+        pub fn cache_max_age(&self) -> Result<Duration> {
+            match &self.cache_max_age {
+                Some(max_age) => parse_duration(max_age).map_err(anyhow::Error::msg),
+                None => Ok(ResponseCache::DEFAULT_MAX_AGE),
+            }
+        }
+
+        /// How long lookups keep their responses, 5 minutes unless configured
+        pub fn cache_ttl(&self) -> Result<Duration> {
+            match &self.cache_ttl {
+                Some(ttl) => parse_duration(ttl).map_err(anyhow::Error::msg),
+                None => Ok(ResponseCache::DEFAULT_TTL),
+            }
+        }
+
+        /// Where the plugin comes from, Ore unless overridden
+        pub fn plugin_source(&self, plugin_id: &str) -> PluginSource {
+            self.plugins.get(plugin_id).cloned().unwrap_or_default()
+        }
+
+        pub fn path() -> Option<PathBuf> {
+            dirs::config_dir().map(|dir| dir.join("ore-monitor").join("config.toml"))
+        }
+
+        /// Loads the config, a missing file is the same as an empty config
+        pub fn load() -> Result<Config> {
+            Ok(Self::load_table()?.try_into()?)
+        }
+
+        /// The config as written, without defaults filled in
+        fn load_table() -> Result<Table> {
+            match Self::path().filter(|path| path.is_file()) {
+                Some(path) => Ok(toml::from_str(&fs::read_to_string(path)?)?),
+                None => Ok(Table::new()),
+            }
+        }
+
+        /// Looks up a key, nested keys are separated by dots, ex: `http.pool_max_idle_per_host`
+        pub fn get_value(key: &str) -> Result<Option<Value>> {
+            let table = Self::load_table()?;
+
+            let mut keys = key.split('.');
+            let mut value = keys.next().and_then(|k| table.get(k));
+            for k in keys {
+                value = value.and_then(|v| v.get(k));
+            }
+            Ok(value.cloned())
+        }
+
+        /// Sets a key, the value is read as TOML when possible and as a string otherwise
+        /// The change is only saved when the resulting config is still valid.
+        pub fn set_value(key: &str, value: &str) -> Result<()> {
+            let mut table = Self::load_table()?;
+
+            let value = toml::from_str::<Table>(&format!("value = {}", value))
+                .ok()
+                .and_then(|mut t| t.remove("value"))
+                .unwrap_or_else(|| Value::String(value.to_string()));
+
+            let (parents, last) = match key.rsplit_once('.') {
+                Some((parents, last)) => (parents.split('.').collect(), last),
+                None => (vec![], key),
+            };
+
+            let mut current = &mut table;
+            for parent in parents {
+                current = current
+                    .entry(parent)
+                    .or_insert_with(|| Value::Table(Table::new()))
+                    .as_table_mut()
+                    .ok_or_else(|| anyhow::Error::msg(format!("'{}' is not a table", parent)))?;
+            }
+            current.insert(last.to_string(), value);
+
+            // Parsing it back catches unknown value types before they're written
+            let _: Config = table.clone().try_into()?;
+
+            let path = Self::path()
+                .ok_or_else(|| anyhow::Error::msg("No config directory on this platform"))?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, toml::to_string_pretty(&table)?)?;
+            Ok(())
+        }
+    }
+}
@@ -1,5 +1,8 @@
+pub mod ore;
+pub mod sponge_schemas;
+
 pub mod query {
-    use std::fmt::Display;
+    use chrono::NaiveDate;
 
     /// Builds a set of arguments to build a query for a link
     /// Returns a [Vec]<([String],[String])>
@@ -30,26 +33,56 @@ pub mod query {
     ///     .collect();
     /// assert_eq!(query_vec, result);
     /// ```
+    /// ```
+    /// use ore_monitor::query::{Query, QueryType};
+    /// use ore_monitor::{plugin_response, query_builder};
+    ///
+    /// let query = query_builder!(
+    ///     "sort" : QueryType::Value(Some("stars")),
+    ///     "category" : QueryType::Vec(Some(vec!["admin_tools"])),
+    ///     "limit" : QueryType::Value(None::<u32>),
+    ///     "q" : QueryType::Value(Some("nucleus")),
+    /// ).to_vec();
+    ///
+    /// let keys: Vec<String> = query.into_iter().map(|(k, _)| k).collect();
+    /// assert_eq!(keys, vec!["sort", "category", "q"]);
+    /// ```
+    /// Text keeps its case, enums are written as Ore names them
+    /// ```
+    /// use ore_monitor::query::{Query, QueryType};
+    /// use ore_monitor::query_builder;
+    /// use ore_monitor::sponge_schemas::{Category, ProjectSortingStrategy};
+    ///
+    /// let query = query_builder!(
+    ///     "q" : QueryType::Value(Some("GriefPrevention")),
+    ///     "owner" : QueryType::Value(Some("NucleusPowered")),
+    ///     "tags" : QueryType::Vec(Some(vec!["Sponge:8.0.0"])),
+    ///     "sort" : QueryType::Value(Some(ProjectSortingStrategy::RecentDownloads)),
+    ///     "categories" : QueryType::Vec(Some(vec![Category::RolePlaying])),
+    /// ).to_vec();
+    ///
+    /// let values: Vec<String> = query.into_iter().map(|(_, v)| v).collect();
+    /// assert_eq!(
+    ///     values,
+    ///     vec!["GriefPrevention", "NucleusPowered", "Sponge:8.0.0", "recent_downloads", "role_playing"]
+    /// );
+    /// ```
     #[macro_export]
     macro_rules! query_builder {
         ($($lit:literal : $val:expr),+ $(,)?) => {
             {
-                use std::collections::HashMap;
                 use ore_monitor::query::{Query, QueryType};
 
-                let mut map: HashMap<String, Vec<String>> = Default::default();
+                // Arguments keep the order they're given in, so the same query is always built
+                let mut query: Vec<(String, String)> = vec![];
 
                 $(
-                    if let Some(args) = $val.into() {
-                        map.insert($lit.to_string(), args)
-                    } else {
-                        None
-                    };
+                    let args: Option<Vec<String>> = $val.into();
+                    if let Some(args) = args {
+                        query.extend(args.into_iter().map(|v| ($lit.to_string(), v)));
+                    }
                 )+
 
-                let query = map.iter().map( |k| {
-                    k.1.iter().map(|v| (k.0.to_string(), v.to_string()))
-                }).flatten().collect::<Vec<(String,String)>>();
                 Query::new(query)
             }
         }
@@ -145,18 +178,45 @@ pub mod query {
         }
     }
 
+    /// How a value is written into a query
+    /// Text, numbers and dates are written as they are, enums as the name Ore knows them by.
+    pub trait QueryValue {
+        fn query_value(&self) -> String;
+    }
+
+    impl<T: QueryValue + ?Sized> QueryValue for &T {
+        fn query_value(&self) -> String {
+            (**self).query_value()
+        }
+    }
+
+    /// Values whose [Display](std::fmt::Display) is already what Ore expects
+    macro_rules! displayed_query_value {
+        ($($ty:ty),+) => {
+            $(
+                impl QueryValue for $ty {
+                    fn query_value(&self) -> String {
+                        self.to_string()
+                    }
+                }
+            )+
+        };
+    }
+
+    displayed_query_value!(str, String, bool, i32, i64, u32, u64, usize, NaiveDate);
+
     /// Differentiates the difference between a Vec and Non-Vec value
-    /// For the purposes of providing a clean [Display] impl
-    pub enum QueryType<T: Display> {
+    /// Each value is written by its [QueryValue]
+    pub enum QueryType<T: QueryValue> {
         Vec(Option<Vec<T>>),
         Value(Option<T>),
     }
 
-    impl<T: Display> Into<Option<Vec<String>>> for QueryType<T> {
-        fn into(self) -> Option<Vec<String>> {
-            match self {
-                QueryType::Value(Some(e)) => Some(vec![e.to_string().to_lowercase()]),
-                QueryType::Vec(Some(e)) => Some(e.iter().map(|f| f.to_string()).collect()),
+    impl<T: QueryValue> From<QueryType<T>> for Option<Vec<String>> {
+        fn from(value: QueryType<T>) -> Self {
+            match value {
+                QueryType::Value(Some(e)) => Some(vec![e.query_value()]),
+                QueryType::Vec(Some(e)) => Some(e.iter().map(|f| f.query_value()).collect()),
                 _ => None,
             }
         }
@@ -165,52 +225,214 @@ pub mod query {
 
 pub mod file_reader {
     use std::{
-        fs::{self, File},
-        io::{BufReader, Read},
+        fmt::Display,
+        fs::File,
+        io::{BufReader, Cursor, Read, Seek},
         ops::Deref,
         path::{Path, PathBuf},
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
     };
 
-    use anyhow::{Ok, Result};
+    use anyhow::{Error, Result};
 
     use serde::de::DeserializeOwned;
-    use zip::ZipArchive;
+    use zip::{result::ZipError, ZipArchive};
 
-    use crate::ore_mod_info::{ModInfo, OreModInfo, PluginInfo};
+    use crate::{
+        disabled_plugins::is_disabled,
+        ore_mod_info::{FabricModInfo, JarManifest, ModInfo, OreModInfo, PluginInfo},
+        scan_cache::ScanCache,
+        scan_filter::{NestedScan, ScanFilter},
+    };
 
     /// A reader that takes a [PathBuf] to read a file or group of files
     #[derive(Debug, Default)]
     pub struct FileReader {
         pub base_path: PathBuf,
+        /// Which files of a directory are read
+        pub filter: ScanFilter,
     }
 
+    /// The metadata files a jar is read from, in the order they're tried
     enum FileTypes {
-        InfoFile,
-        PluginFile,
+        McModInfo,
+        SpongePlugins,
+        /// Where Sponge 8 looked for plugins before the file was renamed
+        LegacySpongePlugins,
+        FabricMod,
+        /// Jars without any plugin metadata may still name themselves in their manifest
+        Manifest,
     }
 
     impl FileTypes {
-        pub fn try_get(&self, jar_reader: &mut JarFileReader) -> Result<OreModInfo> {
+        const ALL: [FileTypes; 5] = [
+            FileTypes::McModInfo,
+            FileTypes::SpongePlugins,
+            FileTypes::LegacySpongePlugins,
+            FileTypes::FabricMod,
+            FileTypes::Manifest,
+        ];
+
+        fn file_name(&self) -> &'static str {
             match self {
-                FileTypes::InfoFile => jar_reader
-                    .find_file::<ModInfo>("mcmod.info")
-                    .map(Into::into),
-                FileTypes::PluginFile => jar_reader
-                    .find_file::<PluginInfo>("META-INF/sponge_plugins.json")
-                    .map(Into::into),
+                FileTypes::McModInfo => "mcmod.info",
+                FileTypes::SpongePlugins => "META-INF/sponge_plugins.json",
+                FileTypes::LegacySpongePlugins => "META-INF/plugins.json",
+                FileTypes::FabricMod => "fabric.mod.json",
+                FileTypes::Manifest => "META-INF/MANIFEST.MF",
             }
         }
+
+        /// Reads the metadata, [None] when the jar doesn't hold the file
+        pub fn try_get<R: Read + Seek>(
+            &self,
+            jar_reader: &mut JarFileReader<R>,
+        ) -> Result<Option<OreModInfo>> {
+            let Some(contents) = jar_reader.read_file(self.file_name())? else {
+                return Ok(None);
+            };
+            let info = match self {
+                FileTypes::McModInfo => parse::<ModInfo>(&contents)?.into(),
+                FileTypes::SpongePlugins | FileTypes::LegacySpongePlugins => {
+                    parse::<PluginInfo>(&contents)?.into()
+                }
+                FileTypes::FabricMod => parse::<FabricModInfo>(&contents)?.into(),
+                FileTypes::Manifest => JarManifest::parse(&contents).try_into()?,
+            };
+            Ok(Some(info))
+        }
+    }
+
+    fn parse<T: DeserializeOwned>(contents: &str) -> Result<T> {
+        Ok(serde_json::from_str::<T>(contents)?)
+    }
+
+    /// A jar that was scanned but couldn't be read, and why
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct UnreadableJar {
+        pub path: PathBuf,
+        pub reason: SkipReason,
+    }
+
+    /// Why a jar was skipped, returned as the error of reading it
+    /// ```
+    /// # use ore_monitor::file_reader::{FileReader, SkipReason};
+    /// # use std::io::{Cursor, Write};
+    /// let error = FileReader::read_jar(Cursor::new(b"not a jar".to_vec())).unwrap_err();
+    /// assert!(matches!(SkipReason::of(&error), SkipReason::BadZip(_)));
+    ///
+    /// let mut jar = zip::ZipWriter::new(Cursor::new(vec![]));
+    /// jar.start_file("readme.txt", Default::default()).unwrap();
+    /// let error = FileReader::read_jar(jar.finish().unwrap()).unwrap_err();
+    /// assert_eq!(SkipReason::of(&error), SkipReason::MissingMetadata);
+    ///
+    /// let mut jar = zip::ZipWriter::new(Cursor::new(vec![]));
+    /// jar.start_file("mcmod.info", Default::default()).unwrap();
+    /// jar.write_all(b"[{").unwrap();
+    /// let error = FileReader::read_jar(jar.finish().unwrap()).unwrap_err();
+    /// assert!(matches!(SkipReason::of(&error), SkipReason::InvalidMetadata { file, .. } if file == "mcmod.info"));
+    /// ```
+    #[derive(Debug, PartialEq, Clone)]
+    pub enum SkipReason {
+        /// The file couldn't be opened or read
+        Io(String),
+        /// Not a zip archive, or a corrupt one
+        BadZip(String),
+        /// None of the metadata files are in the jar
+        MissingMetadata,
+        /// A metadata file is in the jar, but couldn't be parsed
+        InvalidMetadata { file: String, error: String },
+    }
+
+    impl SkipReason {
+        /// The reason behind an error of reading a jar, errors of any other kind are taken as failing to read it
+        pub fn of(error: &Error) -> SkipReason {
+            if let Some(reason) = error.downcast_ref::<SkipReason>() {
+                return reason.clone();
+            }
+            match error.downcast_ref::<ZipError>() {
+                Some(ZipError::Io(e)) => SkipReason::Io(e.to_string()),
+                Some(e) => SkipReason::BadZip(e.to_string()),
+                None => SkipReason::Io(error.to_string()),
+            }
+        }
+    }
+
+    impl Display for SkipReason {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                SkipReason::Io(e) => write!(f, "{}", e),
+                SkipReason::BadZip(e) => write!(f, "not a valid jar, {}", e),
+                SkipReason::MissingMetadata => {
+                    let names = FileTypes::ALL
+                        .iter()
+                        .map(FileTypes::file_name)
+                        .collect::<Vec<&str>>();
+                    write!(
+                        f,
+                        "no plugin metadata found, looked for {}",
+                        names.join(", ")
+                    )
+                }
+                SkipReason::InvalidMetadata { file, error } => {
+                    write!(f, "{} is malformed : {}", file, error)
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for SkipReason {}
+
+    impl Display for UnreadableJar {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "Unable to read {} : {}",
+                self.path.display(),
+                self.reason
+            )
+        }
+    }
+
+    /// What scanning a directory found
+    #[derive(Debug, Default)]
+    pub struct DirScan {
+        /// The path and metadata of each readable file, sorted by plugin id
+        pub entries: Vec<(PathBuf, OreModInfo)>,
+        /// Jars that were skipped, files without the `.jar` extension are skipped silently
+        pub unreadable: Vec<UnreadableJar>,
+        /// Jars renamed so the server doesn't load them, kept apart from the entries
+        pub disabled: Vec<(PathBuf, OreModInfo)>,
+    }
+
+    /// Whether a path names a jar, other files of a plugins directory aren't expected to be readable
+    pub fn is_jar(path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("jar"))
     }
 
     impl FileReader {
         pub fn from(base_path: &Path) -> FileReader {
             Self {
                 base_path: base_path.to_path_buf(),
+                filter: ScanFilter::default(),
             }
         }
 
+        pub fn with_filter(mut self, filter: ScanFilter) -> FileReader {
+            self.filter = filter;
+            self
+        }
+
+        /// The files of the directory that pass the filter, in path order
+        pub fn files(&self) -> Result<Vec<PathBuf>> {
+            self.filter.files(&self.base_path)
+        }
+
         /// Handles a directory and reads the files inside of it
-        /// Returns a Vector of [ModInfo] of each valid file.
+        /// Returns a Vector of [ModInfo] of each valid file, sorted by plugin id.
+        /// Disabled jars, and jars that can't be read, are left out, [FileReader::scan_dir] reports both.
         /// ```
         /// # use ore_monitor::file_reader::FileReader;
         /// # use std::path::Path;
@@ -239,19 +461,142 @@ pub mod file_reader {
         ///         "huskyui@0.6.0PRE3".to_string()
         ///     ]
         /// }.into();
-        /// let mods = vec![mod_one, mod_two];
+        /// let mods = vec![mod_two, mod_one];
         /// assert_eq!(file, mods);
         /// ```
         pub fn handle_dir(&self) -> Result<Vec<OreModInfo>> {
-            let info = fs::read_dir(&self.base_path)?
-                .filter_map(|res| res.ok())
-                .map(|entry| entry.path())
-                .filter_map(|path| self.handle_file(Some(&path)).ok())
+            let info = self
+                .handle_dir_entries()?
+                .into_iter()
+                .map(|(_, info)| info)
                 .collect::<Vec<OreModInfo>>();
 
             Ok(info)
         }
 
+        /// Same as [FileReader::handle_dir], but keeps the path each [OreModInfo] was read from.
+        pub fn handle_dir_entries(&self) -> Result<Vec<(PathBuf, OreModInfo)>> {
+            Ok(self.scan_dir()?.entries)
+        }
+
+        /// Same as [FileReader::handle_dir_entries], but unchanged files are read from the cache
+        pub fn handle_dir_entries_cached(
+            &self,
+            cache: &mut ScanCache,
+        ) -> Result<Vec<(PathBuf, OreModInfo)>> {
+            Ok(self
+                .collect(|paths| cache.get_or_read_all(paths, |paths| self.read_all(paths)))?
+                .entries)
+        }
+
+        /// Reads each file of the directory, keeping why any of its jars couldn't be read
+        /// ```
+        /// # use ore_monitor::file_reader::{FileReader, SkipReason};
+        /// # use std::path::Path;
+        /// let dir = std::env::temp_dir().join("ore_monitor_scan_dir_doctest");
+        /// std::fs::create_dir_all(&dir).unwrap();
+        /// std::fs::copy("./local/test/nucleus.jar", dir.join("nucleus.jar")).unwrap();
+        /// std::fs::write(dir.join("broken.jar"), "not a jar").unwrap();
+        /// std::fs::write(dir.join("notes.txt"), "not a jar either").unwrap();
+        /// std::fs::copy("./local/test/huskycrates.jar", dir.join("huskycrates.jar.off")).unwrap();
+        ///
+        /// let scan = FileReader::from(&dir).scan_dir().unwrap();
+        /// assert_eq!(scan.entries.len(), 1);
+        /// assert_eq!(scan.disabled[0].1.modid, "huskycrates");
+        /// assert_eq!(scan.unreadable.len(), 1);
+        /// assert_eq!(scan.unreadable[0].path, dir.join("broken.jar"));
+        /// assert!(matches!(scan.unreadable[0].reason, SkipReason::BadZip(_)));
+        /// # std::fs::remove_dir_all(&dir).unwrap();
+        /// ```
+        pub fn scan_dir(&self) -> Result<DirScan> {
+            self.collect(|paths| self.read_all(paths))
+        }
+
+        fn collect<F>(&self, read: F) -> Result<DirScan>
+        where
+            F: FnOnce(&[PathBuf]) -> Vec<Result<OreModInfo>>,
+        {
+            let mut scan = DirScan::default();
+            let paths = self.files()?;
+            let read = read(&paths);
+            for (path, read) in paths.into_iter().zip(read) {
+                match read {
+                    Ok(info) if is_disabled(&path) => scan.disabled.push((path, info)),
+                    Ok(info) => scan.entries.push((path, info)),
+                    Err(e) if is_jar(&path) || is_disabled(&path) => {
+                        scan.unreadable.push(UnreadableJar {
+                            path,
+                            reason: SkipReason::of(&e),
+                        })
+                    }
+                    Err(_) => {}
+                }
+            }
+            scan.entries = Self::sorted(scan.entries);
+            Ok(scan)
+        }
+
+        /// Directories are listed in no particular order, entries are sorted by plugin id instead
+        fn sorted(mut info: Vec<(PathBuf, OreModInfo)>) -> Vec<(PathBuf, OreModInfo)> {
+            info.sort_by(|(a_path, a), (b_path, b)| a.modid.cmp(&b.modid).then(a_path.cmp(b_path)));
+            info
+        }
+
+        /// Reads each jar, on as many threads as the filter allows, the results in the same order as the paths
+        /// ```
+        /// # use ore_monitor::{file_reader::FileReader, scan_filter::ScanFilter};
+        /// # use std::path::PathBuf;
+        /// let paths = ["nucleus.jar", "missing.jar", "huskycrates.jar"]
+        ///     .map(|jar| PathBuf::from("./local/test").join(jar));
+        /// let reader = FileReader::default().with_filter(ScanFilter::default().with_threads(Some(2)));
+        ///
+        /// let read = reader.read_all(&paths);
+        /// assert_eq!(read[0].as_ref().unwrap().modid, "nucleus");
+        /// assert!(read[1].is_err());
+        /// assert_eq!(read[2].as_ref().unwrap().modid, "huskycrates");
+        /// ```
+        pub fn read_all(&self, paths: &[PathBuf]) -> Vec<Result<OreModInfo>> {
+            let threads = self.filter.threads().min(paths.len());
+            if threads <= 1 {
+                return paths
+                    .iter()
+                    .map(|path| self.handle_file(Some(path)))
+                    .collect();
+            }
+
+            // Each thread takes the next unread jar, so a slow jar doesn't hold up a whole share of them
+            let next = AtomicUsize::new(0);
+            let mut slots = paths.iter().map(|_| None).collect::<Vec<_>>();
+            thread::scope(|scope| {
+                let workers = (0..threads)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            let mut read = vec![];
+                            loop {
+                                let index = next.fetch_add(1, Ordering::Relaxed);
+                                let Some(path) = paths.get(index) else {
+                                    break;
+                                };
+                                read.push((index, self.handle_file(Some(path))));
+                            }
+                            read
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                for worker in workers {
+                    for (index, info) in worker.join().unwrap_or_default() {
+                        slots[index] = Some(info);
+                    }
+                }
+            });
+
+            // A panicking read leaves its jar unread, reported like any other jar that can't be read
+            slots
+                .into_iter()
+                .map(|slot| slot.unwrap_or_else(|| Err(Error::msg("reading the jar panicked"))))
+                .collect()
+        }
+
         /// Handles a single file. It reads from the [PathBuf] provided.
         /// If a path is provided it will read from it instead.
         /// ```
@@ -271,57 +616,199 @@ pub mod file_reader {
         /// assert_eq!(file,mod_info);
         /// ```
         pub fn handle_file(&self, path: Option<&Path>) -> Result<OreModInfo> {
-            let mut reader = Ok(path.unwrap_or(self.base_path.deref()))
-                .map(File::open)?
-                .map(BufReader::new)
-                .map(ZipArchive::new)?
-                .map(JarFileReader::new)?;
+            let file = File::open(path.unwrap_or(self.base_path.deref())).map(BufReader::new)?;
+            Self::read_jar_nested(file, self.filter.nested)
+        }
+
+        /// Reads the metadata of a jar from any source, such as a jar held in memory
+        /// Malformed jars and metadata are returned as errors, naming the file that couldn't be read.
+        /// ```
+        /// # use ore_monitor::file_reader::FileReader;
+        /// # use std::io::{Cursor, Write};
+        /// let jar = std::fs::read("./local/test/nucleus.jar").unwrap();
+        /// let info = FileReader::read_jar(Cursor::new(jar)).unwrap();
+        /// assert_eq!(info.modid, "nucleus");
+        ///
+        /// assert!(FileReader::read_jar(Cursor::new(b"PK\x03\x04 not a jar".to_vec())).is_err());
+        ///
+        /// let mut jar = zip::ZipWriter::new(Cursor::new(vec![]));
+        /// jar.start_file("META-INF/sponge_plugins.json", Default::default()).unwrap();
+        /// jar.write_all(b"{\"plugins\": 3}").unwrap();
+        /// let jar = jar.finish().unwrap();
+        /// let error = FileReader::read_jar(jar).unwrap_err();
+        /// assert!(error.to_string().starts_with("META-INF/sponge_plugins.json is malformed"));
+        /// ```
+        pub fn read_jar<R: Read + Seek>(reader: R) -> Result<OreModInfo> {
+            Self::read_jar_nested(reader, None)
+        }
+
+        /// Same as [FileReader::read_jar], but a jar without metadata of its own is looked into
+        /// The first embedded jar holding plugin metadata is read, otherwise the outer jar's error is kept.
+        /// ```
+        /// # use ore_monitor::file_reader::FileReader;
+        /// # use ore_monitor::scan_filter::NestedScan;
+        /// # use std::io::{Cursor, Write};
+        /// let plugin = std::fs::read("./local/test/nucleus.jar").unwrap();
+        /// let mut bundle = zip::ZipWriter::new(Cursor::new(vec![]));
+        /// bundle.start_file("META-INF/jars/nucleus.jar", Default::default()).unwrap();
+        /// bundle.write_all(&plugin).unwrap();
+        /// let bundle = bundle.finish().unwrap().into_inner();
+        ///
+        /// assert!(FileReader::read_jar(Cursor::new(&bundle)).is_err());
+        /// let nested = Some(NestedScan::default());
+        /// let info = FileReader::read_jar_nested(Cursor::new(&bundle), nested).unwrap();
+        /// assert_eq!(info.modid, "nucleus");
+        ///
+        /// let small = Some(NestedScan { max_size: 64, ..Default::default() });
+        /// assert!(FileReader::read_jar_nested(Cursor::new(&bundle), small).is_err());
+        /// ```
+        pub fn read_jar_nested<R: Read + Seek>(
+            reader: R,
+            nested: Option<NestedScan>,
+        ) -> Result<OreModInfo> {
+            Ok(Self::read_jar_source(reader, nested)?.0)
+        }
+
+        /// Same as [FileReader::read_jar_nested], also naming the file the metadata was read from
+        /// Metadata of an embedded jar is named by the path through the jars, separated by `!/`.
+        /// ```
+        /// # use ore_monitor::file_reader::FileReader;
+        /// # use std::io::Cursor;
+        /// let jar = std::fs::read("./local/test/nucleus.jar").unwrap();
+        /// let (info, source) = FileReader::read_jar_source(Cursor::new(jar), None).unwrap();
+        /// assert_eq!(info.modid, "nucleus");
+        /// assert_eq!(source, "mcmod.info");
+        /// ```
+        pub fn read_jar_source<R: Read + Seek>(
+            reader: R,
+            nested: Option<NestedScan>,
+        ) -> Result<(OreModInfo, String)> {
+            let mut reader = JarFileReader::new(ZipArchive::new(reader)?);
+            let info = Self::metadata(&mut reader);
+            match nested.filter(|nested| nested.max_depth > 0) {
+                Some(nested) => info.or_else(|e| Self::read_embedded(&mut reader, nested).ok_or(e)),
+                None => info,
+            }
+        }
+
+        /// Reads the first embedded jar that holds plugin metadata, one level less deep
+        fn read_embedded<R: Read + Seek>(
+            reader: &mut JarFileReader<R>,
+            nested: NestedScan,
+        ) -> Option<(OreModInfo, String)> {
+            let deeper = NestedScan {
+                max_depth: nested.max_depth - 1,
+                ..nested
+            };
+            for name in reader.jar_names() {
+                // An embedded jar that can't be read is only one of the candidates
+                let Ok(Some(jar)) = reader.read_bytes(&name, nested.max_size) else {
+                    continue;
+                };
+                if let Ok((info, source)) = Self::read_jar_source(Cursor::new(jar), Some(deeper)) {
+                    return Some((info, format!("{}!/{}", name, source)));
+                }
+            }
+            None
+        }
+
+        fn metadata<R: Read + Seek>(reader: &mut JarFileReader<R>) -> Result<(OreModInfo, String)> {
+            // A malformed file is only reported when no other metadata could be read
+            let mut malformed: Option<SkipReason> = None;
+            for file_type in FileTypes::ALL {
+                match file_type.try_get(reader) {
+                    Ok(Some(info)) => return Ok((info, file_type.file_name().to_string())),
+                    Ok(None) => {}
+                    Err(e) => {
+                        malformed.get_or_insert(SkipReason::InvalidMetadata {
+                            file: file_type.file_name().to_string(),
+                            error: e.to_string(),
+                        });
+                    }
+                }
+            }
 
-            FileTypes::InfoFile
-                .try_get(&mut reader)
-                .or_else(|_| FileTypes::PluginFile.try_get(&mut reader))
+            Err(malformed.unwrap_or(SkipReason::MissingMetadata).into())
         }
     }
 
     /// JarFileReader is intended to read `.jar` files
-    struct JarFileReader {
-        file: ZipArchive<BufReader<File>>,
+    struct JarFileReader<R: Read + Seek> {
+        file: ZipArchive<R>,
     }
 
-    impl JarFileReader {
-        fn new(file: ZipArchive<BufReader<File>>) -> Self {
+    impl<R: Read + Seek> JarFileReader<R> {
+        fn new(file: ZipArchive<R>) -> Self {
             JarFileReader { file }
         }
 
-        /// Locates a file from a [ZipArchive] by the files name
-        /// Returns [self] for method chaining.
-        fn find_file<T: DeserializeOwned>(&mut self, file_name: &str) -> Result<T> {
+        /// Reads a file from a [ZipArchive] by the files name, [None] if the archive doesn't hold it
+        fn read_file(&mut self, file_name: &str) -> Result<Option<String>> {
             let mut buf = String::new();
-            self.file.by_name(file_name)?.read_to_string(&mut buf)?;
-            Ok(serde_json::from_str::<T>(&buf)?)
+            match self.file.by_name(file_name) {
+                Ok(mut file) => file.read_to_string(&mut buf)?,
+                Err(ZipError::FileNotFound) => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+            Ok(Some(buf))
+        }
+
+        /// The names of the jars held inside of the archive, in the order they're stored
+        fn jar_names(&self) -> Vec<String> {
+            self.file
+                .file_names()
+                .filter(|name| is_jar(Path::new(name)))
+                .map(str::to_string)
+                .collect()
+        }
+
+        /// Reads a file held in the archive into memory, [None] if it's larger than `max_size` once uncompressed
+        /// The declared size isn't trusted, reading stops past the limit either way.
+        fn read_bytes(&mut self, file_name: &str, max_size: u64) -> Result<Option<Vec<u8>>> {
+            let file = self.file.by_name(file_name)?;
+            if file.size() > max_size {
+                return Ok(None);
+            }
+            let mut buf = vec![];
+            file.take(max_size + 1).read_to_end(&mut buf)?;
+            Ok((buf.len() as u64 <= max_size).then_some(buf))
         }
     }
 }
 
 pub mod ore_mod_info {
-    use serde::Deserialize;
+    use std::collections::{BTreeMap, HashMap};
 
-    /// A generic representation of both McMod.info and sponge_plugins.json
-    #[derive(Deserialize, Debug, PartialEq)]
+    use serde::{Deserialize, Serialize};
+
+    use crate::one_or_many::OneOrMany;
+
+    /// A generic representation of the metadata a jar can hold, such as mcmod.info or sponge_plugins.json
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
     pub struct OreModInfo {
         pub modid: String,
         pub name: String,
         pub version: String,
         pub major_api_version: u32,
+        /// Plugins this one depends on, excluding the Sponge API
+        #[serde(default)]
+        pub dependencies: Vec<PluginDependency>,
     }
 
     impl OreModInfo {
-        fn new(modid: String, name: String, version: String, major_api_version: u32) -> Self {
+        fn new(
+            modid: String,
+            name: String,
+            version: String,
+            major_api_version: u32,
+            dependencies: Vec<PluginDependency>,
+        ) -> Self {
             OreModInfo {
                 modid,
                 name,
                 version,
                 major_api_version,
+                dependencies,
             }
         }
     }
@@ -336,18 +823,36 @@ pub mod ore_mod_info {
         fn from(value: McModInfo) -> Self {
             let info = value;
             let major = info.sponge_tag_version();
-            OreModInfo::new(info.modid, info.name, info.version, major)
+            let dependencies = info.plugin_dependencies();
+            OreModInfo::new(info.modid, info.name, info.version, major, dependencies)
         }
     }
 
     impl From<PluginInfo> for OreModInfo {
         fn from(value: PluginInfo) -> Self {
             let plugin = value.first_plugin();
+            // Sponge 8 plugins often leave their version to the global section
+            let version = plugin
+                .version
+                .or(value
+                    .global
+                    .as_ref()
+                    .and_then(|global| global.version.clone()))
+                .unwrap_or_default();
+            let name = match plugin.name.is_empty() {
+                true => plugin.id.clone(),
+                false => plugin.name,
+            };
             OreModInfo::new(
                 plugin.id,
-                plugin.name,
-                plugin.version.unwrap_or_default().replace(' ', "-"),
+                name,
+                version.replace(' ', "-"),
                 value.major_api_version(),
+                plugin
+                    .dependencies
+                    .into_iter()
+                    .filter(|dep| !dep.is_sponge_dep())
+                    .collect(),
             )
         }
     }
@@ -365,7 +870,9 @@ pub mod ore_mod_info {
         pub modid: String,
         pub name: String,
         pub version: String,
+        #[serde(default)]
         pub dependencies: Vec<String>,
+        #[serde(default)]
         pub required_mods: Vec<String>,
     }
 
@@ -392,7 +899,40 @@ pub mod ore_mod_info {
                 .unwrap_or_default()
         }
 
-        fn find_major_version(&self, id: &'_ str, list: &Vec<String>) -> Option<u32> {
+        /// Reads the `id@version` entries of both lists, excluding the Sponge API
+        /// ```
+        /// use ore_monitor::ore_mod_info::McModInfo;
+        ///
+        /// let mod_info = McModInfo {
+        ///     modid : "nucleus".to_string(),
+        ///     name : "Nucleus".to_string(),
+        ///     version : "2.1.4".to_string(),
+        ///     dependencies : vec!["spongeapi@7.3".to_string(), "luckperms@[5.0,)".to_string()],
+        ///     required_mods : vec!["luckperms@[5.0,)".to_string(), "placeholderapi".to_string()]
+        /// };
+        ///
+        /// let deps = mod_info.plugin_dependencies();
+        /// assert_eq!(deps.len(), 2);
+        /// assert_eq!(deps[0].id, "luckperms");
+        /// assert_eq!(deps[0].version, "[5.0,)");
+        /// assert_eq!(deps[1].version, "");
+        /// ```
+        pub fn plugin_dependencies(&self) -> Vec<PluginDependency> {
+            let mut deps: Vec<PluginDependency> = vec![];
+            for entry in self.dependencies.iter().chain(&self.required_mods) {
+                let (id, version) = entry.split_once('@').unwrap_or((entry, ""));
+                let dep = PluginDependency {
+                    id: id.to_string(),
+                    version: version.to_string(),
+                };
+                if !dep.is_sponge_dep() && !deps.iter().any(|d| d.id == dep.id) {
+                    deps.push(dep)
+                }
+            }
+            deps
+        }
+
+        fn find_major_version(&self, id: &'_ str, list: &[String]) -> Option<u32> {
             list.iter()
                 .find(|str| str.starts_with(id))
                 .and_then(|str| str.split_once('@'))
@@ -401,6 +941,7 @@ pub mod ore_mod_info {
         }
     }
 
+    /// A sponge_plugins.json, the Sponge 8 loader and license sections are ignored
     #[derive(Deserialize, Debug, PartialEq)]
     pub struct PluginInfo {
         pub global: Option<GlobalPlugin>,
@@ -415,41 +956,42 @@ pub mod ore_mod_info {
                 .clone()
         }
 
+        /// The Sponge API is declared either globally or by the plugin itself
         fn major_api_version(&self) -> u32 {
             self.global
-                .clone()
-                .and_then(|f| Some(f.dependencies))
-                .or(Some(self.first_plugin().dependencies))
-                .unwrap_or_default()
                 .iter()
-                .filter(|dep| dep.is_sponge_dep())
-                .map(|ver| ver.major_api_version())
-                .collect::<Vec<u32>>()
-                .first()
-                .and_then(|f| Some(f.clone()))
+                .flat_map(|global| global.dependencies.iter())
+                .chain(self.first_plugin().dependencies.iter())
+                .find(|dep| dep.is_sponge_dep())
+                .map(|dep| dep.major_api_version())
                 .unwrap_or_default()
         }
     }
 
     #[derive(Deserialize, Debug, PartialEq, Clone)]
     pub struct GlobalPlugin {
-        pub version: String,
+        pub version: Option<String>,
+        #[serde(default)]
         pub dependencies: Vec<PluginDependency>,
     }
 
-    #[derive(Debug, Deserialize, PartialEq, Default, Clone)]
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
     pub struct PluginDependency {
         pub id: String,
+        #[serde(default)]
         pub version: String,
     }
 
     impl PluginDependency {
-        fn is_sponge_dep(&self) -> bool {
+        /// The Sponge API is provided by the server, it's never installed as a plugin
+        pub fn is_sponge_dep(&self) -> bool {
             self.id.eq_ignore_ascii_case("spongeapi")
         }
 
         fn major_api_version(&self) -> u32 {
+            // Sponge 8 declares the API as a range, ex: `[8.0,9)`
             self.version
+                .trim_start_matches(['[', '('])
                 .split_once('.')
                 .and_then(|(major, _)| major.parse().ok())
                 .unwrap_or_default()
@@ -459,8 +1001,4370 @@ pub mod ore_mod_info {
     #[derive(Deserialize, Debug, PartialEq, Clone, Default)]
     pub struct PluginData {
         pub id: String,
+        #[serde(default)]
         pub name: String,
         pub version: Option<String>,
+        #[serde(default)]
         pub dependencies: Vec<PluginDependency>,
     }
+
+    /// Ids a fabric.mod.json depends on that are provided by the platform, never installed as plugins
+    const FABRIC_PLATFORM: [&str; 5] =
+        ["minecraft", "java", "fabricloader", "fabric", "fabric-api"];
+
+    /// A partial representation of a fabric.mod.json
+    #[derive(Deserialize, Debug, PartialEq)]
+    pub struct FabricModInfo {
+        pub id: String,
+        pub name: Option<String>,
+        pub version: String,
+        /// Each dependency and the version, or versions, it's required at
+        #[serde(default)]
+        pub depends: BTreeMap<String, OneOrMany<String>>,
+    }
+
+    impl From<FabricModInfo> for OreModInfo {
+        /// ```
+        /// use ore_monitor::ore_mod_info::{FabricModInfo, OreModInfo};
+        ///
+        /// let json = r#"{
+        ///     "schemaVersion": 1,
+        ///     "id": "ledger",
+        ///     "version": "1.2.3",
+        ///     "depends": {"fabricloader": ">=0.14", "fabric-api": "*", "fabric-language-kotlin": [">=1.8"]}
+        /// }"#;
+        /// let info: OreModInfo = serde_json::from_str::<FabricModInfo>(json).unwrap().into();
+        ///
+        /// assert_eq!(info.name, "ledger");
+        /// assert_eq!(info.major_api_version, 0);
+        /// assert_eq!(info.dependencies.len(), 1);
+        /// assert_eq!(info.dependencies[0].version, ">=1.8");
+        /// ```
+        fn from(value: FabricModInfo) -> Self {
+            let dependencies = value
+                .depends
+                .into_iter()
+                .filter(|(id, _)| !FABRIC_PLATFORM.contains(&id.as_str()))
+                .map(|(id, versions)| PluginDependency {
+                    id,
+                    version: versions.to_vec().join(" || "),
+                })
+                .collect();
+            let name = value.name.unwrap_or(value.id.clone());
+            OreModInfo::new(value.id, name, value.version, 0, dependencies)
+        }
+    }
+
+    /// The attributes of a jar's META-INF/MANIFEST.MF, read when a jar holds no other metadata
+    #[derive(Debug, PartialEq, Default)]
+    pub struct JarManifest {
+        pub attributes: HashMap<String, String>,
+    }
+
+    impl JarManifest {
+        /// Reads the `Name: value` lines of a manifest, joining continued lines
+        /// ```
+        /// use ore_monitor::ore_mod_info::{JarManifest, OreModInfo};
+        ///
+        /// let manifest = JarManifest::parse(
+        ///     "Manifest-Version: 1.0\r\nImplementation-Title: Better Chat\r\nImplementation-Vers\r\n ion: 1.4.0\r\n",
+        /// );
+        /// assert_eq!(manifest.attributes["Implementation-Version"], "1.4.0");
+        ///
+        /// let info = OreModInfo::try_from(manifest).unwrap();
+        /// assert_eq!(info.modid, "betterchat");
+        /// assert_eq!(info.name, "Better Chat");
+        ///
+        /// assert!(OreModInfo::try_from(JarManifest::parse("Manifest-Version: 1.0")).is_err());
+        /// ```
+        pub fn parse(contents: &str) -> Self {
+            let mut lines: Vec<String> = vec![];
+            for line in contents.lines() {
+                match (line.strip_prefix(' '), lines.last_mut()) {
+                    (Some(continued), Some(last)) => last.push_str(continued),
+                    _ => lines.push(line.to_string()),
+                }
+            }
+            let attributes = lines
+                .iter()
+                .filter_map(|line| line.split_once(':'))
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .collect();
+            JarManifest { attributes }
+        }
+
+        fn get(&self, keys: &[&str]) -> Option<&str> {
+            keys.iter()
+                .find_map(|key| self.attributes.get(*key))
+                .map(String::as_str)
+                .filter(|value| !value.is_empty())
+        }
+    }
+
+    impl TryFrom<JarManifest> for OreModInfo {
+        type Error = anyhow::Error;
+
+        /// The name and version are required, the id falls back to the name without spaces or symbols
+        fn try_from(value: JarManifest) -> Result<Self, Self::Error> {
+            let (Some(name), Some(version)) = (
+                value.get(&["Implementation-Title", "Bundle-Name", "Specification-Title"]),
+                value.get(&[
+                    "Implementation-Version",
+                    "Bundle-Version",
+                    "Specification-Version",
+                ]),
+            ) else {
+                return Err(anyhow::Error::msg(
+                    "the manifest doesn't name a plugin and its version",
+                ));
+            };
+            let id = value
+                .get(&["Bundle-SymbolicName"])
+                .map(|id| id.split(';').next().unwrap_or(id).trim().to_string())
+                .unwrap_or_else(|| {
+                    name.to_lowercase()
+                        .chars()
+                        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+                        .collect()
+                });
+            Ok(OreModInfo::new(
+                id,
+                name.to_string(),
+                version.to_string(),
+                0,
+                vec![],
+            ))
+        }
+    }
+}
+
+pub mod file_hash {
+    use std::{
+        fs::File,
+        io::{BufReader, Read},
+        path::Path,
+    };
+
+    use anyhow::Result;
+    use md5::{Digest, Md5};
+    use sha2::{Sha256, Sha512};
+
+    /// Computes the md5 of a file as a lowercase hex [String]
+    /// ```
+    /// # use ore_monitor::file_hash::md5_hex;
+    /// # use std::path::Path;
+    /// let hash = md5_hex(Path::new("./local/test/nucleus.jar")).unwrap();
+    /// assert_eq!(hash.len(), 32);
+    /// assert_eq!(hash, md5_hex(Path::new("./local/test/nucleus.jar")).unwrap());
+    /// ```
+    pub fn md5_hex(path: &Path) -> Result<String> {
+        hex_digest::<Md5>(path)
+    }
+
+    /// Computes the sha512 of a file as a lowercase hex [String], as Modrinth publishes
+    /// ```
+    /// # use ore_monitor::file_hash::sha512_hex;
+    /// # use std::path::Path;
+    /// let hash = sha512_hex(Path::new("./local/test/nucleus.jar")).unwrap();
+    /// assert_eq!(hash.len(), 128);
+    /// ```
+    pub fn sha512_hex(path: &Path) -> Result<String> {
+        hex_digest::<Sha512>(path)
+    }
+
+    /// Computes the sha256 of a file as a lowercase hex [String]
+    /// ```
+    /// # use ore_monitor::file_hash::sha256_hex;
+    /// # use std::path::Path;
+    /// let hash = sha256_hex(Path::new("./local/test/nucleus.jar")).unwrap();
+    /// assert_eq!(hash.len(), 64);
+    /// ```
+    pub fn sha256_hex(path: &Path) -> Result<String> {
+        hex_digest::<Sha256>(path)
+    }
+
+    /// Lines of a checksum file as written by `sha256sum`, ordered by file name
+    /// ```
+    /// use ore_monitor::file_hash::checksum_lines;
+    ///
+    /// let sums = vec![
+    ///     ("nucleus.jar".to_string(), "b2".to_string()),
+    ///     ("huskycrates.jar".to_string(), "a1".to_string()),
+    /// ];
+    /// assert_eq!(checksum_lines(sums), "a1  huskycrates.jar\nb2  nucleus.jar\n");
+    /// ```
+    pub fn checksum_lines(mut sums: Vec<(String, String)>) -> String {
+        sums.sort();
+        sums.iter()
+            .map(|(file, hash)| format!("{}  {}\n", hash, file))
+            .collect()
+    }
+
+    fn hex_digest<D: Digest>(path: &Path) -> Result<String>
+    where
+        md5::digest::Output<D>: std::fmt::LowerHex,
+    {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut hasher = D::new();
+        let mut buf = [0; 8192];
+
+        loop {
+            let read = reader.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buf[..read]);
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Compares a file against an expected md5, ignoring case
+    /// Returns the actual hash when it does not match
+    pub fn verify_md5(path: &Path, expected: &str) -> Result<Option<String>> {
+        let actual = md5_hex(path)?;
+        Ok((!actual.eq_ignore_ascii_case(expected)).then_some(actual))
+    }
+}
+
+pub mod quarantine {
+    use std::{
+        fs::{self, OpenOptions},
+        io::Write,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::{Context, Result};
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    /// A directory that files failing verification are moved into
+    #[derive(Debug)]
+    pub struct Quarantine {
+        pub dir: PathBuf,
+    }
+
+    /// A record of a file that has been quarantined
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct QuarantineEvent {
+        pub file: PathBuf,
+        pub quarantined_to: PathBuf,
+        /// The hash that didn't match, events recorded before it was kept are all md5
+        #[serde(default = "QuarantineEvent::md5")]
+        pub algorithm: String,
+        #[serde(alias = "expected_md5")]
+        pub expected_hash: String,
+        #[serde(alias = "actual_md5")]
+        pub actual_hash: String,
+        pub at: DateTime<Utc>,
+    }
+
+    impl QuarantineEvent {
+        fn md5() -> String {
+            "md5".to_string()
+        }
+    }
+
+    impl Quarantine {
+        const DIR_NAME: &'static str = ".quarantine";
+        const LOG_NAME: &'static str = "quarantine.log";
+
+        pub fn new(dir: PathBuf) -> Self {
+            Quarantine { dir }
+        }
+
+        /// The default quarantine, located inside of the plugins directory
+        /// ```
+        /// # use ore_monitor::quarantine::Quarantine;
+        /// # use std::path::Path;
+        /// let quarantine = Quarantine::for_dir(Path::new("./plugins"));
+        /// assert_eq!(quarantine.dir, Path::new("./plugins/.quarantine"));
+        /// ```
+        pub fn for_dir(plugins_dir: &Path) -> Self {
+            Self::new(plugins_dir.join(Self::DIR_NAME))
+        }
+
+        /// Moves the file into the quarantine directory and records the event
+        pub fn isolate(
+            &self,
+            file: &Path,
+            algorithm: &str,
+            expected_hash: &str,
+            actual_hash: &str,
+        ) -> Result<QuarantineEvent> {
+            fs::create_dir_all(&self.dir)?;
+
+            let at = Utc::now();
+            let file_name = file
+                .file_name()
+                .and_then(|f| f.to_str())
+                .context("Quarantined path must point to a file")?;
+            // Prefixing the time keeps repeated failures of the same file apart
+            let quarantined_to =
+                self.dir
+                    .join(format!("{}-{}", at.format("%Y%m%d%H%M%S"), file_name));
+
+            fs::rename(file, &quarantined_to)?;
+
+            let event = QuarantineEvent {
+                file: file.to_path_buf(),
+                quarantined_to,
+                algorithm: algorithm.to_string(),
+                expected_hash: expected_hash.to_string(),
+                actual_hash: actual_hash.to_string(),
+                at,
+            };
+            self.record(&event)?;
+
+            Ok(event)
+        }
+
+        /// Appends the event to the quarantine log as a line of json
+        fn record(&self, event: &QuarantineEvent) -> Result<()> {
+            let mut log = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.dir.join(Self::LOG_NAME))?;
+            writeln!(log, "{}", serde_json::to_string(event)?)?;
+            Ok(())
+        }
+    }
+}
+
+pub mod discard {
+    use std::{fs, path::Path};
+
+    use anyhow::Result;
+
+    /// Removes a jar that's no longer installed, moving it to the OS trash unless it's removed permanently
+    /// The trash keeps it recoverable after the backups of an update are discarded.
+    /// ```
+    /// # use ore_monitor::discard::discard;
+    /// # use std::fs;
+    /// let dir = std::env::temp_dir().join("ore_monitor_discard_doctest");
+    /// fs::create_dir_all(&dir).unwrap();
+    /// fs::write(dir.join("plugin-1.0.jar"), "old").unwrap();
+    ///
+    /// discard(&dir.join("plugin-1.0.jar"), true).unwrap();
+    /// assert!(!dir.join("plugin-1.0.jar").exists());
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn discard(path: &Path, permanent: bool) -> Result<()> {
+        if permanent {
+            fs::remove_file(path)?;
+            return Ok(());
+        }
+        trash::delete(path).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Unable to move '{}' to the trash, add --permanent to delete it instead : {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+}
+
+pub mod disabled_plugins {
+    use std::path::{Path, PathBuf};
+
+    use crate::file_reader::is_jar;
+
+    /// Suffixes added to a jar's name that keep the server from loading it
+    pub const SUFFIXES: [&str; 2] = ["disabled", "off"];
+
+    /// Whether the jar has been disabled by renaming it, ex: `nucleus.jar.disabled` or `nucleus.jar.off`
+    /// ```
+    /// use ore_monitor::disabled_plugins::is_disabled;
+    /// use std::path::Path;
+    ///
+    /// assert!(is_disabled(Path::new("plugins/nucleus.jar.disabled")));
+    /// assert!(is_disabled(Path::new("plugins/nucleus.jar.OFF")));
+    /// assert!(!is_disabled(Path::new("plugins/nucleus.jar")));
+    /// assert!(!is_disabled(Path::new("plugins/notes.txt.disabled")));
+    /// ```
+    pub fn is_disabled(path: &Path) -> bool {
+        enabled_path(path).is_some()
+    }
+
+    /// Where the jar is moved to when disabled
+    /// ```
+    /// use ore_monitor::disabled_plugins::disabled_path;
+    /// use std::path::Path;
+    ///
+    /// let path = disabled_path(Path::new("plugins/nucleus.jar"));
+    /// assert_eq!(path, Path::new("plugins/nucleus.jar.disabled"));
+    /// ```
+    pub fn disabled_path(path: &Path) -> PathBuf {
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(".");
+        name.push(SUFFIXES[0]);
+        path.with_file_name(name)
+    }
+
+    /// Where a disabled jar is moved back to when enabled, [None] if the jar isn't disabled
+    /// ```
+    /// use ore_monitor::disabled_plugins::enabled_path;
+    /// use std::path::Path;
+    ///
+    /// let path = enabled_path(Path::new("plugins/nucleus.jar.off"));
+    /// assert_eq!(path.as_deref(), Some(Path::new("plugins/nucleus.jar")));
+    /// assert_eq!(enabled_path(Path::new("plugins/nucleus.jar")), None);
+    /// ```
+    pub fn enabled_path(path: &Path) -> Option<PathBuf> {
+        let name = path.file_name()?.to_str()?;
+        let (jar, suffix) = name.rsplit_once('.')?;
+        let disabled = SUFFIXES
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(suffix));
+        (disabled && is_jar(Path::new(jar))).then(|| path.with_file_name(jar))
+    }
+}
+
+pub mod file_naming {
+    use std::{convert::Infallible, str::FromStr};
+
+    /// A template describing how installed jars should be named
+    ///
+    /// Supports the placeholders `{id}`, `{version}` and `{file}`,
+    /// where `{file}` is the name the file was served with.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NameTemplate(String);
+
+    impl Default for NameTemplate {
+        fn default() -> Self {
+            NameTemplate("{file}".to_string())
+        }
+    }
+
+    impl FromStr for NameTemplate {
+        type Err = Infallible;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(NameTemplate(s.to_string()))
+        }
+    }
+
+    impl NameTemplate {
+        /// Renders a file name from the template
+        /// Characters that aren't safe in a file name are replaced with `-`
+        /// ```
+        /// use ore_monitor::file_naming::NameTemplate;
+        ///
+        /// let template: NameTemplate = "{id}-{version}.jar".parse().unwrap();
+        /// let name = template.render("nucleus", "2.1.4", "Nucleus-2.1.4-S7.1-MC1.12.2.jar");
+        /// assert_eq!(name, "nucleus-2.1.4.jar");
+        ///
+        /// let name = NameTemplate::default().render("nucleus", "2.1.4", "Nucleus-2.1.4.jar");
+        /// assert_eq!(name, "Nucleus-2.1.4.jar");
+        ///
+        /// let name = template.render("huskycrates", "2.0.0 PRE9", "HuskyCrates.jar");
+        /// assert_eq!(name, "huskycrates-2.0.0-PRE9.jar");
+        /// ```
+        pub fn render(&self, id: &str, version: &str, file: &str) -> String {
+            let sanitize = |s: &str| s.replace(['/', '\\', ' '], "-");
+            self.0
+                .replace("{id}", &sanitize(id))
+                .replace("{version}", &sanitize(version))
+                .replace("{file}", &sanitize(file))
+        }
+    }
+
+    /// Reads the file name out of a `Content-Disposition` header
+    /// ```
+    /// use ore_monitor::file_naming::extract_filename;
+    ///
+    /// let header = r#"attachment; filename="Nucleus-2.1.4.jar""#;
+    /// assert_eq!(extract_filename(header), Some("Nucleus-2.1.4.jar"));
+    ///
+    /// assert_eq!(extract_filename("attachment"), None);
+    /// // A lone or empty quote isn't a name
+    /// assert_eq!(extract_filename(r#"attachment; filename=""#), None);
+    /// assert_eq!(extract_filename(r#"attachment; filename="""#), None);
+    /// ```
+    pub fn extract_filename(header: &str) -> Option<&str> {
+        let start = header.find('"')?;
+        let end = header.rfind('"')?;
+        (start < end)
+            .then(|| &header[start + 1..end])
+            .filter(|name| !name.is_empty())
+    }
+}
+
+pub mod lockfile {
+    use std::{
+        collections::BTreeMap,
+        env, fs,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        clock::Clock,
+        instance_lock::write_atomic,
+        lock_signature::{self, LockSignature},
+    };
+
+    /// Records the exact plugin versions installed into a directory
+    #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+    pub struct Lockfile {
+        #[serde(default)]
+        pub plugins: BTreeMap<String, LockedPlugin>,
+    }
+
+    /// A single plugin entry of the [Lockfile]
+    #[derive(Serialize, Deserialize, Debug, Default, PartialEq, Clone)]
+    pub struct LockedPlugin {
+        pub version: String,
+        pub md5: Option<String>,
+        pub file: Option<String>,
+        pub url: Option<String>,
+        /// The jar was disabled with the `disable` command
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        pub disabled: bool,
+        /// The owner of the Ore project it was installed from
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub owner: Option<String>,
+        /// Who downloaded the jar, and when
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub provenance: Option<Provenance>,
+    }
+
+    /// Who introduced a jar into the directory, and with what
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+    pub struct Provenance {
+        pub installed_at: DateTime<Utc>,
+        /// The ore-monitor version that downloaded it
+        pub tool: String,
+        /// The user running it
+        pub operator: Option<String>,
+        /// The [fingerprint](crate::sponge_schemas::OreSession::fingerprint) of the Ore session it was downloaded with
+        pub session: Option<String>,
+    }
+
+    impl Provenance {
+        /// The provenance of a download happening now, by the clock
+        pub fn now(session: Option<String>, clock: &dyn Clock) -> Provenance {
+            Provenance {
+                installed_at: clock.now(),
+                tool: format!("ore-monitor {}", env!("CARGO_PKG_VERSION")),
+                operator: env::var("USER").or(env::var("USERNAME")).ok(),
+                session,
+            }
+        }
+    }
+
+    impl Lockfile {
+        pub const FILE_NAME: &'static str = "ore-monitor.lock";
+
+        pub fn path_in(dir: &Path) -> PathBuf {
+            dir.join(Self::FILE_NAME)
+        }
+
+        /// Reads the lockfile from the directory, if one exists
+        pub fn load(dir: &Path) -> Result<Option<Lockfile>> {
+            let path = Self::path_in(dir);
+            if !path.is_file() {
+                return Ok(None);
+            }
+            Ok(Some(toml::from_str(&fs::read_to_string(path)?)?))
+        }
+
+        pub fn load_or_default(dir: &Path) -> Result<Lockfile> {
+            Ok(Self::load(dir)?.unwrap_or_default())
+        }
+
+        /// Saves the lockfile, signing it when a signing key was generated
+        /// Without a key an earlier signature is left as is, and no longer matches.
+        pub fn save(&self, dir: &Path) -> Result<()> {
+            let contents = toml::to_string_pretty(self)?;
+            write_atomic(&Self::path_in(dir), &contents)?;
+            if let Some(key) = lock_signature::load_key()? {
+                LockSignature::sign(contents.as_bytes(), &key).save(dir)?;
+            }
+            Ok(())
+        }
+
+        pub fn get(&self, id: &str) -> Option<&LockedPlugin> {
+            self.plugins.get(id)
+        }
+
+        pub fn insert(&mut self, id: &str, plugin: LockedPlugin) {
+            self.plugins.insert(id.to_string(), plugin);
+        }
+
+        /// Returns the locked entry when the local version no longer matches it
+        /// ```
+        /// use ore_monitor::lockfile::{LockedPlugin, Lockfile};
+        ///
+        /// let mut lockfile = Lockfile::default();
+        /// lockfile.insert("nucleus", LockedPlugin {
+        ///     version: "2.1.4".to_string(),
+        ///     ..Default::default()
+        /// });
+        ///
+        /// assert!(lockfile.drift("nucleus", "2.1.4").is_none());
+        /// assert_eq!(lockfile.drift("nucleus", "2.1.5").unwrap().version, "2.1.4");
+        /// assert!(lockfile.drift("huskycrates", "2.0.0").is_none());
+        /// ```
+        pub fn drift(&self, id: &str, local_version: &str) -> Option<&LockedPlugin> {
+            self.get(id)
+                .filter(|locked| locked.version != local_version)
+        }
+
+        /// The owner the plugin was locked from, when the project now belongs to another
+        /// After a rename or a fork the same id can resolve to another owner's project.
+        /// Entries locked before owners were recorded can't have changed.
+        /// ```
+        /// use ore_monitor::lockfile::{LockedPlugin, Lockfile};
+        ///
+        /// let mut lockfile = Lockfile::default();
+        /// lockfile.insert("nucleus", LockedPlugin {
+        ///     owner: Some("NucleusPowered".to_string()),
+        ///     ..Default::default()
+        /// });
+        /// lockfile.insert("huskycrates", LockedPlugin::default());
+        ///
+        /// assert_eq!(lockfile.owner_change("nucleus", "SomeoneElse"), Some("NucleusPowered"));
+        /// assert_eq!(lockfile.owner_change("nucleus", "NucleusPowered"), None);
+        /// assert_eq!(lockfile.owner_change("huskycrates", "SomeoneElse"), None);
+        /// assert_eq!(lockfile.owner_change("luckperms", "SomeoneElse"), None);
+        /// ```
+        pub fn owner_change(&self, id: &str, owner: &str) -> Option<&str> {
+            self.get(id)
+                .and_then(|locked| locked.owner.as_deref())
+                .filter(|locked| *locked != owner)
+        }
+    }
+}
+
+pub mod lock_signature {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+    use rand_core::OsRng;
+    use serde::{Deserialize, Serialize};
+
+    use crate::instance_lock::{write_atomic, write_private};
+
+    /// An ed25519 signature of a lockfile, saved next to it as `ore-monitor.lock.sig`
+    /// Holds the public key it was made with, which is only accepted when it's the trusted key.
+    /// ```
+    /// use ed25519_dalek::SigningKey;
+    /// use ore_monitor::lock_signature::LockSignature;
+    ///
+    /// let key = SigningKey::from_bytes(&[7; 32]);
+    /// let signature = LockSignature::sign(b"[plugins.nucleus]", &key);
+    ///
+    /// assert!(signature.verify(b"[plugins.nucleus]", &signature.key).is_ok());
+    /// // Any change to the lockfile breaks the signature
+    /// assert!(signature.verify(b"[plugins.luckperms]", &signature.key).is_err());
+    ///
+    /// // A lockfile re-signed with another key carries that key, which isn't trusted
+    /// let other = LockSignature::sign(b"[plugins.nucleus]", &SigningKey::from_bytes(&[8; 32]));
+    /// assert!(other.verify(b"[plugins.nucleus]", &signature.key).is_err());
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct LockSignature {
+        /// The base64 public key of the signer
+        pub key: String,
+        /// The base64 signature of the lockfile's contents
+        pub signature: String,
+    }
+
+    impl LockSignature {
+        pub const FILE_NAME: &'static str = "ore-monitor.lock.sig";
+
+        pub fn path_in(dir: &Path) -> PathBuf {
+            dir.join(Self::FILE_NAME)
+        }
+
+        pub fn sign(contents: &[u8], key: &SigningKey) -> LockSignature {
+            LockSignature {
+                key: STANDARD.encode(key.verifying_key().as_bytes()),
+                signature: STANDARD.encode(key.sign(contents).to_bytes()),
+            }
+        }
+
+        /// Checks the contents are what was signed, by the trusted public key
+        pub fn verify(&self, contents: &[u8], trusted: &str) -> Result<()> {
+            if trusted.trim() != self.key {
+                return Err(anyhow::Error::msg(format!(
+                    "The lockfile was signed by {}, which isn't the trusted key",
+                    self.key
+                )));
+            }
+            let key: [u8; 32] = STANDARD
+                .decode(&self.key)?
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("The public key isn't 32 bytes"))?;
+            let signature: [u8; 64] = STANDARD
+                .decode(&self.signature)?
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("The signature isn't 64 bytes"))?;
+
+            VerifyingKey::from_bytes(&key)?
+                .verify(contents, &Signature::from_bytes(&signature))
+                .map_err(|_| anyhow::Error::msg("The lockfile doesn't match its signature"))
+        }
+
+        /// Reads the signature of the lockfile in the directory, if it was signed
+        pub fn load(dir: &Path) -> Result<Option<LockSignature>> {
+            let path = Self::path_in(dir);
+            if !path.is_file() {
+                return Ok(None);
+            }
+            Ok(Some(toml::from_str(&fs::read_to_string(path)?)?))
+        }
+
+        pub fn save(&self, dir: &Path) -> Result<()> {
+            write_atomic(&Self::path_in(dir), toml::to_string_pretty(self)?)?;
+            Ok(())
+        }
+    }
+
+    /// The key lockfiles are signed with, located in the platform's config directory
+    /// Lockfiles are only signed once a key was generated.
+    pub fn key_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("ore-monitor").join("lock-signing.key"))
+    }
+
+    /// The signing key, if one was generated
+    pub fn load_key() -> Result<Option<SigningKey>> {
+        let Some(path) = key_path().filter(|path| path.is_file()) else {
+            return Ok(None);
+        };
+        let bytes: [u8; 32] = STANDARD
+            .decode(fs::read_to_string(path)?.trim())?
+            .try_into()
+            .map_err(|_| anyhow::Error::msg("The signing key isn't 32 bytes"))?;
+        Ok(Some(SigningKey::from_bytes(&bytes)))
+    }
+
+    /// Generates a signing key, refusing to replace one as lockfiles signed with it could no longer be re-signed
+    /// Returns the base64 public key, which verifiers are given.
+    pub fn generate_key() -> Result<String> {
+        let path = key_path()
+            .ok_or_else(|| anyhow::Error::msg("No config directory to keep the key in"))?;
+        if path.exists() {
+            return Err(anyhow::Error::msg(format!(
+                "A signing key already exists at '{}'",
+                path.display()
+            )));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let key = SigningKey::generate(&mut OsRng);
+        write_private(&path, STANDARD.encode(key.to_bytes()))?;
+        Ok(STANDARD.encode(key.verifying_key().as_bytes()))
+    }
+}
+
+pub mod manifest {
+    use std::{collections::BTreeMap, fs, path::Path};
+
+    use anyhow::Result;
+    use serde::{Deserialize, Serialize};
+
+    use crate::instance_lock::write_atomic;
+
+    /// The plugins a server should have installed, read by `install --manifest` and written by `freeze`
+    /// ```
+    /// use ore_monitor::manifest::Manifest;
+    ///
+    /// let manifest: Manifest = toml::from_str(r#"
+    ///     [plugins]
+    ///     nucleus = "2.1.4"
+    ///     luckperms = "latest"
+    ///     huskycrates = { version = "[2.0,3.0)", api = 7 }
+    ///     griefprevention = { api = 8, id = "griefpreventionsponge" }
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(manifest.plugins["nucleus"].version(), "2.1.4");
+    /// assert_eq!(manifest.plugins["huskycrates"].version(), "[2.0,3.0)");
+    /// assert_eq!(manifest.plugins["huskycrates"].api(), Some(7));
+    /// assert_eq!(manifest.plugins["griefprevention"].version(), "latest");
+    /// assert_eq!(manifest.plugins["luckperms"].api(), None);
+    /// assert_eq!(manifest.plugins["griefprevention"].id(), Some("griefpreventionsponge"));
+    /// assert_eq!(manifest, toml::from_str(&toml::to_string(&manifest).unwrap()).unwrap());
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+    pub struct Manifest {
+        #[serde(default)]
+        pub plugins: BTreeMap<String, ManifestEntry>,
+    }
+
+    /// The version constraint of a plugin in the [Manifest]
+    /// An exact version, `latest`, or a range such as `[2.0,3.0)`
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+    #[serde(untagged)]
+    pub enum ManifestEntry {
+        Version(String),
+        Detailed {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            version: Option<String>,
+            /// The Sponge API major version the installed version must be for
+            #[serde(skip_serializing_if = "Option::is_none")]
+            api: Option<u32>,
+            /// The id on the plugin's source, when it differs from the plugin id
+            #[serde(skip_serializing_if = "Option::is_none")]
+            id: Option<String>,
+        },
+    }
+
+    impl ManifestEntry {
+        pub fn version(&self) -> &str {
+            match self {
+                ManifestEntry::Version(version) => version,
+                ManifestEntry::Detailed { version, .. } => version.as_deref().unwrap_or("latest"),
+            }
+        }
+
+        pub fn api(&self) -> Option<u32> {
+            match self {
+                ManifestEntry::Version(_) => None,
+                ManifestEntry::Detailed { api, .. } => *api,
+            }
+        }
+
+        pub fn id(&self) -> Option<&str> {
+            match self {
+                ManifestEntry::Version(_) => None,
+                ManifestEntry::Detailed { id, .. } => id.as_deref(),
+            }
+        }
+
+        /// Pins an exact version, keeping the id on the source only when it differs
+        pub fn exact(plugin_id: &str, version: &str, remote_id: &str) -> ManifestEntry {
+            match plugin_id == remote_id {
+                true => ManifestEntry::Version(version.to_string()),
+                false => ManifestEntry::Detailed {
+                    version: Some(version.to_string()),
+                    api: None,
+                    id: Some(remote_id.to_string()),
+                },
+            }
+        }
+    }
+
+    impl Manifest {
+        pub fn load(path: &Path) -> Result<Manifest> {
+            Ok(toml::from_str(&fs::read_to_string(path)?)?)
+        }
+
+        pub fn save(&self, path: &Path) -> Result<()> {
+            write_atomic(path, toml::to_string_pretty(self)?)?;
+            Ok(())
+        }
+    }
+}
+
+pub mod conflict {
+    use std::{
+        fmt::Display,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use clap::ValueEnum;
+
+    use crate::{file_hash::md5_hex, lockfile::LockedPlugin};
+
+    /// How the plugins directory disagrees with the lockfile about a plugin
+    /// ```
+    /// # use ore_monitor::{conflict::Conflict, lockfile::LockedPlugin};
+    /// # use std::fs;
+    /// let dir = std::env::temp_dir().join("ore_monitor_conflict_doctest");
+    /// # let _ = fs::remove_dir_all(&dir);
+    /// fs::create_dir_all(&dir).unwrap();
+    /// fs::write(dir.join("nucleus.jar"), "edited").unwrap();
+    ///
+    /// let locked = |file: &str| LockedPlugin {
+    ///     version: "2.1.4".to_string(),
+    ///     md5: Some("0cc175b9c0f1b6a831c399e269772661".to_string()),
+    ///     file: Some(file.to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let modified = Conflict::detect(&dir, Some(&locked("nucleus.jar")), None).unwrap();
+    /// assert_eq!(modified, Some(Conflict::Modified(dir.join("nucleus.jar"))));
+    ///
+    /// let missing = Conflict::detect(&dir, Some(&locked("luckperms.jar")), None).unwrap();
+    /// assert_eq!(missing, Some(Conflict::Missing(dir.join("luckperms.jar"))));
+    ///
+    /// let unlocked = Conflict::detect(&dir, None, Some(&dir.join("nucleus.jar"))).unwrap();
+    /// assert_eq!(unlocked, Some(Conflict::Unlocked(dir.join("nucleus.jar"))));
+    ///
+    /// assert_eq!(Conflict::detect(&dir, None, None).unwrap(), None);
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    #[derive(Debug, PartialEq)]
+    pub enum Conflict {
+        /// The locked jar is gone, ex: deleted by hand or lost to a failed update
+        Missing(PathBuf),
+        /// The locked jar no longer matches its md5, ex: replaced by hand
+        Modified(PathBuf),
+        /// A jar of the plugin is in the directory without being locked
+        Unlocked(PathBuf),
+    }
+
+    impl Conflict {
+        /// Compares the locked entry of a plugin with the directory
+        /// `found` is the jar of the plugin the directory holds, when it was found by a scan.
+        /// Disabled plugins are left alone, their jar was renamed on purpose.
+        pub fn detect(
+            dir: &Path,
+            locked: Option<&LockedPlugin>,
+            found: Option<&Path>,
+        ) -> Result<Option<Conflict>> {
+            let Some(locked) = locked else {
+                return Ok(found.map(|found| Conflict::Unlocked(found.to_path_buf())));
+            };
+            let Some(path) = locked.file.as_ref().map(|file| dir.join(file)) else {
+                return Ok(None);
+            };
+            if locked.disabled {
+                return Ok(None);
+            }
+            if !path.is_file() {
+                return Ok(Some(Conflict::Missing(path)));
+            }
+            match &locked.md5 {
+                Some(md5) if *md5 != md5_hex(&path)? => Ok(Some(Conflict::Modified(path))),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    impl Display for Conflict {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Conflict::Missing(path) => {
+                    write!(f, "the locked jar '{}' is missing", path.display())
+                }
+                Conflict::Modified(path) => {
+                    write!(f, "'{}' changed since it was locked", path.display())
+                }
+                Conflict::Unlocked(path) => {
+                    write!(f, "'{}' isn't in the lockfile", path.display())
+                }
+            }
+        }
+    }
+
+    /// How a [Conflict] is resolved
+    #[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+    pub enum Resolution {
+        /// Keeps the directory as it is, recording its jar in the lockfile
+        Local,
+        /// Installs the manifest's version, replacing the jar of the directory
+        Remote,
+        /// Leaves both the directory and the lockfile untouched
+        Skip,
+    }
+}
+
+pub mod duration {
+    use std::time::Duration;
+
+    /// Parses a duration such as `45s`, `30m`, `6h` or `1d`
+    /// ```
+    /// use ore_monitor::duration::parse_duration;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(parse_duration("30m"), Ok(Duration::from_secs(30 * 60)));
+    /// assert_eq!(parse_duration("6h"), Ok(Duration::from_secs(6 * 60 * 60)));
+    /// assert_eq!(parse_duration("1d"), Ok(Duration::from_secs(24 * 60 * 60)));
+    /// assert!(parse_duration("6x").is_err());
+    /// assert!(parse_duration("h").is_err());
+    /// assert!(parse_duration("18446744073709551615d").is_err());
+    /// ```
+    pub fn parse_duration(value: &str) -> Result<Duration, String> {
+        let value = value.trim();
+        let split = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (amount, unit) = value.split_at(split);
+
+        let amount: u64 = amount
+            .parse()
+            .map_err(|_| format!("'{}' does not start with a number", value))?;
+
+        let seconds = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            _ => return Err(format!("'{}' is not a valid unit, use s, m, h or d", unit)),
+        };
+
+        amount
+            .checked_mul(seconds)
+            .map(Duration::from_secs)
+            .ok_or_else(|| format!("'{}' is too long", value))
+    }
+
+    /// Parses a duration to wait between repeated work, which can't be zero
+    /// ```
+    /// use ore_monitor::duration::parse_interval;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(parse_interval("45s"), Ok(Duration::from_secs(45)));
+    /// assert!(parse_interval("0s").is_err());
+    /// assert!(parse_interval("0d").is_err());
+    /// ```
+    pub fn parse_interval(value: &str) -> Result<Duration, String> {
+        match parse_duration(value)? {
+            Duration::ZERO => Err(format!("'{}' is too short, it can't be zero", value.trim())),
+            interval => Ok(interval),
+        }
+    }
+}
+
+pub mod error {
+    use reqwest::{Method, StatusCode};
+    use std::{error::Error, fmt::Display};
+
+    use crate::request_context::RequestId;
+
+    /// Why a request to Ore failed, with the endpoint it was sent to
+    /// Returned by the [OreClient](crate::ore::ore_client::OreClient) behind [anyhow::Error], so it can be downcast.
+    /// ```
+    /// use ore_monitor::error::OreMonitorError;
+    /// use reqwest::{Method, StatusCode};
+    ///
+    /// let error = OreMonitorError::Status {
+    ///     request: None,
+    ///     method: Method::GET,
+    ///     endpoint: "/api/v2/projects/nucleus".to_string(),
+    ///     status: StatusCode::NOT_FOUND,
+    /// };
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "GET /api/v2/projects/nucleus failed with 404 Not Found : \
+    ///     Resource not found! Ensure you've used the correct identifiers"
+    /// );
+    /// assert_eq!(error.exit_code(), 6);
+    /// ```
+    /// Errors of requests that were sent name the request, matching the debug logs and the debug bundle
+    /// ```
+    /// use ore_monitor::{error::OreMonitorError, request_context::RequestId};
+    /// use reqwest::{Method, StatusCode};
+    ///
+    /// let error = OreMonitorError::Status {
+    ///     request: None,
+    ///     method: Method::GET,
+    ///     endpoint: "/api/v2/projects/nucleus".to_string(),
+    ///     status: StatusCode::BAD_GATEWAY,
+    /// }
+    /// .with_request(RequestId::from(0x7f3a));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "request 7f3a GET /api/v2/projects/nucleus failed with 502 Bad Gateway : \
+    ///     Ore is having issues, try again later"
+    /// );
+    /// ```
+    #[derive(Debug)]
+    pub enum OreMonitorError {
+        /// Ore responded with an error status
+        Status {
+            request: Option<RequestId>,
+            method: Method,
+            endpoint: String,
+            status: StatusCode,
+        },
+        /// The body of a response isn't what was expected, the snippet is the start of the body
+        Deserialize {
+            request: Option<RequestId>,
+            endpoint: String,
+            snippet: String,
+            source: serde_json::Error,
+        },
+        /// The request couldn't be sent, or its response couldn't be received
+        Http {
+            request: Option<RequestId>,
+            endpoint: String,
+            source: reqwest::Error,
+        },
+        Io(std::io::Error),
+    }
+
+    impl OreMonitorError {
+        /// How much of a body is kept for [OreMonitorError::Deserialize]
+        pub const SNIPPET_LEN: usize = 200;
+
+        /// The start of the body, cut at a character boundary
+        /// ```
+        /// use ore_monitor::error::OreMonitorError;
+        ///
+        /// assert_eq!(OreMonitorError::snippet(b"<html>"), "<html>");
+        /// assert_eq!(OreMonitorError::snippet(&[b'a'; 500]).len(), OreMonitorError::SNIPPET_LEN);
+        /// ```
+        pub fn snippet(body: &[u8]) -> String {
+            let mut snippet = String::from_utf8_lossy(body).to_string();
+            if snippet.len() > Self::SNIPPET_LEN {
+                let mut end = Self::SNIPPET_LEN;
+                while !snippet.is_char_boundary(end) {
+                    end -= 1;
+                }
+                snippet.truncate(end);
+            }
+            snippet
+        }
+
+        /// Names the request the error came from
+        pub fn with_request(mut self, id: RequestId) -> Self {
+            match &mut self {
+                OreMonitorError::Status { request, .. }
+                | OreMonitorError::Deserialize { request, .. }
+                | OreMonitorError::Http { request, .. } => *request = Some(id),
+                OreMonitorError::Io(_) => (),
+            }
+            self
+        }
+
+        /// The status Ore responded with, if it responded at all
+        pub fn status(&self) -> Option<StatusCode> {
+            match self {
+                OreMonitorError::Status { status, .. } => Some(*status),
+                OreMonitorError::Http { source, .. } => source.status(),
+                _ => None,
+            }
+        }
+
+        /// The code the process exits with, see the Exit codes section of the readme
+        pub fn exit_code(&self) -> i32 {
+            match self {
+                OreMonitorError::Status { status, .. } => match *status {
+                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => 4,
+                    StatusCode::NOT_FOUND => 6,
+                    _ => 5,
+                },
+                OreMonitorError::Http { .. } => 7,
+                OreMonitorError::Deserialize { .. } => 8,
+                OreMonitorError::Io(_) => 9,
+            }
+        }
+
+        /// What the status usually means when Ore responds with it
+        fn hint(status: StatusCode) -> &'static str {
+            match status {
+                StatusCode::BAD_REQUEST => "Request not made with a session",
+                StatusCode::UNAUTHORIZED => "Api session missing, invalid, or expired",
+                StatusCode::FORBIDDEN => "Not enough permission for endpoint",
+                StatusCode::NOT_FOUND => {
+                    "Resource not found! Ensure you've used the correct identifiers"
+                }
+                StatusCode::TOO_MANY_REQUESTS => "Rate limited by Ore, try again later",
+                status if status.is_server_error() => "Ore is having issues, try again later",
+                _ => "Unexpected Status Code",
+            }
+        }
+    }
+
+    impl Display for OreMonitorError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                OreMonitorError::Status {
+                    request,
+                    method,
+                    endpoint,
+                    status,
+                } => {
+                    if let Some(id) = request {
+                        write!(f, "request {} ", id)?;
+                    }
+                    write!(
+                        f,
+                        "{} {} failed with {} : {}",
+                        method,
+                        endpoint,
+                        status,
+                        Self::hint(*status)
+                    )
+                }
+                OreMonitorError::Deserialize {
+                    request,
+                    endpoint,
+                    snippet,
+                    source,
+                } => {
+                    if let Some(id) = request {
+                        write!(f, "request {} : ", id)?;
+                    }
+                    write!(
+                        f,
+                        "Unexpected response from {} : {}\n{}",
+                        endpoint, source, snippet
+                    )
+                }
+                OreMonitorError::Http {
+                    request,
+                    endpoint,
+                    source,
+                } => {
+                    if let Some(id) = request {
+                        write!(f, "request {} : ", id)?;
+                    }
+                    write!(f, "Unable to reach {} : {}", endpoint, source)
+                }
+                OreMonitorError::Io(e) => write!(f, "{}", e),
+            }
+        }
+    }
+
+    impl Error for OreMonitorError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                OreMonitorError::Status { .. } => None,
+                OreMonitorError::Deserialize { source, .. } => Some(source),
+                OreMonitorError::Http { source, .. } => Some(source),
+                OreMonitorError::Io(e) => Some(e),
+            }
+        }
+    }
+
+    impl From<std::io::Error> for OreMonitorError {
+        fn from(value: std::io::Error) -> Self {
+            OreMonitorError::Io(value)
+        }
+    }
+}
+
+pub mod retry {
+    use chrono::{DateTime, Utc};
+    use reqwest::StatusCode;
+    use std::time::Duration;
+
+    /// How requests that failed on a blip, such as a rate limit or a timeout, are sent again
+    /// The wait doubles after every attempt, up to the max delay.
+    /// ```
+    /// use ore_monitor::retry::RetryPolicy;
+    /// use std::time::Duration;
+    ///
+    /// let policy = RetryPolicy::default();
+    /// assert_eq!(policy.backoff(1), Duration::from_secs(1));
+    /// assert_eq!(policy.backoff(3), Duration::from_secs(4));
+    /// assert_eq!(policy.backoff(20), policy.max_delay);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct RetryPolicy {
+        /// Attempts of a request including the first, one never retries
+        pub max_attempts: u32,
+        /// Wait before the first retry
+        pub base_delay: Duration,
+        /// Longest wait between attempts, a longer `Retry-After` is cut down to it
+        pub max_delay: Duration,
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            RetryPolicy {
+                max_attempts: 4,
+                base_delay: Duration::from_secs(1),
+                max_delay: Duration::from_secs(30),
+            }
+        }
+    }
+
+    impl RetryPolicy {
+        /// A policy sending every request once
+        pub fn never() -> Self {
+            RetryPolicy {
+                max_attempts: 1,
+                ..Default::default()
+            }
+        }
+
+        /// The wait after the attempt failed, attempts count from one
+        pub fn backoff(&self, attempt: u32) -> Duration {
+            let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+            self.base_delay
+                .checked_mul(factor)
+                .unwrap_or(self.max_delay)
+                .min(self.max_delay)
+        }
+
+        /// The wait after the attempt failed, the server's `Retry-After` taking priority over the backoff
+        pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+            retry_after
+                .map(|delay| delay.min(self.max_delay))
+                .unwrap_or_else(|| self.backoff(attempt))
+        }
+
+        /// Whether the response is worth another attempt, being rate limited or a server error
+        /// ```
+        /// use ore_monitor::retry::RetryPolicy;
+        /// use reqwest::StatusCode;
+        ///
+        /// assert!(RetryPolicy::retries_status(StatusCode::TOO_MANY_REQUESTS));
+        /// assert!(RetryPolicy::retries_status(StatusCode::BAD_GATEWAY));
+        /// assert!(!RetryPolicy::retries_status(StatusCode::NOT_FOUND));
+        /// ```
+        pub fn retries_status(status: StatusCode) -> bool {
+            status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+        }
+
+        /// Whether the request failed without a response in a way worth another attempt
+        pub fn retries_error(error: &reqwest::Error) -> bool {
+            error.is_timeout() || error.is_connect()
+        }
+    }
+
+    /// Reads a `Retry-After` header, either seconds or an HTTP date
+    /// A date in the past means retrying right away.
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use ore_monitor::retry::retry_after;
+    /// use std::time::Duration;
+    ///
+    /// let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+    /// assert_eq!(retry_after("120", now), Some(Duration::from_secs(120)));
+    /// assert_eq!(
+    ///     retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+    ///     Some(Duration::from_secs(30))
+    /// );
+    /// assert_eq!(retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now), Some(Duration::ZERO));
+    /// assert_eq!(retry_after("soon", now), None);
+    /// ```
+    pub fn retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+        let value = value.trim();
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some(
+            (date.with_timezone(&Utc) - now)
+                .to_std()
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+}
+
+pub mod markdown {
+    use std::io::{self, IsTerminal};
+
+    use termimad::{terminal_size, MadSkin};
+
+    /// Renders markdown, such as version descriptions, with termimad
+    /// Styles are only written to a terminal, piped or redirected output is laid out without them.
+    pub fn render(markdown: &str) -> String {
+        match io::stdout().is_terminal() {
+            true => styled(markdown, Some(terminal_size().0 as usize)),
+            false => plain(markdown),
+        }
+    }
+
+    /// Renders the markdown with termimad's default skin, wrapped to the width
+    pub fn styled(markdown: &str, width: Option<usize>) -> String {
+        MadSkin::default().text(markdown, width).to_string()
+    }
+
+    /// Renders the markdown without any styles, the markers are still replaced
+    /// ```
+    /// use ore_monitor::markdown::plain;
+    ///
+    /// let rendered = plain("## Fixes\n- **new** `api`");
+    /// assert!(!rendered.contains('\x1b'));
+    /// assert!(!rendered.contains("**"));
+    /// assert!(rendered.contains("new api"));
+    /// ```
+    pub fn plain(markdown: &str) -> String {
+        MadSkin::no_style().text(markdown, None).to_string()
+    }
+}
+
+pub mod table {
+    use std::fmt::Display;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Align {
+        Left,
+        Right,
+    }
+
+    /// Rows of text printed in aligned columns, each as wide as its widest cell
+    /// ```
+    /// use ore_monitor::table::{Align, Table};
+    ///
+    /// let mut table = Table::new(vec![("Plugin ID", Align::Left), ("Stars", Align::Right)]);
+    /// table.row(vec!["nucleus".to_string(), "73".to_string()]);
+    /// table.row(vec!["luckperms".to_string(), "1024".to_string()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "Plugin ID  Stars\nnucleus       73\nluckperms   1024"
+    /// );
+    /// ```
+    #[derive(Debug, Default)]
+    pub struct Table {
+        headers: Vec<(String, Align)>,
+        rows: Vec<Vec<String>>,
+    }
+
+    impl Table {
+        pub fn new(headers: Vec<(&str, Align)>) -> Self {
+            Table {
+                headers: headers
+                    .into_iter()
+                    .map(|(header, align)| (header.to_string(), align))
+                    .collect(),
+                rows: vec![],
+            }
+        }
+
+        /// Adds a row, missing cells are left blank
+        pub fn row(&mut self, cells: Vec<String>) {
+            self.rows.push(cells);
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.rows.is_empty()
+        }
+
+        fn widths(&self) -> Vec<usize> {
+            self.headers
+                .iter()
+                .enumerate()
+                .map(|(i, (header, _))| {
+                    self.rows
+                        .iter()
+                        .filter_map(|row| row.get(i))
+                        .map(|cell| cell.chars().count())
+                        .chain([header.chars().count()])
+                        .max()
+                        .unwrap_or_default()
+                })
+                .collect()
+        }
+
+        /// The last column isn't padded on the left, so lines don't end in spaces
+        fn write_row(
+            &self,
+            f: &mut std::fmt::Formatter<'_>,
+            cells: &[&str],
+            widths: &[usize],
+        ) -> std::fmt::Result {
+            let last = self.headers.len().saturating_sub(1);
+            let line = self
+                .headers
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, ((_, align), width))| {
+                    let cell = cells.get(i).copied().unwrap_or_default();
+                    match align {
+                        Align::Right => format!("{:>width$}", cell, width = width),
+                        Align::Left if i == last => cell.to_string(),
+                        Align::Left => format!("{:<width$}", cell, width = width),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("  ");
+            write!(f, "{}", line.trim_end())
+        }
+    }
+
+    impl Display for Table {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let widths = self.widths();
+            let headers = self
+                .headers
+                .iter()
+                .map(|(header, _)| header.as_str())
+                .collect::<Vec<&str>>();
+            self.write_row(f, &headers, &widths)?;
+            for row in &self.rows {
+                writeln!(f)?;
+                let cells = row.iter().map(String::as_str).collect::<Vec<&str>>();
+                self.write_row(f, &cells, &widths)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+pub mod report {
+    use chrono::{DateTime, Utc};
+    use clap::ValueEnum;
+
+    /// Formats a report can be rendered in
+    #[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+    pub enum ReportFormat {
+        /// A markdown table, ex: for tickets and wikis
+        Md,
+        Csv,
+        /// A standalone page with a table for each group and clickable links, ex: a nightly status page
+        Html,
+    }
+
+    /// The text of a cell, and where it links to in formats that have links
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct Cell {
+        pub text: String,
+        pub link: Option<String>,
+    }
+
+    impl Cell {
+        /// A cell linking to the url, text only when there's no url
+        pub fn linked(text: impl Into<String>, link: Option<String>) -> Cell {
+            Cell {
+                text: text.into(),
+                link,
+            }
+        }
+    }
+
+    impl From<String> for Cell {
+        fn from(text: String) -> Self {
+            Cell { text, link: None }
+        }
+    }
+
+    impl From<&str> for Cell {
+        fn from(text: &str) -> Self {
+            Cell::from(text.to_string())
+        }
+    }
+
+    /// A table of results written to be read elsewhere, rendered in any [ReportFormat]
+    /// ```
+    /// use ore_monitor::report::{Report, ReportFormat};
+    ///
+    /// let mut report = Report::new(vec!["Plugin ID", "Status"]);
+    /// report.row(vec!["nucleus".to_string(), "Out of date".to_string()]);
+    /// report.row(vec!["luckperms".to_string(), "Up to date, \"5.4\"".to_string()]);
+    ///
+    /// assert_eq!(
+    ///     report.render(ReportFormat::Md),
+    ///     "| Plugin ID | Status            |\n\
+    ///      | --------- | ----------------- |\n\
+    ///      | nucleus   | Out of date       |\n\
+    ///      | luckperms | Up to date, \"5.4\" |\n"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     report.render(ReportFormat::Csv),
+    ///     "Plugin ID,Status\nnucleus,Out of date\nluckperms,\"Up to date, \"\"5.4\"\"\"\n"
+    /// );
+    /// ```
+    ///
+    /// Only HTML shows groups and links, the other formats list every row in the order they were added
+    /// ```
+    /// use ore_monitor::report::{Cell, Report, ReportFormat};
+    ///
+    /// let mut report = Report::new(vec!["Plugin ID"]).with_title("Plugins");
+    /// report.group("Out of date");
+    /// report.group("Up to date");
+    /// report.row_in("Up to date", vec![Cell::from("luckperms")]);
+    /// let page = Some("https://ore.spongepowered.org/NucleusPowered/Nucleus".to_string());
+    /// report.row_in("Out of date", vec![Cell::linked("nucleus", page)]);
+    ///
+    /// let html = report.render(ReportFormat::Html);
+    /// assert!(html.contains("<title>Plugins</title>"));
+    /// assert!(html.contains(
+    ///     "<td><a href=\"https://ore.spongepowered.org/NucleusPowered/Nucleus\">nucleus</a></td>"
+    /// ));
+    /// assert!(html.find("Out of date (1)") < html.find("Up to date (1)"));
+    ///
+    /// assert_eq!(report.render(ReportFormat::Csv), "Plugin ID\nluckperms\nnucleus\n");
+    /// ```
+    #[derive(Debug, Default)]
+    pub struct Report {
+        title: Option<String>,
+        generated: Option<DateTime<Utc>>,
+        headers: Vec<String>,
+        /// Groups in the order they're shown
+        groups: Vec<String>,
+        rows: Vec<(Option<String>, Vec<Cell>)>,
+    }
+
+    impl Report {
+        pub fn new(headers: Vec<&str>) -> Self {
+            Report {
+                headers: headers.into_iter().map(str::to_string).collect(),
+                ..Default::default()
+            }
+        }
+
+        /// The heading of the page, for formats that have one
+        pub fn with_title(mut self, title: &str) -> Self {
+            self.title = Some(title.to_string());
+            self
+        }
+
+        /// When the report was made, for formats that have somewhere to put it
+        pub fn with_timestamp(mut self, generated: DateTime<Utc>) -> Self {
+            self.generated = Some(generated);
+            self
+        }
+
+        /// Adds a row, missing cells are left blank
+        pub fn row<C: Into<Cell>>(&mut self, cells: Vec<C>) {
+            self.rows
+                .push((None, cells.into_iter().map(Into::into).collect()));
+        }
+
+        /// Declares a group, so groups can be shown in another order than their rows were added
+        /// Groups left without rows aren't shown.
+        pub fn group(&mut self, group: &str) {
+            if !self.groups.iter().any(|g| g == group) {
+                self.groups.push(group.to_string());
+            }
+        }
+
+        /// Adds a row to the group, declaring it if it wasn't yet
+        pub fn row_in<C: Into<Cell>>(&mut self, group: &str, cells: Vec<C>) {
+            self.group(group);
+            self.rows.push((
+                Some(group.to_string()),
+                cells.into_iter().map(Into::into).collect(),
+            ));
+        }
+
+        pub fn render(&self, format: ReportFormat) -> String {
+            match format {
+                ReportFormat::Md => self.markdown(),
+                ReportFormat::Csv => self.csv(),
+                ReportFormat::Html => self.html(),
+            }
+        }
+
+        /// Each row with exactly as many cells as there are headers
+        fn cells<'a>(
+            &'a self,
+            rows: impl Iterator<Item = &'a Vec<Cell>> + 'a,
+        ) -> impl Iterator<Item = Vec<&'a Cell>> + 'a {
+            static BLANK: Cell = Cell {
+                text: String::new(),
+                link: None,
+            };
+            rows.map(|row| {
+                (0..self.headers.len())
+                    .map(|i| row.get(i).unwrap_or(&BLANK))
+                    .collect()
+            })
+        }
+
+        fn texts(&self) -> impl Iterator<Item = Vec<&str>> {
+            self.cells(self.rows.iter().map(|(_, row)| row))
+                .map(|row| row.into_iter().map(|cell| cell.text.as_str()).collect())
+        }
+
+        /// Columns are padded to line up when read as text too
+        fn markdown(&self) -> String {
+            let escape = |cell: &str| cell.replace('|', "\\|").replace(['\r', '\n'], " ");
+            let headers: Vec<String> = self.headers.iter().map(|h| escape(h)).collect();
+            let rows: Vec<Vec<String>> = self
+                .texts()
+                .map(|row| row.into_iter().map(escape).collect())
+                .collect();
+            let widths: Vec<usize> = headers
+                .iter()
+                .enumerate()
+                .map(|(i, header)| {
+                    rows.iter()
+                        .map(|row| row[i].chars().count())
+                        .chain([header.chars().count(), 3])
+                        .max()
+                        .unwrap_or_default()
+                })
+                .collect();
+
+            let line = |cells: &[String]| {
+                let cells = cells
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                    .collect::<Vec<String>>();
+                format!("| {} |\n", cells.join(" | "))
+            };
+            let divider = widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<String>>();
+
+            let mut markdown = line(&headers);
+            markdown.push_str(&line(&divider));
+            rows.iter().for_each(|row| markdown.push_str(&line(row)));
+            markdown
+        }
+
+        /// Cells holding a separator, quote or line break are quoted, as RFC 4180 describes
+        fn csv(&self) -> String {
+            let escape = |cell: &str| match cell.contains([',', '"', '\r', '\n']) {
+                true => format!("\"{}\"", cell.replace('"', "\"\"")),
+                false => cell.to_string(),
+            };
+            let line = |cells: Vec<&str>| {
+                let cells = cells.into_iter().map(escape).collect::<Vec<String>>();
+                format!("{}\n", cells.join(","))
+            };
+
+            let mut csv = line(self.headers.iter().map(String::as_str).collect());
+            self.texts().for_each(|row| csv.push_str(&line(row)));
+            csv
+        }
+
+        /// A table for the rows outside of any group, then one under a heading for each group
+        fn html(&self) -> String {
+            let title = escape_html(self.title.as_deref().unwrap_or("Report"));
+            let generated = self
+                .generated
+                .map(|generated| {
+                    format!(
+                        "<p>Generated <time datetime=\"{}\">{}</time></p>\n",
+                        generated.to_rfc3339(),
+                        generated.format("%Y-%m-%d %H:%M UTC")
+                    )
+                })
+                .unwrap_or_default();
+
+            let mut body = self.html_table(self.rows_in(None));
+            for group in &self.groups {
+                let count = self.rows_in(Some(group)).count();
+                if count > 0 {
+                    body.push_str(&format!("<h2>{} ({})</h2>\n", escape_html(group), count));
+                    body.push_str(&self.html_table(self.rows_in(Some(group))));
+                }
+            }
+
+            format!(
+                r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; margin-bottom: 2em; }}
+th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; }}
+th {{ background: #f4f4f4; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+{generated}{body}</body>
+</html>
+"#
+            )
+        }
+
+        fn rows_in<'a>(
+            &'a self,
+            group: Option<&'a str>,
+        ) -> impl Iterator<Item = &'a Vec<Cell>> + 'a {
+            self.rows
+                .iter()
+                .filter(move |(g, _)| g.as_deref() == group)
+                .map(|(_, row)| row)
+        }
+
+        /// Empty when there are no rows
+        fn html_table<'a>(&'a self, rows: impl Iterator<Item = &'a Vec<Cell>> + 'a) -> String {
+            let rows = self
+                .cells(rows)
+                .map(|row| {
+                    let cells = row
+                        .into_iter()
+                        .map(|cell| match &cell.link {
+                            Some(link) => format!(
+                                "<td><a href=\"{}\">{}</a></td>",
+                                escape_html(link),
+                                escape_html(&cell.text)
+                            ),
+                            None => format!("<td>{}</td>", escape_html(&cell.text)),
+                        })
+                        .collect::<String>();
+                    format!("<tr>{}</tr>\n", cells)
+                })
+                .collect::<String>();
+            if rows.is_empty() {
+                return rows;
+            }
+
+            let headers = self
+                .headers
+                .iter()
+                .map(|header| format!("<th>{}</th>", escape_html(header)))
+                .collect::<String>();
+            format!(
+                "<table>\n<thead><tr>{}</tr></thead>\n<tbody>\n{}</tbody>\n</table>\n",
+                headers, rows
+            )
+        }
+    }
+
+    fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
+    }
+}
+
+pub mod one_or_many {
+    use serde::{Deserialize, Serialize};
+
+    /// A value the API types as either a single value or an array of them
+    /// ```
+    /// use ore_monitor::one_or_many::OneOrMany;
+    /// use std::collections::HashMap;
+    ///
+    /// let json = r#"{"Sponge":"8.0.0","Minecraft":["1.16.5","1.17"]}"#;
+    /// let tags: HashMap<String, OneOrMany<String>> = serde_json::from_str(json).unwrap();
+    ///
+    /// assert_eq!(tags["Sponge"], OneOrMany::One("8.0.0".to_string()));
+    /// assert_eq!(tags["Minecraft"].to_vec(), vec!["1.16.5", "1.17"]);
+    ///
+    /// let round_trip: HashMap<String, OneOrMany<String>> =
+    ///     serde_json::from_str(&serde_json::to_string(&tags).unwrap()).unwrap();
+    /// assert_eq!(round_trip, tags);
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+    #[serde(untagged)]
+    pub enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    impl<T: Clone> OneOrMany<T> {
+        pub fn to_vec(&self) -> Vec<T> {
+            match self {
+                OneOrMany::One(value) => vec![value.clone()],
+                OneOrMany::Many(values) => values.clone(),
+            }
+        }
+
+        /// Adds a value, turning a single value into many
+        /// ```
+        /// use ore_monitor::one_or_many::OneOrMany;
+        ///
+        /// let mut tag = OneOrMany::One("1.16.5");
+        /// tag.push("1.17");
+        /// assert_eq!(tag, OneOrMany::Many(vec!["1.16.5", "1.17"]));
+        /// ```
+        pub fn push(&mut self, value: T) {
+            *self = OneOrMany::Many([self.to_vec(), vec![value]].concat());
+        }
+    }
+}
+
+pub mod alias {
+    use std::collections::HashMap;
+
+    /// Short forms of commands that are always available
+    pub const BUILTIN: [(&str, &str); 4] = [
+        ("s", "search"),
+        ("p", "plugin"),
+        ("i", "install"),
+        ("c", "check"),
+    ];
+
+    /// Expands the command, the first argument after the program name and global flags, if it's an alias
+    /// User defined aliases take priority over the built in short forms.
+    /// ```
+    /// use ore_monitor::alias::expand;
+    /// use std::collections::HashMap;
+    ///
+    /// let args = |s: &str| s.split(' ').map(String::from).collect::<Vec<String>>();
+    /// let aliases = HashMap::from([("up".to_string(), "check --update --yes".to_string())]);
+    ///
+    /// assert_eq!(expand(args("ore up ./plugins"), &aliases), args("ore check --update --yes ./plugins"));
+    /// assert_eq!(expand(args("ore i nucleus 2.1.4"), &aliases), args("ore install nucleus 2.1.4"));
+    /// assert_eq!(expand(args("ore search up"), &aliases), args("ore search up"));
+    /// // Global flags can come before the alias
+    /// assert_eq!(
+    ///     expand(args("ore -v --no-cache up ./plugins"), &aliases),
+    ///     args("ore -v --no-cache check --update --yes ./plugins")
+    /// );
+    /// ```
+    pub fn expand(args: Vec<String>, aliases: &HashMap<String, String>) -> Vec<String> {
+        // None of the global flags take a value, so the command is the first argument that isn't a flag
+        let Some(position) = args
+            .iter()
+            .skip(1)
+            .position(|arg| !arg.starts_with('-'))
+            .map(|position| position + 1)
+        else {
+            return args;
+        };
+        let command = &args[position];
+
+        let expanded: Vec<String> = match aliases.get(command) {
+            Some(alias) => alias.split_whitespace().map(String::from).collect(),
+            None => match BUILTIN.iter().find(|(short, _)| short == command) {
+                Some((_, full)) => vec![full.to_string()],
+                None => return args,
+            },
+        };
+
+        let mut args = args.into_iter();
+        let flags = args.by_ref().take(position).collect::<Vec<String>>();
+        flags
+            .into_iter()
+            .chain(expanded)
+            .chain(args.skip(1))
+            .collect()
+    }
+}
+
+pub mod project_detect {
+    use std::{fs, path::Path};
+
+    use crate::file_reader::FileReader;
+
+    const BUILD_FILES: [&str; 2] = ["build.gradle", "build.gradle.kts"];
+    const LIBS_DIR: &str = "build/libs";
+
+    /// Infers the plugin id of the plugin project inside of the directory
+    /// The build script is preferred, falling back to a jar built into `build/libs`.
+    pub fn detect_plugin_id(dir: &Path) -> Option<String> {
+        BUILD_FILES
+            .iter()
+            .filter_map(|file| fs::read_to_string(dir.join(file)).ok())
+            .find_map(|script| gradle_plugin_id(&script))
+            .or_else(|| {
+                FileReader::from(&dir.join(LIBS_DIR))
+                    .handle_dir()
+                    .ok()?
+                    .into_iter()
+                    .next()
+                    .map(|info| info.modid)
+            })
+    }
+
+    /// Reads the plugin id from a gradle build script
+    /// Supports an ore `pluginId` property as well as SpongeGradle's `plugin("id")`
+    /// ```
+    /// use ore_monitor::project_detect::gradle_plugin_id;
+    ///
+    /// let groovy = "oreDeploy {\n    pluginId = 'myplugin'\n}";
+    /// assert_eq!(gradle_plugin_id(groovy), Some("myplugin".to_string()));
+    ///
+    /// let kotlin = "sponge {\n    plugin(\"myplugin\") {\n    }\n}";
+    /// assert_eq!(gradle_plugin_id(kotlin), Some("myplugin".to_string()));
+    ///
+    /// assert_eq!(gradle_plugin_id("plugins {\n    id(\"java\")\n}"), None);
+    /// ```
+    pub fn gradle_plugin_id(script: &str) -> Option<String> {
+        script
+            .lines()
+            .map(str::trim)
+            .filter(|line| line.starts_with("pluginId") || line.starts_with("plugin("))
+            .find_map(first_quoted)
+    }
+
+    fn first_quoted(line: &str) -> Option<String> {
+        let start = line.find(['"', '\''])?;
+        let quote = line[start..].chars().next()?;
+        let rest = &line[start + 1..];
+        let end = rest.find(quote)?;
+        Some(rest[..end].to_string()).filter(|id| !id.is_empty())
+    }
+}
+
+pub mod source {
+    use serde::Deserialize;
+    use std::fmt::Display;
+
+    /// A backend plugins can be retrieved from
+    #[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Source {
+        #[default]
+        Ore,
+        Modrinth,
+    }
+
+    impl Display for Source {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Source::Ore => write!(f, "Ore"),
+                Source::Modrinth => write!(f, "Modrinth"),
+            }
+        }
+    }
+
+    /// Where a single plugin comes from, ex: `luckperms = { source = "modrinth", id = "Vebnzrzj" }`
+    #[derive(Deserialize, Debug, Default, Clone)]
+    pub struct PluginSource {
+        #[serde(default)]
+        pub source: Source,
+        /// The id on the source, when it differs from the plugin id
+        pub id: Option<String>,
+    }
+
+    impl PluginSource {
+        /// The id to query the source with
+        /// ```
+        /// use ore_monitor::source::{PluginSource, Source};
+        ///
+        /// let source: PluginSource = toml::from_str(r#"source = "modrinth"
+        /// id = "Vebnzrzj""#).unwrap();
+        ///
+        /// assert_eq!(source.source, Source::Modrinth);
+        /// assert_eq!(source.id_or("luckperms"), "Vebnzrzj");
+        /// assert_eq!(PluginSource::default().id_or("luckperms"), "luckperms");
+        /// ```
+        pub fn id_or<'a>(&'a self, plugin_id: &'a str) -> &'a str {
+            self.id.as_deref().unwrap_or(plugin_id)
+        }
+    }
+}
+
+pub mod update_order {
+    use ore_monitor_common::version_status::Lenient;
+    use std::cmp::Ordering;
+
+    /// Orders plugins so each comes after the plugins it depends on, otherwise keeping the given order
+    /// Dependencies outside of the list are ignored, plugins in a cycle keep their given order
+    /// ```
+    /// use ore_monitor::update_order::order;
+    ///
+    /// let plugins = vec![
+    ///     ("nucleus".to_string(), vec!["luckperms".to_string()]),
+    ///     ("huskycrates".to_string(), vec![]),
+    ///     ("luckperms".to_string(), vec!["spongeapi".to_string()]),
+    /// ];
+    ///
+    /// assert_eq!(order(&plugins), vec!["huskycrates", "luckperms", "nucleus"]);
+    /// ```
+    pub fn order(plugins: &[(String, Vec<String>)]) -> Vec<String> {
+        let mut remaining: Vec<&(String, Vec<String>)> = plugins.iter().collect();
+        let mut ordered: Vec<String> = vec![];
+
+        while !remaining.is_empty() {
+            let ready = remaining.iter().position(|(_, deps)| {
+                deps.iter()
+                    .all(|dep| !remaining.iter().any(|(id, _)| id == dep))
+            });
+            // Only a cycle leaves nothing ready, break it by taking the first plugin
+            let (id, _) = remaining.remove(ready.unwrap_or_default());
+            ordered.push(id.to_string());
+        }
+
+        ordered
+    }
+
+    /// The newest of the versions, by the same [Lenient] ordering `check` compares versions with
+    /// Versions that aren't version numbers are older than any that are.
+    /// ```
+    /// use ore_monitor::update_order::newest;
+    ///
+    /// assert_eq!(newest(["2.1.4", "2.10.0", "2.9.1"]), Some("2.10.0"));
+    /// assert_eq!(newest(["3.0.0-BETA1", "2.1.4"]), Some("3.0.0-BETA1"));
+    /// assert_eq!(newest(["1.2.0-beta", "v1.2.0"]), Some("v1.2.0"));
+    /// assert_eq!(newest(["v1.2.0", "${version}"]), Some("v1.2.0"));
+    /// assert_eq!(newest([]), None);
+    /// ```
+    pub fn newest<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+        versions
+            .into_iter()
+            .max_by_key(|version| Lenient::parse(version))
+    }
+
+    /// Whether the constraint is a range rather than a single version
+    /// ```
+    /// use ore_monitor::update_order::is_range;
+    ///
+    /// assert!(is_range("[2.0,3.0)"));
+    /// assert!(is_range("(,3.0]"));
+    /// assert!(!is_range("2.1.4"));
+    /// ```
+    pub fn is_range(constraint: &str) -> bool {
+        constraint.trim_start().starts_with(['[', '('])
+    }
+
+    /// Whether a version falls within a declared range, by the [Lenient] ordering
+    /// Supports maven style ranges, a bare version is the minimum and an empty range allows anything
+    /// A version that isn't a version number is below every bound.
+    /// ```
+    /// use ore_monitor::update_order::satisfies;
+    ///
+    /// assert!(satisfies("5.1", "[5.0,6.0)"));
+    /// assert!(!satisfies("6.0", "[5.0,6.0)"));
+    /// assert!(satisfies("6.0", "[5.0,6.0]"));
+    /// assert!(satisfies("7.2", "[5.0,)"));
+    /// assert!(!satisfies("4.9", "5.0"));
+    /// assert!(satisfies("4.9", ""));
+    /// // Build metadata and a `v` prefix don't change the version
+    /// assert!(satisfies("v1.2.0", "[1.2.0+build,1.2.0+build]"));
+    /// assert!(!satisfies("1.2.0-beta", "[v1.2.0,)"));
+    /// ```
+    pub fn satisfies(version: &str, range: &str) -> bool {
+        let range = range.trim();
+        let version = Lenient::parse(version);
+        let cmp = |bound: &str| {
+            Lenient::parse(bound)
+                .map(|bound| version.as_ref().map_or(Ordering::Less, |v| v.cmp(&bound)))
+        };
+
+        let (Some(lower), Some(upper)) = (range.chars().next(), range.chars().last()) else {
+            return true;
+        };
+
+        if !matches!(lower, '[' | '(') || !matches!(upper, ']' | ')') {
+            return cmp(range).is_none_or(|c| c != Ordering::Less);
+        }
+
+        let inner = &range[1..range.len() - 1];
+        // Splitting at the last comma keeps a lower bound that has a comma in it whole
+        let (min, max) = inner.rsplit_once(',').unwrap_or((inner, inner));
+
+        let above_min = match cmp(min) {
+            None => true,
+            Some(c) => c == Ordering::Greater || (c == Ordering::Equal && lower == '['),
+        };
+        let below_max = match cmp(max) {
+            None => true,
+            Some(c) => c == Ordering::Less || (c == Ordering::Equal && upper == ']'),
+        };
+
+        above_min && below_max
+    }
+}
+
+pub mod transaction {
+    use std::{
+        collections::HashSet,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use chrono::Utc;
+    use tracing::warn;
+
+    use crate::scan_filter::{RecursiveScan, ScanFilter};
+
+    /// Changes to the files of a directory that can be undone as a whole
+    /// Replaced files are backed up first, files that didn't exist when it began are removed on rollback.
+    /// The directory is walked as deep as the scan that found the files, so jars in its subdirectories are covered too.
+    /// ```
+    /// # use ore_monitor::{scan_filter::{RecursiveScan, ScanFilter}, transaction::Transaction};
+    /// # use std::fs;
+    /// let dir = std::env::temp_dir().join("ore-monitor-transaction-doctest");
+    /// # let _ = fs::remove_dir_all(&dir);
+    /// fs::create_dir_all(dir.join("world")).unwrap();
+    /// fs::write(dir.join("plugin-1.0.jar"), "old").unwrap();
+    /// fs::write(dir.join("world/plugin-1.0.jar"), "old world").unwrap();
+    ///
+    /// let filter = ScanFilter::default().with_recursion(Some(RecursiveScan::default()));
+    /// let mut transaction = Transaction::begin(&dir, &filter).unwrap();
+    /// transaction.backup(&dir.join("plugin-1.0.jar")).unwrap();
+    /// transaction.backup(&dir.join("world/plugin-1.0.jar")).unwrap();
+    /// fs::remove_file(dir.join("plugin-1.0.jar")).unwrap();
+    /// fs::remove_file(dir.join("world/plugin-1.0.jar")).unwrap();
+    /// fs::write(dir.join("plugin-2.0.jar"), "new").unwrap();
+    /// fs::write(dir.join("world/plugin-2.0.jar"), "new").unwrap();
+    /// transaction.rollback().unwrap();
+    ///
+    /// assert_eq!(fs::read_to_string(dir.join("plugin-1.0.jar")).unwrap(), "old");
+    /// assert_eq!(fs::read_to_string(dir.join("world/plugin-1.0.jar")).unwrap(), "old world");
+    /// assert!(!dir.join("plugin-2.0.jar").exists());
+    /// assert!(!dir.join("world/plugin-2.0.jar").exists());
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    #[derive(Debug)]
+    pub struct Transaction {
+        dir: PathBuf,
+        backup_dir: PathBuf,
+        /// Walks the directories the changed files are in
+        walked: ScanFilter,
+        existing: HashSet<PathBuf>,
+        /// Original location and backup of each file
+        backups: Vec<(PathBuf, PathBuf)>,
+    }
+
+    impl Transaction {
+        const DIR_NAME: &'static str = ".backup";
+
+        /// Begins changing the files the filter scans in the directory
+        pub fn begin(dir: &Path, filter: &ScanFilter) -> Result<Transaction> {
+            let backup_dir = dir
+                .join(Self::DIR_NAME)
+                .join(Utc::now().format("%Y%m%d%H%M%S").to_string());
+
+            // Every file of the scanned directories counts, not only those the patterns match,
+            // and hidden directories such as the backups are never walked into
+            let recursion = filter
+                .recursion
+                .or(filter.recursive().then(RecursiveScan::default));
+            let walked = ScanFilter::default().with_recursion(recursion);
+            let existing = walked.files(dir)?.into_iter().collect();
+
+            let mut transaction = Transaction {
+                dir: dir.to_path_buf(),
+                backup_dir,
+                walked,
+                existing,
+                backups: vec![],
+            };
+
+            // The lockfile changes with each file, so it's always kept
+            let lockfile = crate::lockfile::Lockfile::path_in(dir);
+            if lockfile.is_file() {
+                transaction.backup(&lockfile)?;
+            }
+
+            Ok(transaction)
+        }
+
+        /// Copies the file aside so it can be restored, a file is only backed up once
+        /// Backups keep the file's path relative to the directory, so files of the same name in subdirectories don't collide.
+        pub fn backup(&mut self, file: &Path) -> Result<()> {
+            if !file.is_file() || self.backups.iter().any(|(original, _)| original == file) {
+                return Ok(());
+            }
+
+            let relative = file
+                .strip_prefix(&self.dir)
+                .unwrap_or(Path::new(file.file_name().unwrap_or_default()));
+            let backup = self.backup_dir.join(relative);
+            if let Some(parent) = backup.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(file, &backup)?;
+            self.backups.push((file.to_path_buf(), backup));
+            Ok(())
+        }
+
+        /// Removes the files created since it began, then restores every backup
+        /// Each step is attempted even when an earlier one failed, the failures are returned together.
+        /// Backups are kept when anything failed, so what couldn't be restored can be by hand.
+        pub fn rollback(self) -> Result<()> {
+            let mut failures = vec![];
+
+            match self.walked.files(&self.dir) {
+                Ok(files) => {
+                    for created in files.iter().filter(|file| !self.existing.contains(*file)) {
+                        if let Err(e) = fs::remove_file(created) {
+                            warn!("Unable to remove '{}' : {}", created.display(), e);
+                            failures.push(format!("removing '{}' : {}", created.display(), e));
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("Unable to list the files created : {}", e);
+                    failures.push(format!("listing the files created : {}", e));
+                }
+            }
+
+            for (original, backup) in &self.backups {
+                let restored = original
+                    .parent()
+                    .map_or(Ok(()), fs::create_dir_all)
+                    .and_then(|_| fs::copy(backup, original));
+                if let Err(e) = restored {
+                    warn!("Unable to restore '{}' : {}", original.display(), e);
+                    failures.push(format!("restoring '{}' : {}", original.display(), e));
+                }
+            }
+
+            if failures.is_empty() {
+                return self.discard_backups();
+            }
+            Err(anyhow::Error::msg(format!(
+                "Rolling back failed, the backups are kept in '{}' :\n{}",
+                self.backup_dir.display(),
+                failures.join("\n")
+            )))
+        }
+
+        /// Keeps the changes, the backups are no longer needed
+        pub fn commit(self) -> Result<()> {
+            self.discard_backups()
+        }
+
+        fn discard_backups(&self) -> Result<()> {
+            if self.backup_dir.exists() {
+                fs::remove_dir_all(&self.backup_dir)?;
+            }
+            // Only removed when no other backups are kept
+            let _ = fs::remove_dir(self.dir.join(Self::DIR_NAME));
+            Ok(())
+        }
+    }
+}
+
+pub mod instance_lock {
+    use std::{
+        fmt::Display,
+        fs::{self, File, OpenOptions, TryLockError},
+        io::{Read, Write},
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use anyhow::Result;
+
+    /// An advisory lock on a directory, held while an instance changes its jars or lockfile
+    /// Another instance fails right away instead of waiting, see [AnotherInstance].
+    /// The lock is released when dropped, or however the process exits, so a crash never leaves it behind.
+    /// ```
+    /// use ore_monitor::instance_lock::DirLock;
+    ///
+    /// let dir = std::env::temp_dir().join("ore_monitor_dir_lock_doctest");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    ///
+    /// let lock = DirLock::acquire(&dir).unwrap();
+    /// let error = DirLock::acquire(&dir).unwrap_err();
+    /// assert!(error.to_string().starts_with("Another ore-monitor instance"));
+    ///
+    /// drop(lock);
+    /// assert!(DirLock::acquire(&dir).is_ok());
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    #[derive(Debug)]
+    pub struct DirLock {
+        _file: File,
+    }
+
+    impl DirLock {
+        pub const FILE_NAME: &'static str = ".ore-monitor.lock";
+
+        pub fn acquire(dir: &Path) -> Result<DirLock> {
+            let mut file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(dir.join(Self::FILE_NAME))?;
+
+            match file.try_lock() {
+                Ok(()) => (),
+                Err(TryLockError::WouldBlock) => {
+                    // Some platforms refuse reading a locked file, the holder is then left unnamed
+                    let mut pid = String::new();
+                    let _ = file.read_to_string(&mut pid);
+                    return Err(AnotherInstance {
+                        dir: dir.to_path_buf(),
+                        pid: pid.trim().parse().ok(),
+                    }
+                    .into());
+                }
+                Err(TryLockError::Error(e)) => return Err(e.into()),
+            }
+
+            // Names the holder to the instances finding it locked
+            file.set_len(0)?;
+            file.write_all(process::id().to_string().as_bytes())?;
+            Ok(DirLock { _file: file })
+        }
+    }
+
+    /// Returned when another instance holds the lock of the directory
+    #[derive(Debug)]
+    pub struct AnotherInstance {
+        pub dir: PathBuf,
+        pub pid: Option<u32>,
+    }
+
+    impl Display for AnotherInstance {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Another ore-monitor instance ")?;
+            if let Some(pid) = self.pid {
+                write!(f, "(pid {}) ", pid)?;
+            }
+            write!(
+                f,
+                "is changing {}, try again once it's done",
+                self.dir.display()
+            )
+        }
+    }
+
+    impl std::error::Error for AnotherInstance {}
+
+    /// Replaces the contents of the file as a whole, writing them beside it first
+    /// Another instance reading it meanwhile sees the old or the new contents, never a partial write.
+    /// ```
+    /// use ore_monitor::instance_lock::write_atomic;
+    ///
+    /// let dir = std::env::temp_dir().join("ore_monitor_write_atomic_doctest");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// write_atomic(&dir.join("ore-monitor.lock"), "old").unwrap();
+    /// write_atomic(&dir.join("ore-monitor.lock"), "new").unwrap();
+    ///
+    /// assert_eq!(std::fs::read_to_string(dir.join("ore-monitor.lock")).unwrap(), "new");
+    /// assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+        replace(path, contents.as_ref(), OpenOptions::new())
+    }
+
+    /// Replaces the contents of the file as [write_atomic] does, only its owner may read it on unix
+    /// For secrets, which are never readable by others, even while they're being written.
+    /// ```
+    /// use ore_monitor::instance_lock::write_private;
+    ///
+    /// let dir = std::env::temp_dir().join("ore_monitor_write_private_doctest");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// write_private(&dir.join("session.json"), "secret").unwrap();
+    ///
+    /// assert_eq!(std::fs::read_to_string(dir.join("session.json")).unwrap(), "secret");
+    /// # #[cfg(unix)]
+    /// # {
+    /// use std::os::unix::fs::PermissionsExt;
+    /// let mode = std::fs::metadata(dir.join("session.json")).unwrap().permissions().mode();
+    /// assert_eq!(mode & 0o777, 0o600);
+    /// # }
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn write_private(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+        let mut options = OpenOptions::new();
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        replace(path, contents.as_ref(), options)
+    }
+
+    /// Writes the contents beside the file, opened with the options, then moves them over it
+    fn replace(path: &Path, contents: &[u8], mut options: OpenOptions) -> Result<()> {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let partial = path.with_file_name(format!(".{}.{}.tmp", name, process::id()));
+        // One left behind by a crash would keep its permissions
+        let _ = fs::remove_file(&partial);
+        let written = options
+            .write(true)
+            .create_new(true)
+            .open(&partial)
+            .and_then(|mut file| file.write_all(contents))
+            .and_then(|_| fs::rename(&partial, path));
+        if let Err(e) = written {
+            let _ = fs::remove_file(&partial);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+}
+
+pub mod scan_filter {
+    use std::{
+        fs::{self, DirEntry},
+        path::{Path, PathBuf},
+        thread,
+    };
+
+    use anyhow::Result;
+
+    /// Globs choosing which files of a directory are scanned, matched against their path relative to it
+    /// Without an include pattern every file directly inside of the directory is scanned,
+    /// subdirectories are only walked when an include pattern reaches into them.
+    /// ```
+    /// use ore_monitor::scan_filter::ScanFilter;
+    /// use std::path::Path;
+    ///
+    /// let filter = ScanFilter::new(vec!["**/*.jar".to_string()], vec!["disabled/*".to_string()]);
+    /// assert!(filter.matches(Path::new("nucleus.jar")));
+    /// assert!(filter.matches(Path::new("extra/luckperms.jar")));
+    /// assert!(!filter.matches(Path::new("disabled/huskycrates.jar")));
+    /// assert!(!filter.matches(Path::new("world.zip")));
+    /// assert!(filter.recursive());
+    ///
+    /// assert!(ScanFilter::default().matches(Path::new("world.zip")));
+    /// assert!(!ScanFilter::default().recursive());
+    /// ```
+    #[derive(Debug, Default, Clone, PartialEq)]
+    pub struct ScanFilter {
+        pub include: Vec<String>,
+        pub exclude: Vec<String>,
+        /// Also reads the jars held inside of a jar without plugin metadata of its own
+        pub nested: Option<NestedScan>,
+        /// Walks subdirectories without an include pattern reaching into them
+        pub recursion: Option<RecursiveScan>,
+        /// How many jars are read at once, one per core unless set
+        pub threads: Option<usize>,
+        /// Reads every jar again instead of using what the scan cache holds of them
+        pub rescan: bool,
+    }
+
+    /// How far subdirectories are walked, ex: plugins kept in per-world folders
+    /// Hidden directories, such as the `.quarantine` and `.backup` ore-monitor keeps, are never walked into.
+    /// ```
+    /// use ore_monitor::scan_filter::{RecursiveScan, ScanFilter};
+    /// use std::fs;
+    ///
+    /// let dir = std::env::temp_dir().join("ore_monitor_recursive_doctest");
+    /// # let _ = fs::remove_dir_all(&dir);
+    /// fs::create_dir_all(dir.join("world/extra")).unwrap();
+    /// fs::create_dir_all(dir.join(".backup")).unwrap();
+    /// for jar in ["nucleus.jar", "world/luckperms.jar", "world/extra/huskycrates.jar", ".backup/nucleus.jar"] {
+    ///     fs::write(dir.join(jar), "").unwrap();
+    /// }
+    ///
+    /// let top_level = ScanFilter::default().files(&dir).unwrap();
+    /// assert_eq!(top_level, vec![dir.join("nucleus.jar")]);
+    ///
+    /// let one_deep = RecursiveScan { max_depth: 1, ..Default::default() };
+    /// let filter = ScanFilter::default().with_recursion(Some(one_deep));
+    /// assert_eq!(
+    ///     filter.files(&dir).unwrap(),
+    ///     vec![dir.join("nucleus.jar"), dir.join("world/luckperms.jar")]
+    /// );
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct RecursiveScan {
+        /// How many directories deep to look, one only walks the subdirectories directly inside
+        pub max_depth: u32,
+        /// Also walks into directories that are symlinks, otherwise they're skipped
+        pub follow_symlinks: bool,
+    }
+
+    impl Default for RecursiveScan {
+        fn default() -> Self {
+            RecursiveScan {
+                max_depth: 8,
+                follow_symlinks: false,
+            }
+        }
+    }
+
+    /// How far jars held inside of other jars are read, ex: a plugin shaded into a bundle
+    /// Both are bounded, embedded jars are read into memory and may hold jars themselves.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct NestedScan {
+        /// How many jars deep to look, one only reads the jars directly inside
+        pub max_depth: u32,
+        /// Embedded jars larger than this, once uncompressed, are skipped
+        pub max_size: u64,
+    }
+
+    impl Default for NestedScan {
+        fn default() -> Self {
+            NestedScan {
+                max_depth: 2,
+                max_size: 32 * 1024 * 1024,
+            }
+        }
+    }
+
+    impl ScanFilter {
+        pub fn new(include: Vec<String>, exclude: Vec<String>) -> Self {
+            ScanFilter {
+                include,
+                exclude,
+                nested: None,
+                recursion: None,
+                threads: None,
+                rescan: false,
+            }
+        }
+
+        pub fn with_nested(mut self, nested: Option<NestedScan>) -> Self {
+            self.nested = nested;
+            self
+        }
+
+        pub fn with_recursion(mut self, recursion: Option<RecursiveScan>) -> Self {
+            self.recursion = recursion;
+            self
+        }
+
+        pub fn with_threads(mut self, threads: Option<usize>) -> Self {
+            self.threads = threads;
+            self
+        }
+
+        pub fn with_rescan(mut self, rescan: bool) -> Self {
+            self.rescan = rescan;
+            self
+        }
+
+        /// How many jars are read at once, never less than one
+        pub fn threads(&self) -> usize {
+            self.threads
+                .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+                .max(1)
+        }
+
+        /// Whether the file at the relative path is scanned
+        pub fn matches(&self, relative: &Path) -> bool {
+            let path = Self::normalized(relative);
+            (self.include.is_empty() || self.include.iter().any(|p| glob_match(p, &path)))
+                && !self.excluded(&path)
+        }
+
+        fn excluded(&self, path: &str) -> bool {
+            self.exclude.iter().any(|p| glob_match(p, path))
+        }
+
+        pub fn recursive(&self) -> bool {
+            self.include.iter().any(|p| p.contains('/'))
+        }
+
+        /// The files of the directory that are scanned, in path order
+        pub fn files(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+            let mut files = vec![];
+            self.walk(dir, dir, 0, &mut files)?;
+            files.sort();
+            Ok(files)
+        }
+
+        fn walk(
+            &self,
+            base: &Path,
+            dir: &Path,
+            depth: u32,
+            files: &mut Vec<PathBuf>,
+        ) -> Result<()> {
+            for entry in fs::read_dir(dir)?.filter_map(|res| res.ok()) {
+                let path = entry.path();
+                let relative = path.strip_prefix(base).unwrap_or(&path);
+
+                if path.is_dir() {
+                    if self.enters(&entry, relative, depth) {
+                        self.walk(base, &path, depth + 1, files)?;
+                    }
+                } else if self.matches(relative) {
+                    files.push(path)
+                }
+            }
+            Ok(())
+        }
+
+        /// Whether the subdirectory of a directory `depth` levels below the scanned one is walked
+        fn enters(&self, entry: &DirEntry, relative: &Path, depth: u32) -> bool {
+            // An excluded directory is skipped entirely, ex: `disabled`
+            if self.excluded(&Self::normalized(relative)) {
+                return false;
+            }
+            if self.recursive() {
+                return true;
+            }
+            let Some(recursion) = self.recursion else {
+                return false;
+            };
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            let symlink = entry.file_type().is_ok_and(|t| t.is_symlink());
+            depth < recursion.max_depth && !hidden && (recursion.follow_symlinks || !symlink)
+        }
+
+        /// Patterns use `/` on every platform
+        fn normalized(relative: &Path) -> String {
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+        }
+    }
+
+    /// Splits a path into the directory before its first wildcard, and the glob matched under it
+    /// A path without wildcards has no glob.
+    /// ```
+    /// use ore_monitor::scan_filter::split_glob;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// assert_eq!(
+    ///     split_glob(Path::new("mods/*.jar")),
+    ///     (PathBuf::from("mods"), Some("*.jar".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     split_glob(Path::new("servers/*/plugins/*.jar")),
+    ///     (PathBuf::from("servers"), Some("*/plugins/*.jar".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     split_glob(Path::new("*.jar")),
+    ///     (PathBuf::from("."), Some("*.jar".to_string()))
+    /// );
+    /// assert_eq!(split_glob(Path::new("plugins")), (PathBuf::from("plugins"), None));
+    /// ```
+    pub fn split_glob(path: &Path) -> (PathBuf, Option<String>) {
+        let components = path.components().collect::<Vec<_>>();
+        let wildcard = components.iter().position(|c| {
+            let c = c.as_os_str().to_string_lossy();
+            c.contains('*') || c.contains('?')
+        });
+        let Some(wildcard) = wildcard else {
+            return (path.to_path_buf(), None);
+        };
+
+        let base = components[..wildcard].iter().collect::<PathBuf>();
+        let base = match base.as_os_str().is_empty() {
+            true => PathBuf::from("."),
+            false => base,
+        };
+        let glob = ScanFilter::normalized(&components[wildcard..].iter().collect::<PathBuf>());
+        (base, Some(glob))
+    }
+
+    /// The files matching the path's glob, in path order, a path without wildcards is returned as is
+    pub fn expand_glob(path: &Path) -> Result<Vec<PathBuf>> {
+        match split_glob(path) {
+            (path, None) => Ok(vec![path]),
+            (base, Some(_)) if !base.is_dir() => Ok(vec![]),
+            (base, Some(glob)) => ScanFilter::new(vec![glob], vec![]).files(&base),
+        }
+    }
+
+    /// Matches a path against a glob, `*` matches within a directory, `**` across directories and `?` a single character
+    /// ```
+    /// use ore_monitor::scan_filter::glob_match;
+    ///
+    /// assert!(glob_match("*.jar", "nucleus.jar"));
+    /// assert!(!glob_match("*.jar", "mods/nucleus.jar"));
+    /// assert!(glob_match("**/*.jar", "nucleus.jar"));
+    /// assert!(glob_match("**/*.jar", "mods/extra/nucleus.jar"));
+    /// assert!(glob_match("disabled/*", "disabled/nucleus.jar"));
+    /// assert!(glob_match("nucleus-?.?.jar", "nucleus-2.1.jar"));
+    /// assert!(!glob_match("*.jar", "nucleus.jar.disabled"));
+    /// ```
+    pub fn glob_match(pattern: &str, path: &str) -> bool {
+        let pattern = pattern.chars().collect::<Vec<char>>();
+        let path = path.chars().collect::<Vec<char>>();
+        matches(&pattern, &path)
+    }
+
+    fn matches(pattern: &[char], path: &[char]) -> bool {
+        match pattern {
+            [] => path.is_empty(),
+            ['*', '*', rest @ ..] => {
+                // `**/` also matches no directory at all
+                (0..=path.len()).any(|i| matches(rest, &path[i..]))
+                    || matches!(rest, ['/', ..]) && matches(&rest[1..], path)
+            }
+            ['*', rest @ ..] => (0..=path.len())
+                .take_while(|&i| i == 0 || path[i - 1] != '/')
+                .any(|i| matches(rest, &path[i..])),
+            ['?', rest @ ..] => {
+                path.first().is_some_and(|c| *c != '/') && matches(rest, &path[1..])
+            }
+            [c, rest @ ..] => path.first() == Some(c) && matches(rest, &path[1..]),
+        }
+    }
+}
+
+pub mod platform_support {
+    use std::{
+        fmt::Display,
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use anyhow::Result;
+    use serde::Deserialize;
+
+    /// The dataset shipped with ore-monitor, see `data/platforms.toml`
+    const BUNDLED: &str = include_str!("../data/platforms.toml");
+
+    /// Whether a SpongeAPI release is still supported
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum SupportStatus {
+        Supported,
+        EndOfLife,
+    }
+
+    /// A SpongeAPI release and the Minecraft version it targets
+    #[derive(Deserialize, Debug, Clone, PartialEq)]
+    pub struct Platform {
+        pub api: u32,
+        pub minecraft: String,
+        pub status: SupportStatus,
+    }
+
+    impl Platform {
+        pub fn is_end_of_life(&self) -> bool {
+            self.status == SupportStatus::EndOfLife
+        }
+    }
+
+    impl Display for Platform {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "API {} (Minecraft {})", self.api, self.minecraft)
+        }
+    }
+
+    /// The support status of each SpongeAPI release
+    /// The bundled dataset can be updated by a `platforms.toml` in the config directory.
+    /// ```
+    /// use ore_monitor::platform_support::PlatformSupport;
+    ///
+    /// let mut support = PlatformSupport::bundled();
+    /// assert!(support.end_of_life(7).is_some());
+    /// assert!(support.end_of_life(0).is_none());
+    ///
+    /// let update = PlatformSupport::parse(
+    ///     "[[platform]]\napi = 7\nminecraft = \"1.12.2\"\nstatus = \"supported\"",
+    /// ).unwrap();
+    /// support.merge(update);
+    /// assert!(support.end_of_life(7).is_none());
+    /// assert_eq!(support.by_minecraft("1.12.2").unwrap().to_string(), "API 7 (Minecraft 1.12.2)");
+    /// ```
+    #[derive(Deserialize, Debug, Default, PartialEq)]
+    pub struct PlatformSupport {
+        #[serde(default, rename = "platform")]
+        pub platforms: Vec<Platform>,
+    }
+
+    impl PlatformSupport {
+        const FILE_NAME: &'static str = "platforms.toml";
+
+        pub fn path() -> Option<PathBuf> {
+            dirs::config_dir().map(|dir| dir.join("ore-monitor").join(Self::FILE_NAME))
+        }
+
+        pub fn bundled() -> Self {
+            Self::parse(BUNDLED).unwrap_or_default()
+        }
+
+        pub fn parse(toml: &str) -> Result<Self> {
+            Ok(toml::from_str(toml)?)
+        }
+
+        /// The bundled dataset, updated by the one in the config directory if there is one
+        pub fn load() -> Result<Self> {
+            let mut support = Self::bundled();
+            if let Some(path) = Self::path().filter(|path| path.is_file()) {
+                support.merge(Self::parse(&fs::read_to_string(path)?)?);
+            }
+            Ok(support)
+        }
+
+        /// Adds the platforms of the other dataset, replacing those of the same API
+        pub fn merge(&mut self, other: PlatformSupport) {
+            for platform in other.platforms {
+                self.platforms.retain(|known| known.api != platform.api);
+                self.platforms.push(platform);
+            }
+            self.platforms.sort_by_key(|platform| platform.api);
+        }
+
+        pub fn api(&self, major: u32) -> Option<&Platform> {
+            self.platforms.iter().find(|platform| platform.api == major)
+        }
+
+        /// The platform of the API, when it's end-of-life
+        pub fn end_of_life(&self, major: u32) -> Option<&Platform> {
+            self.api(major).filter(|platform| platform.is_end_of_life())
+        }
+
+        pub fn by_minecraft(&self, minecraft: &str) -> Option<&Platform> {
+            self.platforms
+                .iter()
+                .find(|platform| platform.minecraft == minecraft)
+        }
+
+        /// The platform of the Sponge server jar inside of the directory, by the Minecraft version in its name
+        /// ```
+        /// use ore_monitor::platform_support::PlatformSupport;
+        ///
+        /// let dir = std::env::temp_dir().join("ore_monitor_platform_doctest");
+        /// std::fs::create_dir_all(&dir).unwrap();
+        /// std::fs::write(dir.join("spongevanilla-1.12.2-7.4.7.jar"), "").unwrap();
+        ///
+        /// let support = PlatformSupport::bundled();
+        /// let platform = support.detect_server(&dir).unwrap();
+        /// assert_eq!(platform.api, 7);
+        /// # std::fs::remove_dir_all(&dir).unwrap();
+        /// ```
+        pub fn detect_server(&self, dir: &Path) -> Option<&Platform> {
+            let mut names = fs::read_dir(dir)
+                .ok()?
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_lowercase())
+                .filter(|name| name.starts_with("sponge") && name.ends_with(".jar"))
+                .collect::<Vec<String>>();
+            names.sort();
+
+            names.iter().find_map(|name| {
+                name.split('-')
+                    .find_map(|part| self.by_minecraft(part.trim_start_matches("mc")))
+            })
+        }
+    }
+}
+
+pub mod storage {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::OnceLock,
+    };
+
+    use anyhow::Result;
+    use serde::Deserialize;
+
+    use crate::instance_lock::write_atomic;
+
+    /// Where caches and the activity log are persisted, each under its own key
+    /// Stored values are JSON, a backend only has to keep them whole.
+    /// ```
+    /// use ore_monitor::storage::{JsonFiles, Storage};
+    ///
+    /// let dir = std::env::temp_dir().join("ore_monitor_storage_doctest");
+    /// let storage = JsonFiles::new(&dir);
+    /// storage.write("scan-cache", "{}").unwrap();
+    ///
+    /// assert_eq!(storage.read("scan-cache").unwrap().as_deref(), Some("{}"));
+    /// assert_eq!(storage.read("activity").unwrap(), None);
+    /// assert!(dir.join("scan-cache.json").is_file());
+    /// # std::fs::remove_dir_all(dir).unwrap();
+    /// ```
+    pub trait Storage: Send + Sync {
+        /// The value stored under the key, `None` when nothing was stored yet
+        fn read(&self, key: &str) -> Result<Option<String>>;
+
+        /// Replaces the value stored under the key
+        fn write(&self, key: &str, value: &str) -> Result<()>;
+    }
+
+    /// The storage backend, set by `storage` in the config
+    #[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Backend {
+        /// A JSON file per key, without any native dependency
+        #[default]
+        Json,
+        /// A single SQLite database, only available when built with the `sqlite` feature
+        Sqlite,
+    }
+
+    impl Backend {
+        /// Opens the backend's storage in the directory
+        pub fn open(self, dir: &Path) -> Result<Box<dyn Storage>> {
+            match self {
+                Backend::Json => Ok(Box::new(JsonFiles::new(dir))),
+                #[cfg(feature = "sqlite")]
+                Backend::Sqlite => Ok(Box::new(Sqlite::open(&dir.join(Sqlite::FILE_NAME))?)),
+                #[cfg(not(feature = "sqlite"))]
+                Backend::Sqlite => Err(anyhow::Error::msg(
+                    "This build doesn't include SQLite storage, rebuild with `--features sqlite` or set `storage = \"json\"`",
+                )),
+            }
+        }
+    }
+
+    static SELECTED: OnceLock<Box<dyn Storage>> = OnceLock::new();
+
+    /// Located in the platform's cache directory
+    pub fn dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("ore-monitor"))
+    }
+
+    /// Opens the backend as the [current] storage, only the first selection is kept
+    pub fn select(backend: Backend) -> Result<()> {
+        let Some(dir) = dir() else {
+            return Ok(());
+        };
+        let _ = SELECTED.set(backend.open(&dir)?);
+        Ok(())
+    }
+
+    /// The selected storage, JSON files when none was selected
+    /// Without a cache directory nothing is kept.
+    pub fn current() -> &'static dyn Storage {
+        SELECTED
+            .get_or_init(|| match dir() {
+                Some(dir) => Box::new(JsonFiles::new(&dir)),
+                None => Box::new(Discard),
+            })
+            .as_ref()
+    }
+
+    /// Each key is a `<key>.json` file of the directory
+    pub struct JsonFiles {
+        dir: PathBuf,
+    }
+
+    impl JsonFiles {
+        pub fn new(dir: &Path) -> JsonFiles {
+            JsonFiles {
+                dir: dir.to_path_buf(),
+            }
+        }
+
+        fn path(&self, key: &str) -> PathBuf {
+            self.dir.join(format!("{}.json", key))
+        }
+    }
+
+    impl Storage for JsonFiles {
+        fn read(&self, key: &str) -> Result<Option<String>> {
+            match fs::read_to_string(self.path(key)) {
+                Ok(value) => Ok(Some(value)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        }
+
+        fn write(&self, key: &str, value: &str) -> Result<()> {
+            fs::create_dir_all(&self.dir)?;
+            write_atomic(&self.path(key), value)?;
+            Ok(())
+        }
+    }
+
+    /// Every key is a row of a single table, each write being its own transaction
+    /// Several processes sharing the database, like `watch` next to a nightly `prefetch`, never see a partial write.
+    #[cfg(feature = "sqlite")]
+    pub struct Sqlite {
+        connection: std::sync::Mutex<rusqlite::Connection>,
+    }
+
+    #[cfg(feature = "sqlite")]
+    impl Sqlite {
+        pub const FILE_NAME: &'static str = "storage.sqlite";
+
+        pub fn open(path: &Path) -> Result<Sqlite> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let connection = rusqlite::Connection::open(path)?;
+            // Waits on another process' write instead of failing right away
+            connection.busy_timeout(std::time::Duration::from_secs(5))?;
+            connection.execute(
+                "CREATE TABLE IF NOT EXISTS entries (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+                (),
+            )?;
+            Ok(Sqlite {
+                connection: std::sync::Mutex::new(connection),
+            })
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    impl Storage for Sqlite {
+        fn read(&self, key: &str) -> Result<Option<String>> {
+            use rusqlite::OptionalExtension;
+
+            let connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+            Ok(connection
+                .query_row("SELECT value FROM entries WHERE key = ?1", [key], |row| {
+                    row.get(0)
+                })
+                .optional()?)
+        }
+
+        fn write(&self, key: &str, value: &str) -> Result<()> {
+            let connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+            connection.execute(
+                "INSERT INTO entries (key, value) VALUES (?1, ?2) ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                [key, value],
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Used without a cache directory
+    struct Discard;
+
+    impl Storage for Discard {
+        fn read(&self, _key: &str) -> Result<Option<String>> {
+            Ok(None)
+        }
+
+        fn write(&self, _key: &str, _value: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+}
+
+pub mod activity_log {
+    use std::{collections::BTreeMap, fmt::Display};
+
+    use anyhow::Result;
+    use chrono::{DateTime, Duration, Utc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::storage;
+
+    /// What Ore's projects looked like when last seen, and the changes seen since
+    /// Ore doesn't keep a history of promoted versions or descriptions, so changes are found by comparing.
+    /// A change is dated by the project's last update when it falls between both sightings, otherwise when it was seen.
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use ore_monitor::activity_log::{ActivityLog, ChangeKind};
+    ///
+    /// let monday = Utc.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap();
+    /// let friday = monday + Duration::days(4);
+    /// let mut log = ActivityLog::default();
+    ///
+    /// // The first sighting has nothing to compare against
+    /// let promoted = vec!["2.1.4".to_string()];
+    /// assert!(log.observe("nucleus", &promoted, "Essentials", monday, monday).is_empty());
+    ///
+    /// let promoted = vec!["2.1.5".to_string()];
+    /// let updated = monday + Duration::days(2);
+    /// let changes = log.observe("nucleus", &promoted, "Essentials", updated, friday);
+    /// assert_eq!(changes.len(), 1);
+    /// assert_eq!(changes[0].at, updated);
+    /// assert!(matches!(changes[0].kind, ChangeKind::Promoted { .. }));
+    ///
+    /// assert_eq!(log.changes_since("nucleus", friday).len(), 0);
+    /// assert_eq!(log.changes_since("nucleus", monday).len(), 1);
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct ActivityLog {
+        projects: BTreeMap<String, ProjectSnapshot>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct ProjectSnapshot {
+        promoted: Vec<String>,
+        description: String,
+        seen_at: DateTime<Utc>,
+        #[serde(default)]
+        changes: Vec<ObservedChange>,
+    }
+
+    /// A change found between two sightings of a project
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ObservedChange {
+        pub at: DateTime<Utc>,
+        pub kind: ChangeKind,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ChangeKind {
+        Promoted { from: Vec<String>, to: Vec<String> },
+        Description,
+    }
+
+    impl Display for ChangeKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ChangeKind::Promoted { from, to } => {
+                    let list = |versions: &[String]| match versions.is_empty() {
+                        true => "none".to_string(),
+                        false => versions.join(", "),
+                    };
+                    write!(
+                        f,
+                        "Promoted versions changed : {} -> {}",
+                        list(from),
+                        list(to)
+                    )
+                }
+                ChangeKind::Description => write!(f, "Description updated"),
+            }
+        }
+    }
+
+    impl ActivityLog {
+        const KEY: &'static str = "activity";
+
+        /// How long changes are remembered
+        pub fn max_age() -> Duration {
+            Duration::days(90)
+        }
+
+        /// Loads the log, a missing or unreadable log is empty and starts over
+        pub fn load() -> ActivityLog {
+            storage::current()
+                .read(Self::KEY)
+                .ok()
+                .flatten()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+
+        /// Saves the log, forgetting changes older than the [max age](Self::max_age)
+        pub fn save(&mut self, now: DateTime<Utc>) -> Result<()> {
+            let oldest = now - Self::max_age();
+            for snapshot in self.projects.values_mut() {
+                snapshot.changes.retain(|change| change.at >= oldest);
+            }
+
+            storage::current().write(Self::KEY, &serde_json::to_string(self)?)
+        }
+
+        /// Records the project as it is now, returning the changes since it was last seen
+        pub fn observe(
+            &mut self,
+            plugin_id: &str,
+            promoted: &[String],
+            description: &str,
+            last_updated: DateTime<Utc>,
+            now: DateTime<Utc>,
+        ) -> Vec<ObservedChange> {
+            let Some(snapshot) = self.projects.get_mut(plugin_id) else {
+                self.projects.insert(
+                    plugin_id.to_string(),
+                    ProjectSnapshot {
+                        promoted: promoted.to_vec(),
+                        description: description.to_string(),
+                        seen_at: now,
+                        changes: vec![],
+                    },
+                );
+                return vec![];
+            };
+
+            let at = match last_updated > snapshot.seen_at && last_updated <= now {
+                true => last_updated,
+                false => now,
+            };
+            let mut changes = vec![];
+            if snapshot.promoted != promoted {
+                changes.push(ObservedChange {
+                    at,
+                    kind: ChangeKind::Promoted {
+                        from: snapshot.promoted.clone(),
+                        to: promoted.to_vec(),
+                    },
+                });
+            }
+            if snapshot.description != description {
+                changes.push(ObservedChange {
+                    at,
+                    kind: ChangeKind::Description,
+                });
+            }
+
+            snapshot.promoted = promoted.to_vec();
+            snapshot.description = description.to_string();
+            snapshot.seen_at = now;
+            snapshot.changes.extend(changes.iter().cloned());
+            changes
+        }
+
+        /// Changes of the project at or after the moment, oldest first
+        pub fn changes_since(&self, plugin_id: &str, since: DateTime<Utc>) -> Vec<&ObservedChange> {
+            self.projects
+                .get(plugin_id)
+                .map(|snapshot| {
+                    snapshot
+                        .changes
+                        .iter()
+                        .filter(|change| change.at >= since)
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+    }
+}
+
+pub mod watchlist {
+    use std::collections::BTreeMap;
+
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::storage;
+
+    /// Plugins followed without being installed, with the newest version seen of each
+    /// Versions are told apart by when they were published, so one removed from Ore doesn't hide those after it.
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use ore_monitor::watchlist::{SeenVersion, Watchlist};
+    ///
+    /// let monday = Utc.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap();
+    /// let version = |name: &str, days| SeenVersion {
+    ///     name: name.to_string(),
+    ///     published: monday + Duration::days(days),
+    /// };
+    /// let mut watchlist = Watchlist::default();
+    /// assert!(watchlist.add("Nucleus"));
+    /// assert!(!watchlist.add("nucleus"));
+    ///
+    /// // The first check has nothing to compare against
+    /// assert!(watchlist.observe("nucleus", vec![version("2.1.4", 0)]).is_empty());
+    /// assert_eq!(watchlist.last_seen("nucleus").unwrap().name, "2.1.4");
+    ///
+    /// let versions = vec![version("2.1.6", 4), version("2.1.5", 2), version("2.1.4", 0)];
+    /// let new = watchlist.observe("nucleus", versions);
+    /// assert_eq!(new, [version("2.1.6", 4), version("2.1.5", 2)]);
+    /// assert_eq!(watchlist.last_seen("nucleus").unwrap().name, "2.1.6");
+    ///
+    /// assert!(watchlist.remove("nucleus"));
+    /// assert_eq!(watchlist.plugins().count(), 0);
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct Watchlist {
+        plugins: BTreeMap<String, Option<SeenVersion>>,
+    }
+
+    /// A version of a watched plugin, and when it was published
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct SeenVersion {
+        pub name: String,
+        pub published: DateTime<Utc>,
+    }
+
+    impl Watchlist {
+        const KEY: &'static str = "watchlist";
+
+        /// Loads the watch list, empty when nothing was watched yet
+        /// Unlike the caches, an unreadable list is an error instead of starting over, it isn't kept anywhere else.
+        pub fn load() -> Result<Watchlist> {
+            match storage::current().read(Self::KEY)? {
+                Some(json) => Ok(serde_json::from_str(&json)?),
+                None => Ok(Watchlist::default()),
+            }
+        }
+
+        pub fn save(&self) -> Result<()> {
+            storage::current().write(Self::KEY, &serde_json::to_string(self)?)
+        }
+
+        /// Starts watching the plugin, false when it already was
+        pub fn add(&mut self, plugin_id: &str) -> bool {
+            let plugin_id = plugin_id.to_lowercase();
+            if self.plugins.contains_key(&plugin_id) {
+                return false;
+            }
+            self.plugins.insert(plugin_id, None);
+            true
+        }
+
+        /// Stops watching the plugin, false when it wasn't
+        pub fn remove(&mut self, plugin_id: &str) -> bool {
+            self.plugins.remove(&plugin_id.to_lowercase()).is_some()
+        }
+
+        /// The watched plugins by id, with the newest version seen of each
+        pub fn plugins(&self) -> impl Iterator<Item = (&str, Option<&SeenVersion>)> {
+            self.plugins
+                .iter()
+                .map(|(id, seen)| (id.as_str(), seen.as_ref()))
+        }
+
+        pub fn last_seen(&self, plugin_id: &str) -> Option<&SeenVersion> {
+            self.plugins
+                .get(&plugin_id.to_lowercase())
+                .and_then(Option::as_ref)
+        }
+
+        /// Records the plugin's latest versions, returning those published after the last one seen, newest first
+        /// Nothing is new the first time a plugin is checked, or when it isn't watched.
+        pub fn observe(&mut self, plugin_id: &str, versions: Vec<SeenVersion>) -> Vec<SeenVersion> {
+            let Some(seen) = self.plugins.get_mut(&plugin_id.to_lowercase()) else {
+                return vec![];
+            };
+
+            let mut new: Vec<SeenVersion> = match seen {
+                Some(last) => versions
+                    .into_iter()
+                    .filter(|version| version.published > last.published)
+                    .collect(),
+                None => {
+                    *seen = versions.into_iter().max_by_key(|version| version.published);
+                    return vec![];
+                }
+            };
+            new.sort_by_key(|version| std::cmp::Reverse(version.published));
+            if let Some(newest) = new.first() {
+                *seen = Some(newest.clone());
+            }
+            new
+        }
+    }
+}
+
+pub mod scan_cache {
+    use std::{
+        collections::HashMap,
+        fs,
+        path::{Path, PathBuf},
+        time::SystemTime,
+    };
+
+    use anyhow::Result;
+    use serde::{Deserialize, Serialize};
+
+    use crate::{file_hash::md5_hex, ore_mod_info::OreModInfo, storage};
+
+    /// The [OreModInfo] of jars that have already been read
+    /// A jar is only read again once its modified time or size changes,
+    /// a jar whose modified time changed but whose md5 didn't, like one copied over again, isn't read either.
+    /// ```
+    /// # use ore_monitor::{file_reader::FileReader, scan_cache::ScanCache};
+    /// # use std::{fs, path::Path, time::{Duration, SystemTime}};
+    /// let jar = std::env::temp_dir().join("ore_monitor_scan_cache_doctest.jar");
+    /// fs::copy("./local/test/nucleus.jar", &jar).unwrap();
+    /// let reader = FileReader::from(&jar);
+    /// let mut cache = ScanCache::default();
+    ///
+    /// let info = cache.get_or_read(&jar, |path| reader.handle_file(Some(path))).unwrap();
+    /// let cached = cache.get_or_read(&jar, |_| panic!("unchanged jars are not read again")).unwrap();
+    /// assert_eq!(info, cached);
+    ///
+    /// let touched = SystemTime::now() + Duration::from_secs(60);
+    /// fs::File::options().write(true).open(&jar).unwrap().set_modified(touched).unwrap();
+    /// let cached = cache.get_or_read(&jar, |_| panic!("the same bytes are not read again")).unwrap();
+    /// assert_eq!(info, cached);
+    ///
+    /// let mut cache = cache.with_rescan(true);
+    /// let mut read = false;
+    /// cache.get_or_read(&jar, |path| { read = true; reader.handle_file(Some(path)) }).unwrap();
+    /// assert!(read, "rescanning reads every jar again");
+    /// # fs::remove_file(&jar).unwrap();
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct ScanCache {
+        entries: HashMap<PathBuf, CachedInfo>,
+        /// Reads every jar again, replacing what was cached of them
+        #[serde(skip)]
+        rescan: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct CachedInfo {
+        modified: SystemTime,
+        size: u64,
+        /// Missing from entries cached before jars were hashed, those are read again once they change
+        #[serde(default)]
+        md5: Option<String>,
+        info: OreModInfo,
+    }
+
+    impl ScanCache {
+        const KEY: &'static str = "scan-cache";
+
+        /// Loads the cache, a missing or unreadable cache is empty as it can always be rebuilt
+        pub fn load() -> ScanCache {
+            storage::current()
+                .read(Self::KEY)
+                .ok()
+                .flatten()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+
+        pub fn with_rescan(mut self, rescan: bool) -> Self {
+            self.rescan = rescan;
+            self
+        }
+
+        /// Saves the cache, forgetting jars that no longer exist
+        pub fn save(&mut self) -> Result<()> {
+            self.entries.retain(|jar, _| jar.is_file());
+
+            storage::current().write(Self::KEY, &serde_json::to_string(self)?)
+        }
+
+        /// The cached info when the jar is unchanged, otherwise it's read and cached
+        pub fn get_or_read<F>(&mut self, jar: &Path, read: F) -> Result<OreModInfo>
+        where
+            F: FnOnce(&Path) -> Result<OreModInfo>,
+        {
+            let stamp = Stamp::of(jar)?;
+            if let Some(info) = self.cached(&stamp) {
+                return Ok(info);
+            }
+
+            let info = read(jar)?;
+            self.insert(stamp, &info);
+            Ok(info)
+        }
+
+        /// Same as [ScanCache::get_or_read] for several jars, the changed ones are read together
+        /// `read` is handed every jar that isn't cached, and returns what it read of each in the same order.
+        /// ```
+        /// # use ore_monitor::{file_reader::FileReader, scan_cache::ScanCache};
+        /// # use std::path::PathBuf;
+        /// let jars = [PathBuf::from("./local/test/nucleus.jar")];
+        /// let reader = FileReader::default();
+        /// let mut cache = ScanCache::default();
+        ///
+        /// let read = cache.get_or_read_all(&jars, |jars| reader.read_all(jars));
+        /// let cached = cache.get_or_read_all(&jars, |jars| {
+        ///     assert!(jars.is_empty(), "unchanged jars are not read again");
+        ///     vec![]
+        /// });
+        /// assert_eq!(read[0].as_ref().unwrap(), cached[0].as_ref().unwrap());
+        /// ```
+        pub fn get_or_read_all<F>(&mut self, jars: &[PathBuf], read: F) -> Vec<Result<OreModInfo>>
+        where
+            F: FnOnce(&[PathBuf]) -> Vec<Result<OreModInfo>>,
+        {
+            let mut results = vec![];
+            let mut changed = vec![];
+            for jar in jars {
+                match Stamp::of(jar) {
+                    Ok(stamp) => match self.cached(&stamp) {
+                        Some(info) => results.push(Some(Ok(info))),
+                        None => {
+                            results.push(None);
+                            changed.push((jar.to_path_buf(), stamp));
+                        }
+                    },
+                    Err(e) => results.push(Some(Err(e))),
+                }
+            }
+
+            let paths = changed
+                .iter()
+                .map(|(jar, _)| jar.clone())
+                .collect::<Vec<_>>();
+            let mut read = read(&paths).into_iter();
+            let unread = results.iter_mut().filter(|result| result.is_none());
+            for (slot, (_, stamp)) in unread.zip(changed) {
+                let info = read
+                    .next()
+                    .unwrap_or_else(|| Err(anyhow::Error::msg("the jar was not read")));
+                if let Ok(info) = &info {
+                    self.insert(stamp, info);
+                }
+                *slot = Some(info);
+            }
+
+            results.into_iter().flatten().collect()
+        }
+
+        /// The cached info of the jar, unless it changed since it was cached
+        /// The md5 is only computed when the modified time changed but the size didn't.
+        fn cached(&mut self, stamp: &Stamp) -> Option<OreModInfo> {
+            if self.rescan {
+                return None;
+            }
+            let cached = self.entries.get_mut(&stamp.key)?;
+            if cached.size != stamp.size {
+                return None;
+            }
+            if cached.modified != stamp.modified {
+                let md5 = cached.md5.as_ref()?;
+                if md5_hex(&stamp.key).ok().as_ref() != Some(md5) {
+                    return None;
+                }
+                cached.modified = stamp.modified;
+            }
+            Some(cached.info.clone())
+        }
+
+        fn insert(&mut self, stamp: Stamp, info: &OreModInfo) {
+            let md5 = md5_hex(&stamp.key).ok();
+            self.entries.insert(
+                stamp.key,
+                CachedInfo {
+                    modified: stamp.modified,
+                    size: stamp.size,
+                    md5,
+                    info: info.clone(),
+                },
+            );
+        }
+    }
+
+    /// What tells whether a jar changed since it was cached
+    struct Stamp {
+        key: PathBuf,
+        modified: SystemTime,
+        size: u64,
+    }
+
+    impl Stamp {
+        fn of(jar: &Path) -> Result<Stamp> {
+            let metadata = fs::metadata(jar)?;
+            Ok(Stamp {
+                key: fs::canonicalize(jar)?,
+                modified: metadata.modified()?,
+                size: metadata.len(),
+            })
+        }
+    }
+}
+
+pub mod project_index {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{sponge_schemas::Project, storage};
+
+    /// A local catalog of Ore's projects, so they can be searched without asking Ore
+    /// Updates only fetch what changed since the newest project seen, see [ProjectIndex::merge].
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use ore_monitor::project_index::{IndexedProject, ProjectIndex};
+    ///
+    /// let monday = Utc.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap();
+    /// let project = |id: &str, last_updated| IndexedProject {
+    ///     plugin_id: id.to_string(),
+    ///     name: id.to_string(),
+    ///     owner: "Sponge".to_string(),
+    ///     category: "admin_tools".to_string(),
+    ///     downloads: 0,
+    ///     stars: 0,
+    ///     last_updated,
+    /// };
+    ///
+    /// let mut index = ProjectIndex::default();
+    /// assert!(!index.merge(vec![project("nucleus", monday)], index.newest()));
+    ///
+    /// // A page reaching back to what was indexed before the update ends it
+    /// let tuesday = monday + Duration::days(1);
+    /// let since = index.newest();
+    /// assert!(index.merge(vec![project("luckperms", tuesday), project("nucleus", monday)], since));
+    /// assert_eq!(index.newest(), Some(tuesday));
+    /// assert_eq!(index.search("luck").len(), 1);
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct ProjectIndex {
+        projects: BTreeMap<String, IndexedProject>,
+        /// Until when Ore allowed the index to be reused, by its `Cache-Control`
+        fresh_until: Option<DateTime<Utc>>,
+    }
+
+    /// What's kept of a project in the index
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct IndexedProject {
+        pub plugin_id: String,
+        pub name: String,
+        pub owner: String,
+        pub category: String,
+        pub downloads: i64,
+        pub stars: i64,
+        pub last_updated: DateTime<Utc>,
+    }
+
+    impl From<Project> for IndexedProject {
+        fn from(value: Project) -> Self {
+            IndexedProject {
+                category: value.category.to_string(),
+                plugin_id: value.plugin_id,
+                name: value.name,
+                owner: value.namespace.owner,
+                downloads: value.stats.downloads,
+                stars: value.stats.stars,
+                last_updated: value.last_updated,
+            }
+        }
+    }
+
+    impl ProjectIndex {
+        const KEY: &'static str = "project-index";
+
+        /// Loads the index, a missing or unreadable index is empty and is rebuilt on the next update
+        pub fn load() -> ProjectIndex {
+            storage::current()
+                .read(Self::KEY)
+                .ok()
+                .flatten()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+
+        pub fn save(&self) -> Result<()> {
+            storage::current().write(Self::KEY, &serde_json::to_string(self)?)
+        }
+
+        pub fn len(&self) -> usize {
+            self.projects.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.projects.is_empty()
+        }
+
+        /// When the most recently updated project was updated
+        pub fn newest(&self) -> Option<DateTime<Utc>> {
+            self.projects.values().map(|p| p.last_updated).max()
+        }
+
+        /// Whether Ore's `Cache-Control` still allows using the index without updating it
+        pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+            self.fresh_until.is_some_and(|until| now < until)
+        }
+
+        /// Keeps the index for as long as Ore allowed, an update without a max age is never fresh
+        pub fn set_max_age(&mut self, now: DateTime<Utc>, max_age: Option<Duration>) {
+            self.fresh_until = max_age
+                .and_then(|max_age| chrono::Duration::from_std(max_age).ok())
+                .map(|max_age| now + max_age);
+        }
+
+        /// Adds a page of projects sorted by most recently updated, replacing their previous entries
+        /// Returns whether the page reached back to `since`, the [newest](Self::newest) update before this one began,
+        /// so no later page is needed.
+        pub fn merge(&mut self, page: Vec<IndexedProject>, since: Option<DateTime<Utc>>) -> bool {
+            let mut reached = false;
+            for project in page {
+                reached |= since.is_some_and(|since| project.last_updated <= since);
+                self.projects.insert(project.plugin_id.to_string(), project);
+            }
+            reached
+        }
+
+        /// Projects whose id, name or owner contain the term, ignoring case
+        pub fn search(&self, term: &str) -> Vec<&IndexedProject> {
+            let term = term.to_lowercase();
+            self.projects
+                .values()
+                .filter(|project| {
+                    [&project.plugin_id, &project.name, &project.owner]
+                        .iter()
+                        .any(|field| field.to_lowercase().contains(&term))
+                })
+                .collect()
+        }
+    }
+
+    /// The max age allowed by a `Cache-Control` header, none when it may not be reused
+    /// ```
+    /// use ore_monitor::project_index::max_age;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(max_age("public, max-age=300"), Some(Duration::from_secs(300)));
+    /// assert_eq!(max_age("no-cache, max-age=300"), None);
+    /// assert_eq!(max_age("private"), None);
+    /// ```
+    pub fn max_age(cache_control: &str) -> Option<Duration> {
+        let directives = cache_control
+            .split(',')
+            .map(|directive| directive.trim().to_lowercase())
+            .collect::<Vec<String>>();
+        if directives
+            .iter()
+            .any(|directive| directive == "no-cache" || directive == "no-store")
+        {
+            return None;
+        }
+        directives
+            .iter()
+            .find_map(|directive| directive.strip_prefix("max-age="))
+            .and_then(|seconds| seconds.trim_matches('"').parse().ok())
+            .map(Duration::from_secs)
+    }
+}
+
+pub mod request_context {
+    use std::{fmt::Display, time::Duration};
+
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use rand_core::{OsRng, RngCore};
+    use serde::{Deserialize, Serialize};
+
+    use crate::storage;
+
+    /// Identifies a request in debug logs, errors and the `X-Request-Id` header sent to Ore
+    /// ```
+    /// use ore_monitor::request_context::RequestId;
+    ///
+    /// assert_eq!(RequestId::from(0x7f3a).to_string(), "7f3a");
+    /// assert_eq!(RequestId::from(0x2a).to_string(), "002a");
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct RequestId(u16);
+
+    impl RequestId {
+        /// Ids only have to tell apart the requests of a single run, so they're kept short
+        pub fn generate() -> Self {
+            RequestId(OsRng.next_u32() as u16)
+        }
+    }
+
+    impl From<u16> for RequestId {
+        fn from(value: u16) -> Self {
+            RequestId(value)
+        }
+    }
+
+    impl Display for RequestId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:04x}", self.0)
+        }
+    }
+
+    /// The last request sent to Ore and how it went, kept for `debug-bundle`
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use ore_monitor::request_context::{RequestContext, RequestId};
+    /// use std::time::Duration;
+    ///
+    /// let context = RequestContext {
+    ///     id: RequestId::from(0x7f3a),
+    ///     method: "GET".to_string(),
+    ///     endpoint: "/api/v2/projects/nucleus".to_string(),
+    ///     sent: Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap(),
+    ///     elapsed: Duration::from_millis(132),
+    ///     status: Some(502),
+    ///     error: None,
+    /// };
+    /// assert_eq!(
+    ///     context.to_string(),
+    ///     "request 7f3a GET /api/v2/projects/nucleus\n\
+    ///     Sent    : 2024-05-01 03:00:00 UTC\n\
+    ///     Elapsed : 132ms\n\
+    ///     Status  : 502"
+    /// );
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct RequestContext {
+        pub id: RequestId,
+        pub method: String,
+        pub endpoint: String,
+        pub sent: DateTime<Utc>,
+        pub elapsed: Duration,
+        /// The status Ore responded with, none when it couldn't be reached
+        pub status: Option<u16>,
+        /// Why the response couldn't be received
+        pub error: Option<String>,
+    }
+
+    impl RequestContext {
+        const KEY: &'static str = "last-request";
+
+        /// The context of the last request, none when nothing was sent yet
+        pub fn load() -> Result<Option<RequestContext>> {
+            match storage::current().read(Self::KEY)? {
+                Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+                None => Ok(None),
+            }
+        }
+
+        /// Replaces the context of the last request
+        pub fn save(&self) -> Result<()> {
+            storage::current().write(Self::KEY, &serde_json::to_string(self)?)
+        }
+    }
+
+    impl Display for RequestContext {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "request {} {} {}", self.id, self.method, self.endpoint)?;
+            writeln!(f, "Sent    : {}", self.sent.format("%Y-%m-%d %H:%M:%S UTC"))?;
+            write!(f, "Elapsed : {}ms", self.elapsed.as_millis())?;
+            if let Some(status) = self.status {
+                write!(f, "\nStatus  : {}", status)?;
+            }
+            if let Some(error) = &self.error {
+                write!(f, "\nError   : {}", error)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+pub mod response_cache {
+    use std::{collections::HashMap, fmt::Debug, time::Duration};
+
+    use anyhow::Result;
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::storage;
+
+    /// Where the [OreClient](crate::ore::ore_client::OreClient) keeps the bodies of responses
+    /// [ResponseCache] persists them, other caches let the client be tested without touching storage.
+    pub trait Cache: Send + Debug {
+        /// The response, unless it's older than its TTL or the max age
+        fn get(&self, key: &str, now: DateTime<Utc>, max_age: Duration) -> Option<Cached<'_>>;
+
+        /// The response however old it is
+        fn last_known(&self, key: &str) -> Option<Cached<'_>>;
+
+        /// Keeps the body requested from the URL, for the max age or its own TTL when that's shorter
+        fn insert(
+            &mut self,
+            key: &str,
+            url: &str,
+            body: String,
+            fetched: DateTime<Utc>,
+            ttl: Option<Duration>,
+        );
+
+        /// Persists the cache, forgetting responses that are too old to be used
+        fn save(&mut self, now: DateTime<Utc>, max_age: Duration) -> Result<()>;
+
+        fn len(&self) -> usize;
+
+        fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+    }
+
+    /// A response served from a [Cache]
+    #[derive(Debug, PartialEq)]
+    pub struct Cached<'a> {
+        /// Where it was requested from, so errors name the endpoint as they would for a sent request
+        pub url: &'a str,
+        pub body: &'a str,
+        pub fetched: DateTime<Utc>,
+    }
+
+    /// Bodies of API responses, keyed by the URL and query they were requested with
+    /// Warmed by `prefetch`, and by lookups for a few minutes, so later commands don't have to wait on Ore.
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use ore_monitor::response_cache::{Cache, ResponseCache};
+    /// use std::time::Duration;
+    ///
+    /// let fetched = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+    /// let key = ResponseCache::key("https://ore/api/v2/projects", &[("q".to_string(), "nucleus".to_string())]);
+    /// let mut cache = ResponseCache::default();
+    /// cache.insert(&key, "https://ore/api/v2/projects?q=nucleus", "{}".to_string(), fetched, None);
+    ///
+    /// let day = Duration::from_secs(24 * 60 * 60);
+    /// let cached = cache.get(&key, fetched + chrono::Duration::hours(9), day).unwrap();
+    /// assert_eq!(cached.body, "{}");
+    /// assert_eq!(cached.url, "https://ore/api/v2/projects?q=nucleus");
+    /// assert_eq!(cache.get(&key, fetched + chrono::Duration::hours(25), day), None);
+    /// assert_eq!(cache.get("https://ore/api/v2/projects", fetched, day), None);
+    /// ```
+    /// Responses kept by lookups expire after their TTL, even when the max age is longer
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use ore_monitor::response_cache::{Cache, ResponseCache};
+    /// use std::time::Duration;
+    ///
+    /// let fetched = Utc.with_ymd_and_hms(2024, 5, 1, 3, 0, 0).unwrap();
+    /// let mut cache = ResponseCache::default();
+    /// let key = "https://ore/api/v2/projects/nucleus";
+    /// cache.insert(key, key, "{}".to_string(), fetched, Some(Duration::from_secs(300)));
+    ///
+    /// let day = Duration::from_secs(24 * 60 * 60);
+    /// assert!(cache.get(key, fetched + chrono::Duration::minutes(4), day).is_some());
+    /// assert_eq!(cache.get(key, fetched + chrono::Duration::minutes(6), day), None);
+    /// // Offline, the last known response is used however old it is
+    /// assert_eq!(cache.last_known(key).map(|cached| cached.fetched), Some(fetched));
+    /// ```
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    pub struct ResponseCache {
+        entries: HashMap<String, CachedResponse>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct CachedResponse {
+        fetched: DateTime<Utc>,
+        /// Set for responses kept by lookups, those warmed by `prefetch` are used for the max age
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ttl: Option<Duration>,
+        /// Missing from responses cached before it was kept, their key is used instead
+        #[serde(default)]
+        url: String,
+        body: String,
+    }
+
+    impl ResponseCache {
+        const KEY: &'static str = "responses";
+        /// How long a response is used when not configured
+        pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+        /// How long lookups keep their responses when not configured
+        pub const DEFAULT_TTL: Duration = Duration::from_secs(5 * 60);
+
+        /// Loads the cache, a missing or unreadable cache is empty as it can always be warmed again
+        pub fn load() -> ResponseCache {
+            storage::current()
+                .read(Self::KEY)
+                .ok()
+                .flatten()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+
+        /// Forgets every cached response, returning how many there were
+        pub fn clear() -> Result<usize> {
+            let cleared = Self::load().entries.len();
+            storage::current().write(Self::KEY, &serde_json::to_string(&Self::default())?)?;
+            Ok(cleared)
+        }
+
+        /// The query is part of the key, in the order it's sent
+        pub fn key(url: &str, query: &[(String, String)]) -> String {
+            let query = query
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<String>>()
+                .join("&");
+            match query.is_empty() {
+                true => url.to_string(),
+                false => format!("{}?{}", url, query),
+            }
+        }
+
+        fn cached<'a>(key: &'a str, cached: &'a CachedResponse) -> Cached<'a> {
+            Cached {
+                url: match cached.url.is_empty() {
+                    true => key,
+                    false => &cached.url,
+                },
+                body: &cached.body,
+                fetched: cached.fetched,
+            }
+        }
+
+        fn is_fresh(cached: &CachedResponse, now: DateTime<Utc>, max_age: Duration) -> bool {
+            let max_age = cached.ttl.map_or(max_age, |ttl| ttl.min(max_age));
+            chrono::Duration::from_std(max_age)
+                .is_ok_and(|max_age| now.signed_duration_since(cached.fetched) <= max_age)
+        }
+    }
+
+    impl Cache for ResponseCache {
+        fn last_known(&self, key: &str) -> Option<Cached<'_>> {
+            self.entries
+                .get_key_value(key)
+                .map(|(key, cached)| Self::cached(key, cached))
+        }
+
+        fn get(&self, key: &str, now: DateTime<Utc>, max_age: Duration) -> Option<Cached<'_>> {
+            self.entries
+                .get_key_value(key)
+                .filter(|(_, cached)| Self::is_fresh(cached, now, max_age))
+                .map(|(key, cached)| Self::cached(key, cached))
+        }
+
+        fn insert(
+            &mut self,
+            key: &str,
+            url: &str,
+            body: String,
+            fetched: DateTime<Utc>,
+            ttl: Option<Duration>,
+        ) {
+            let url = url.to_string();
+            self.entries.insert(
+                key.to_string(),
+                CachedResponse {
+                    fetched,
+                    ttl,
+                    url,
+                    body,
+                },
+            );
+        }
+
+        fn save(&mut self, now: DateTime<Utc>, max_age: Duration) -> Result<()> {
+            self.entries
+                .retain(|_, cached| Self::is_fresh(cached, now, max_age));
+
+            storage::current().write(Self::KEY, &serde_json::to_string(self)?)
+        }
+
+        fn len(&self) -> usize {
+            self.entries.len()
+        }
+    }
+}
+
+pub mod dependency_tree {
+    use std::fmt::Display;
+
+    use crate::update_order::satisfies;
+
+    /// A dependency of a plugin being installed, along with what it depends on in turn
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct Dependency {
+        pub id: String,
+        /// The declared version range, empty when any version is accepted
+        pub range: String,
+        pub status: DependencyStatus,
+        /// Only known for dependencies that would be installed
+        pub dependencies: Vec<Dependency>,
+    }
+
+    #[derive(Debug, PartialEq, Clone)]
+    pub enum DependencyStatus {
+        /// Already installed, with this version
+        Installed(String),
+        /// Not installed, this is the newest version within the range
+        Missing(String),
+        /// Not installed, and nothing within the range could be found
+        Unavailable(String),
+        /// Depends on a plugin further up the tree
+        Cycle,
+    }
+
+    /// The dependencies of a plugin version, printed as a tree below it
+    /// ```
+    /// use ore_monitor::dependency_tree::{Dependency, DependencyStatus, DependencyTree};
+    ///
+    /// let dependency = |id: &str, status, dependencies| Dependency {
+    ///     id: id.to_string(),
+    ///     range: String::new(),
+    ///     status,
+    ///     dependencies,
+    /// };
+    /// let tree = DependencyTree {
+    ///     id: "nucleus".to_string(),
+    ///     version: "2.1.4".to_string(),
+    ///     dependencies: vec![
+    ///         dependency("luckperms", DependencyStatus::Installed("5.3.0".to_string()), vec![]),
+    ///         dependency("griefprevention", DependencyStatus::Missing("16.18".to_string()), vec![
+    ///             dependency("nucleus", DependencyStatus::Cycle, vec![]),
+    ///             dependency("worldedit", DependencyStatus::Missing("7.2".to_string()), vec![]),
+    ///         ]),
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(tree.to_string(), "\
+    /// nucleus 2.1.4
+    /// ├── luckperms : installed 5.3.0
+    /// └── griefprevention : missing, installs 16.18
+    ///     ├── nucleus : cycle
+    ///     └── worldedit : missing, installs 7.2
+    /// ");
+    /// let order = tree.install_order().into_iter().map(|(id, version)| format!("{} {}", id, version));
+    /// assert_eq!(order.collect::<Vec<String>>(), vec!["worldedit 7.2", "griefprevention 16.18"]);
+    /// ```
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct DependencyTree {
+        pub id: String,
+        pub version: String,
+        pub dependencies: Vec<Dependency>,
+    }
+
+    impl DependencyTree {
+        /// The dependencies that aren't installed, each after what it depends on
+        pub fn install_order(&self) -> Vec<(&str, &str)> {
+            let mut order = vec![];
+            Self::visit(&self.dependencies, &mut order);
+            order
+        }
+
+        fn visit<'a>(dependencies: &'a [Dependency], order: &mut Vec<(&'a str, &'a str)>) {
+            for dependency in dependencies {
+                Self::visit(&dependency.dependencies, order);
+                if let DependencyStatus::Missing(version) = &dependency.status {
+                    if !order.iter().any(|(id, _)| *id == dependency.id) {
+                        order.push((&dependency.id, version))
+                    }
+                }
+            }
+        }
+
+        /// Dependencies that can't be installed, or are installed outside of their range
+        pub fn problems(&self) -> Vec<String> {
+            let mut problems = vec![];
+            Self::find_problems(&self.dependencies, &mut problems);
+            problems
+        }
+
+        fn find_problems(dependencies: &[Dependency], problems: &mut Vec<String>) {
+            for dependency in dependencies {
+                match &dependency.status {
+                    DependencyStatus::Unavailable(reason) => {
+                        problems.push(format!("{} can't be installed : {}", dependency.id, reason))
+                    }
+                    DependencyStatus::Installed(version)
+                        if !satisfies(version, &dependency.range) =>
+                    {
+                        problems.push(format!(
+                            "{} {} is installed, but {} is required",
+                            dependency.id, version, dependency.range
+                        ))
+                    }
+                    _ => (),
+                }
+                Self::find_problems(&dependency.dependencies, problems);
+            }
+        }
+
+        fn write_level(
+            f: &mut std::fmt::Formatter<'_>,
+            dependencies: &[Dependency],
+            indent: &str,
+        ) -> std::fmt::Result {
+            for (i, dependency) in dependencies.iter().enumerate() {
+                let last = i + 1 == dependencies.len();
+                write!(
+                    f,
+                    "{}{}{}",
+                    indent,
+                    if last { "└── " } else { "├── " },
+                    dependency.id
+                )?;
+                if !dependency.range.is_empty() {
+                    write!(f, " {}", dependency.range)?;
+                }
+                match &dependency.status {
+                    DependencyStatus::Installed(version) => {
+                        writeln!(f, " : installed {}", version)?
+                    }
+                    DependencyStatus::Missing(version) => {
+                        writeln!(f, " : missing, installs {}", version)?
+                    }
+                    DependencyStatus::Unavailable(_) => writeln!(f, " : unavailable")?,
+                    DependencyStatus::Cycle => writeln!(f, " : cycle")?,
+                }
+                let indent = format!("{}{}", indent, if last { "    " } else { "│   " });
+                Self::write_level(f, &dependency.dependencies, &indent)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Display for DependencyTree {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "{} {}", self.id, self.version)?;
+            Self::write_level(f, &self.dependencies, "")
+        }
+    }
+}
+
+pub mod pairing {
+    /// Entries of two lists matched up by their id
+    #[derive(Debug, PartialEq)]
+    pub struct Paired<L, R> {
+        /// Matched entries, sorted by id
+        pub pairs: Vec<(L, R)>,
+        /// Entries of the left list without a match
+        pub left: Vec<L>,
+        /// Entries of the right list without a match
+        pub right: Vec<R>,
+    }
+
+    /// Pairs the entries of two lists by id, regardless of the order they're in
+    /// A duplicated id is only paired once, the extra entry is left unmatched
+    /// ```
+    /// use ore_monitor::pairing::pair_by_id;
+    ///
+    /// let local = vec![("nucleus", "2.1.4"), ("huskycrates", "2.0.0")];
+    /// let remote = vec![("huskycrates", "2.1.0"), ("nucleus", "2.1.4")];
+    ///
+    /// let paired = pair_by_id(local, remote, |l| l.0, |r| r.0);
+    /// assert_eq!(
+    ///     paired.pairs,
+    ///     vec![
+    ///         (("huskycrates", "2.0.0"), ("huskycrates", "2.1.0")),
+    ///         (("nucleus", "2.1.4"), ("nucleus", "2.1.4")),
+    ///     ]
+    /// );
+    /// assert!(paired.left.is_empty() && paired.right.is_empty());
+    /// ```
+    /// Entries missing from either side are kept apart instead of shifting the rest
+    /// ```
+    /// use ore_monitor::pairing::pair_by_id;
+    ///
+    /// let local = vec!["nucleus", "luckperms", "nucleus"];
+    /// let remote = vec!["placeholderapi", "nucleus"];
+    ///
+    /// let paired = pair_by_id(local, remote, |l| *l, |r| *r);
+    /// assert_eq!(paired.pairs, vec![("nucleus", "nucleus")]);
+    /// assert_eq!(paired.left, vec!["luckperms", "nucleus"]);
+    /// assert_eq!(paired.right, vec!["placeholderapi"]);
+    /// ```
+    pub fn pair_by_id<L, R>(
+        left: impl IntoIterator<Item = L>,
+        right: impl IntoIterator<Item = R>,
+        left_id: impl Fn(&L) -> &str,
+        right_id: impl Fn(&R) -> &str,
+    ) -> Paired<L, R> {
+        let mut right = right.into_iter().map(Some).collect::<Vec<Option<R>>>();
+        let mut pairs = vec![];
+        let mut unmatched = vec![];
+
+        for l in left {
+            let found = right
+                .iter()
+                .position(|r| r.as_ref().is_some_and(|r| right_id(r) == left_id(&l)));
+            match found.and_then(|pos| right[pos].take()) {
+                Some(r) => pairs.push((l, r)),
+                None => unmatched.push(l),
+            }
+        }
+        pairs.sort_by(|(a, _), (b, _)| left_id(a).cmp(left_id(b)));
+
+        Paired {
+            pairs,
+            left: unmatched,
+            right: right.into_iter().flatten().collect(),
+        }
+    }
+}
+
+pub mod clock {
+    use chrono::{DateTime, Duration, Utc};
+    use std::{fmt::Debug, sync::Mutex};
+
+    /// A source of the current time, so time dependent logic can be checked at any moment
+    pub trait Clock: Send + Sync + Debug {
+        fn now(&self) -> DateTime<Utc>;
+    }
+
+    /// The system's clock
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct SystemClock;
+
+    impl Clock for SystemClock {
+        fn now(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+    }
+
+    /// A clock stopped at a moment, only moving when it's told to
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use ore_monitor::clock::{Clock, FixedClock};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 3, 31, 0, 30, 0).unwrap();
+    /// let clock = FixedClock::new(start);
+    /// assert_eq!(clock.now(), start);
+    ///
+    /// clock.advance(Duration::hours(2));
+    /// assert_eq!(clock.now(), start + Duration::hours(2));
+    ///
+    /// // Clocks can be set back, as a system clock can be
+    /// clock.set(start - Duration::days(1));
+    /// assert!(clock.now() < start);
+    /// ```
+    #[derive(Debug)]
+    pub struct FixedClock {
+        now: Mutex<DateTime<Utc>>,
+    }
+
+    impl FixedClock {
+        pub fn new(now: DateTime<Utc>) -> Self {
+            FixedClock {
+                now: Mutex::new(now),
+            }
+        }
+
+        pub fn advance(&self, by: Duration) {
+            let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+            *now += by;
+        }
+
+        pub fn set(&self, at: DateTime<Utc>) {
+            *self.now.lock().unwrap_or_else(|e| e.into_inner()) = at;
+        }
+    }
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    /// Describes how long ago a moment was, in its largest whole unit
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use ore_monitor::clock::ago;
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 10, 27, 12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(ago(now - Duration::seconds(20), now), "just now");
+    /// assert_eq!(ago(now - Duration::minutes(1), now), "1 minute ago");
+    /// assert_eq!(ago(now - Duration::hours(5), now), "5 hours ago");
+    /// assert_eq!(ago(now - Duration::days(3), now), "3 days ago");
+    /// assert_eq!(ago(now - Duration::days(800), now), "2 years ago");
+    /// // A moment after now, such as from a clock running ahead
+    /// assert_eq!(ago(now + Duration::hours(1), now), "just now");
+    /// ```
+    pub fn ago(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+        let elapsed = now - then;
+
+        let (amount, unit) = match elapsed {
+            e if e.num_days() >= 365 => (e.num_days() / 365, "year"),
+            e if e.num_days() >= 30 => (e.num_days() / 30, "month"),
+            e if e.num_days() >= 1 => (e.num_days(), "day"),
+            e if e.num_hours() >= 1 => (e.num_hours(), "hour"),
+            e if e.num_minutes() >= 1 => (e.num_minutes(), "minute"),
+            _ => return "just now".to_string(),
+        };
+
+        match amount {
+            1 => format!("1 {} ago", unit),
+            _ => format!("{} {}s ago", amount, unit),
+        }
+    }
 }
@@ -0,0 +1,57 @@
+pub mod log_output {
+    use std::{fmt, io};
+
+    use tracing::{level_filters::LevelFilter, Event, Level, Subscriber};
+    use tracing_subscriber::{
+        filter::Targets,
+        fmt::{format::Writer, FmtContext, FormatEvent, FormatFields},
+        prelude::*,
+        registry::LookupSpan,
+    };
+
+    /// How much is logged to stderr, from the global `-q` and `-v` flags
+    /// Quiet only keeps errors, each `-v` adds a level, `-vv` logging every request sent to Ore.
+    pub fn level(quiet: bool, verbose: u8) -> LevelFilter {
+        match (quiet, verbose) {
+            (true, _) => LevelFilter::ERROR,
+            (false, 0) => LevelFilter::INFO,
+            (false, 1) => LevelFilter::DEBUG,
+            (false, _) => LevelFilter::TRACE,
+        }
+    }
+
+    /// Logs ore-monitor's own events to stderr, those of its dependencies are left out
+    pub fn init(level: LevelFilter) {
+        let layer = tracing_subscriber::fmt::layer()
+            .event_format(Plain)
+            .with_writer(io::stderr)
+            .with_filter(Targets::new().with_target("ore_monitor", level));
+        tracing_subscriber::registry().with(layer).init();
+    }
+
+    /// Messages as they were printed before logging went through tracing, warnings and errors named as such
+    struct Plain;
+
+    impl<S, N> FormatEvent<S, N> for Plain
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+        N: for<'a> FormatFields<'a> + 'static,
+    {
+        fn format_event(
+            &self,
+            ctx: &FmtContext<'_, S, N>,
+            mut writer: Writer<'_>,
+            event: &Event<'_>,
+        ) -> fmt::Result {
+            match *event.metadata().level() {
+                Level::ERROR => write!(writer, "Error : ")?,
+                Level::WARN => write!(writer, "Warning : ")?,
+                Level::INFO => (),
+                Level::DEBUG => write!(writer, "[debug] ")?,
+                Level::TRACE => write!(writer, "[trace] ")?,
+            }
+            ctx.field_format().format_fields(writer.by_ref(), event)?;
+            writeln!(writer)
+        }
+    }
+}
@@ -1,17 +1,108 @@
 mod commands;
-mod ore;
-mod sponge_schemas;
+mod config;
+mod logging;
+mod modrinth;
+mod notify;
+mod tui;
+mod verify;
 
 use anyhow::Result;
 use clap::Parser;
-use commands::core_command::Cli;
-use ore::ore_auth::OreAuth;
+use commands::{core_command::Cli, ping_command::ProbeFailed, version_check_command::CheckOutcome};
+use config::ore_config::Config;
+use ore_monitor::{
+    alias,
+    error::OreMonitorError,
+    instance_lock::DirLock,
+    ore::{
+        ore_auth::{OreAuth, ORE_API},
+        ore_client::{Explain, Explained, OreClient},
+    },
+    storage,
+};
 
 /// Entrypoint for the application
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Authorize the ore client
-    let ore_client = OreAuth::default().auth().await?;
+    match run().await {
+        // Explaining stops at the first request, as later ones depend on its response
+        Err(e) if e.is::<Explained>() => Ok(()),
+        Err(e) => {
+            if let Some(outcome) = e.downcast_ref::<CheckOutcome>() {
+                eprintln!("{}", outcome);
+                std::process::exit(outcome.exit_code())
+            }
+            if let Some(failed) = e.downcast_ref::<ProbeFailed>() {
+                eprintln!("{}", failed);
+                std::process::exit(failed.exit_code())
+            }
+            if let Some(error) = e.downcast_ref::<OreMonitorError>() {
+                eprintln!("Error : {}", error);
+                std::process::exit(error.exit_code())
+            }
+            Err(e)
+        }
+        res => res,
+    }
+}
+
+async fn run() -> Result<()> {
+    let config = Config::load()?;
+    storage::select(config.storage)?;
+
+    // Aliases are resolved before clap sees the arguments
+    let args = alias::expand(std::env::args().collect(), &config.alias);
+    let mut cli = Cli::parse_from(args);
+    cli.command.apply_config(&config);
+
+    let verbose = if cli.debug {
+        cli.verbose.max(2)
+    } else {
+        cli.verbose
+    };
+    logging::log_output::init(logging::log_output::level(cli.quiet, verbose));
+
+    let explain = match (cli.explain, cli.run) {
+        (false, _) => Explain::Off,
+        (true, false) => Explain::Only,
+        (true, true) => Explain::Run,
+    };
+
+    let base_url = config.base_url.clone().unwrap_or(ORE_API.to_string());
+
+    // Authorize the ore client, unless nothing will be sent
+    let ore_client = match explain {
+        _ if !cli.command.requires_auth() => OreClient::unauthenticated(base_url),
+        Explain::Only => OreClient::unauthenticated(base_url),
+        Explain::Off | Explain::Run => {
+            let api_key = config.api_key().ok_or_else(|| {
+                anyhow::Error::msg(
+                    "An api key is required, set ORE_API_KEY or `api_key` in the config",
+                )
+            })?;
+            OreAuth::new(config.http.client()?, base_url, api_key)
+                .auth()
+                .await?
+                .with_explain(explain)
+        }
+    };
+    let ore_client = match config.concurrency {
+        Some(concurrency) => ore_client.with_concurrency(concurrency),
+        None => ore_client,
+    };
+    let ore_client = ore_client
+        .with_response_cache(
+            cli.command.cache_mode(cli.no_cache),
+            config.cache_max_age()?,
+        )
+        .with_cache_ttl(config.cache_ttl()?)
+        .with_retry(config.retry.policy()?);
+
+    // Held until the command is done, a directory that doesn't exist yet has nothing to race on
+    let _lock = match cli.command.changes_dir().filter(|dir| dir.is_dir()) {
+        Some(dir) if explain != Explain::Only => Some(DirLock::acquire(&dir)?),
+        _ => None,
+    };
 
-    Ok(Cli::parse().trait_value().handle(ore_client, None).await?)
+    cli.command.trait_value().handle(ore_client, None).await
 }
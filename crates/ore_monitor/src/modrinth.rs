@@ -0,0 +1,153 @@
+pub mod modrinth_client {
+    use anyhow::Result;
+    use reqwest::{header, Client, Response};
+
+    use super::modrinth_schemas::ModrinthVersion;
+
+    /// Where Modrinth's project pages are, as opposed to its API
+    pub const MODRINTH_SITE: &str = "https://modrinth.com";
+
+    /// A minimal client for the Modrinth API, which requires no authentication for reading
+    #[derive(Debug)]
+    pub struct ModrinthClient {
+        client: Client,
+        base_url: String,
+    }
+
+    impl ModrinthClient {
+        pub fn new(client: Client) -> Self {
+            ModrinthClient {
+                client,
+                base_url: "https://api.modrinth.com/v2".to_string(),
+            }
+        }
+
+        async fn get(&self, url: String) -> Result<Response> {
+            let res = self
+                .client
+                .get(url)
+                .header(header::ACCEPT, "application/json")
+                .header("User-Agent", "Ore-Monitor")
+                .send()
+                .await?;
+            Ok(res.error_for_status()?)
+        }
+
+        /// Every version of a project, newest first
+        pub async fn versions(&self, project_id: &str) -> Result<Vec<ModrinthVersion>> {
+            let link = format!("{}/project/{}/version", self.base_url, project_id);
+            let res = self.get(link).await?;
+            Ok(serde_json::from_str(&res.text().await?)?)
+        }
+
+        pub async fn latest_version(&self, project_id: &str) -> Result<ModrinthVersion> {
+            self.versions(project_id)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow::Error::msg(format!("{} has no versions", project_id)))
+        }
+
+        pub async fn version(&self, project_id: &str, version: &str) -> Result<ModrinthVersion> {
+            self.versions(project_id)
+                .await?
+                .into_iter()
+                .find(|v| v.version_number == version)
+                .ok_or_else(|| {
+                    anyhow::Error::msg(format!("{} has no version {}", project_id, version))
+                })
+        }
+
+        pub async fn download(&self, url: &str) -> Result<Response> {
+            self.get(url.to_string()).await
+        }
+    }
+}
+
+pub mod modrinth_schemas {
+    use anyhow::Result;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    pub struct ModrinthVersion {
+        pub version_number: String,
+        pub files: Vec<ModrinthFile>,
+    }
+
+    impl ModrinthVersion {
+        /// The file marked as primary, otherwise the first file
+        pub fn primary_file(&self) -> Option<&ModrinthFile> {
+            self.files
+                .iter()
+                .find(|f| f.primary)
+                .or_else(|| self.files.first())
+        }
+
+        /// The file whose name contains `wanted`, ex: `api8`, otherwise the primary file
+        /// A name matching several files only picks one when it's the full name of one of them.
+        pub fn file(&self, wanted: Option<&str>) -> Result<&ModrinthFile> {
+            let Some(wanted) = wanted else {
+                return self.primary_file().ok_or_else(|| {
+                    anyhow::Error::msg(format!("Version {} has no files", self.version_number))
+                });
+            };
+
+            let lowercase = wanted.to_lowercase();
+            let matching = self
+                .files
+                .iter()
+                .filter(|f| f.filename.to_lowercase().contains(&lowercase))
+                .collect::<Vec<&ModrinthFile>>();
+            match matching.as_slice() {
+                [file] => Ok(file),
+                [] => Err(anyhow::Error::msg(format!(
+                    "Version {} has no file matching '{}', its files are {}",
+                    self.version_number,
+                    wanted,
+                    self.file_names()
+                ))),
+                _ => matching
+                    .into_iter()
+                    .find(|f| f.filename == wanted)
+                    .ok_or_else(|| {
+                        anyhow::Error::msg(format!(
+                            "'{}' matches several files of version {}, its files are {}",
+                            wanted,
+                            self.version_number,
+                            self.file_names()
+                        ))
+                    }),
+            }
+        }
+
+        /// The name of each file, the primary one marked as such
+        pub fn file_names(&self) -> String {
+            self.files
+                .iter()
+                .map(|f| match f.primary {
+                    true => format!("{} (primary)", f.filename),
+                    false => f.filename.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct ModrinthFile {
+        pub url: String,
+        pub filename: String,
+        #[serde(default)]
+        pub primary: bool,
+        #[serde(default)]
+        pub size: Option<u64>,
+        #[serde(default)]
+        pub hashes: ModrinthHashes,
+    }
+
+    /// The hashes Modrinth publishes of a file
+    #[derive(Deserialize, Debug, Default)]
+    pub struct ModrinthHashes {
+        pub sha512: Option<String>,
+    }
+}
@@ -0,0 +1,182 @@
+pub mod notification {
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use reqwest::Client;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Value};
+    use std::fmt::Display;
+    use tracing::error;
+
+    /// A message delivered to each of the configured sinks
+    pub struct Notification {
+        pub title: String,
+        pub body: String,
+        /// Out of date plugins the notification is about
+        pub plugins: Vec<OutdatedPlugin>,
+    }
+
+    /// A plugin with a newer version available
+    #[derive(Serialize, Debug, Clone)]
+    pub struct OutdatedPlugin {
+        pub id: String,
+        pub local_version: String,
+        pub remote_version: String,
+    }
+
+    impl Notification {
+        pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+            Notification {
+                title: title.into(),
+                body: body.into(),
+                plugins: vec![],
+            }
+        }
+
+        pub fn with_plugins(mut self, plugins: Vec<OutdatedPlugin>) -> Self {
+            self.plugins = plugins;
+            self
+        }
+    }
+
+    impl Display for Notification {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "[{}]", self.title)?;
+            write!(f, "{}", self.body)
+        }
+    }
+
+    /// A destination that notifications can be sent to
+    #[async_trait]
+    pub trait NotificationSink: Send + Sync {
+        async fn send(&self, notification: &Notification) -> Result<()>;
+    }
+
+    /// Writes notifications to stderr so they don't mix with command output
+    pub struct ConsoleSink;
+
+    #[async_trait]
+    impl NotificationSink for ConsoleSink {
+        async fn send(&self, notification: &Notification) -> Result<()> {
+            eprintln!("{}", notification);
+            Ok(())
+        }
+    }
+
+    /// How a webhook expects notifications to be shaped
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum WebhookFormat {
+        /// The notification as is, ex: `{"title": "...", "body": "...", "plugins": [...]}`
+        Json,
+        /// A Discord embed, with a field per plugin
+        Discord,
+    }
+
+    impl WebhookFormat {
+        /// Discord is recognized by its webhook URLs, anything else receives plain JSON
+        pub fn for_url(url: &str) -> WebhookFormat {
+            match url.contains("discord.com/api/webhooks") {
+                true => WebhookFormat::Discord,
+                false => WebhookFormat::Json,
+            }
+        }
+    }
+
+    /// POSTs notifications to a webhook URL
+    pub struct WebhookSink {
+        client: Client,
+        url: String,
+        format: WebhookFormat,
+    }
+
+    impl WebhookSink {
+        /// Discord allows no more fields than this in an embed
+        const DISCORD_FIELDS: usize = 25;
+
+        pub fn new(client: Client, url: String, format: Option<WebhookFormat>) -> Self {
+            let format = format.unwrap_or(WebhookFormat::for_url(&url));
+            WebhookSink {
+                client,
+                url,
+                format,
+            }
+        }
+
+        fn payload(&self, notification: &Notification) -> Value {
+            match self.format {
+                WebhookFormat::Json => json!({
+                    "title": notification.title,
+                    "body": notification.body,
+                    "plugins": notification.plugins,
+                }),
+                WebhookFormat::Discord => {
+                    let fields = notification
+                        .plugins
+                        .iter()
+                        .take(Self::DISCORD_FIELDS)
+                        .map(|p| {
+                            json!({
+                                "name": p.id,
+                                "value": format!("{} -> {}", p.local_version, p.remote_version),
+                            })
+                        })
+                        .collect::<Vec<Value>>();
+                    json!({
+                        "embeds": [{
+                            "title": notification.title,
+                            "description": notification.body,
+                            "fields": fields,
+                        }]
+                    })
+                }
+            }
+        }
+    }
+
+    #[async_trait]
+    impl NotificationSink for WebhookSink {
+        async fn send(&self, notification: &Notification) -> Result<()> {
+            self.client
+                .post(&self.url)
+                .json(&self.payload(notification))
+                .send()
+                .await?
+                .error_for_status()?;
+            Ok(())
+        }
+    }
+
+    /// Dispatches notifications to every sink it holds
+    pub struct Notifier {
+        sinks: Vec<Box<dyn NotificationSink>>,
+    }
+
+    impl Default for Notifier {
+        fn default() -> Self {
+            Notifier {
+                sinks: vec![Box::new(ConsoleSink)],
+            }
+        }
+    }
+
+    impl Notifier {
+        /// Without the console, for commands that already print what they notify of
+        pub fn silent() -> Self {
+            Notifier { sinks: vec![] }
+        }
+
+        pub fn with_sink(mut self, sink: impl NotificationSink + 'static) -> Self {
+            self.sinks.push(Box::new(sink));
+            self
+        }
+
+        /// A failing sink is reported but does not stop the others from being notified
+        pub async fn notify(&self, notification: &Notification) {
+            for sink in &self.sinks {
+                if let Err(e) = sink.send(notification).await {
+                    error!("Failed to deliver notification : {}", e)
+                }
+            }
+        }
+    }
+}
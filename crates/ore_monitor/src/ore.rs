@@ -1,111 +1,851 @@
 pub mod ore_client {
+    use crate::{
+        clock::{Clock, SystemClock},
+        error::OreMonitorError,
+        project_index::max_age,
+        request_context::{RequestContext, RequestId},
+        response_cache::{Cache, Cached, ResponseCache},
+        retry::{retry_after, RetryPolicy},
+    };
     use anyhow::Result;
+    use chrono::{DateTime, NaiveDate, Utc};
+    use futures::{stream, StreamExt};
     use reqwest::{
         header::{self, AUTHORIZATION},
-        Client, RequestBuilder, Response, StatusCode,
+        multipart::Form,
+        Client, Method, RequestBuilder, Response, ResponseBuilderExt, StatusCode, Url,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::{
+        collections::BTreeMap,
+        fmt::Display,
+        io::Read,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, Instant},
+    };
+    use tokio::{
+        sync::mpsc::{self, Receiver},
+        task, time,
     };
-    use tokio_stream::StreamExt;
+    use tracing::{debug, info, trace, warn, Level};
 
-    use crate::sponge_schemas::OreSession;
+    use crate::{
+        query::Query,
+        sponge_schemas::{
+            EditableVersion, KeyPermissions, OreSession, PaginatedCompactProjectResult,
+            PaginatedProjectResult, PaginatedVersionResult, Project, ProjectMember,
+            ProjectStatsDay, User, Version, VersionStatsDay,
+        },
+    };
+
+    use super::{ore_auth::request_session, session_cache};
+
+    /// The main site, serving files the API has no links for
+    pub const ORE_SITE: &str = "https://ore.spongepowered.org";
+
+    /// Whether requests are printed before, or instead of, being sent
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub enum Explain {
+        #[default]
+        Off,
+        /// Requests are printed but never sent
+        Only,
+        /// Requests are printed and sent as usual
+        Run,
+    }
 
+    /// Whether API responses are served from the [ResponseCache]
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub enum CacheMode {
+        /// Every request is sent
+        #[default]
+        Off,
+        /// Responses that are fresh enough are served without sending the request, others are kept for the TTL
+        Read,
+        /// Every request is sent, and successful responses replace the cached ones
+        Refresh,
+        /// Nothing is sent, every response is served from the cache however old it is
+        Offline,
+    }
+
+    /// Returned in place of a response when a request was only explained
+    #[derive(Debug)]
+    pub struct Explained;
+
+    impl Display for Explained {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "Request not sent, combine --explain with --run to send it"
+            )
+        }
+    }
+
+    impl std::error::Error for Explained {}
+
+    /// A [Read] over the chunks of a body that are still being received
+    struct ChunkReader<B: AsRef<[u8]>> {
+        chunks: Receiver<B>,
+        current: Option<B>,
+        position: usize,
+    }
+
+    impl<B: AsRef<[u8]>> ChunkReader<B> {
+        fn new(chunks: Receiver<B>) -> Self {
+            ChunkReader {
+                chunks,
+                current: None,
+                position: 0,
+            }
+        }
+    }
+
+    impl<B: AsRef<[u8]>> Read for ChunkReader<B> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            loop {
+                if let Some(chunk) = &self.current {
+                    let remaining = &chunk.as_ref()[self.position..];
+                    if !remaining.is_empty() {
+                        let read = remaining.len().min(buf.len());
+                        buf[..read].copy_from_slice(&remaining[..read]);
+                        self.position += read;
+                        return Ok(read);
+                    }
+                }
+
+                // Blocks until the next chunk, the sender hanging up is the end of the body
+                match self.chunks.blocking_recv() {
+                    Some(chunk) => {
+                        self.current = Some(chunk);
+                        self.position = 0;
+                    }
+                    None => return Ok(0),
+                }
+            }
+        }
+    }
+
+    /// A client of the Ore v2 API, handing back the types of [sponge_schemas](crate::sponge_schemas)
+    /// It's created by authenticating with [OreAuth](super::ore_auth::OreAuth).
+    /// ```no_run
+    /// use ore_monitor::ore::ore_auth::{OreAuth, ORE_API};
+    ///
+    /// # async fn run() -> anyhow::Result<()> {
+    /// let client = OreAuth::new(Default::default(), ORE_API.to_string(), "api key".to_string())
+    ///     .auth()
+    ///     .await?;
+    /// let project = client.project("nucleus").await?;
+    /// println!("{}", project.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Without a session requests are only printed, nothing is sent
+    /// ```
+    /// use ore_monitor::ore::{ore_auth::ORE_API, ore_client::{Explained, OreClient}};
+    /// use ore_monitor::query::Query;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let client = OreClient::unauthenticated(ORE_API.to_string());
+    /// let query = Query::new(vec![("limit".to_string(), "1".to_string())]);
+    /// let err = client.versions("nucleus", query).await.err().unwrap();
+    /// assert!(err.is::<Explained>());
+    /// # });
+    /// ```
     #[derive(Debug)]
     pub struct OreClient {
         client: Client,
-        session: OreSession,
+        session: Mutex<OreSession>,
+        /// Used to replace the session when Ore no longer accepts it
+        api_key: Option<String>,
         base_url: String,
+        /// Where files are downloaded from, see [download](Self::download)
+        site: String,
+        explain: Explain,
+        concurrency: usize,
+        responses: Mutex<Box<dyn Cache>>,
+        cache_mode: CacheMode,
+        cache_max_age: Duration,
+        /// How long responses are kept when reading the cache
+        cache_ttl: Duration,
+        /// Responses were kept since the cache was last saved
+        unsaved: AtomicBool,
+        /// When the oldest response served offline was fetched
+        oldest_served: Mutex<Option<DateTime<Utc>>>,
+        retry: RetryPolicy,
+        /// The context of the last request sent, saved for the debug bundle once the client is dropped
+        last_request: Mutex<Option<RequestContext>>,
+        /// Ages cached responses and times retries, commands tell the time by it too
+        clock: Arc<dyn Clock>,
     }
 
     impl OreClient {
+        /// How many requests bulk operations keep in flight when not configured
+        pub const DEFAULT_CONCURRENCY: usize = 8;
+        /// How many chunks of a body are received ahead of the deserializer
+        const CHUNKS_AHEAD: usize = 16;
+        /// The header a request's [RequestId] is sent in
+        const REQUEST_ID: &'static str = "X-Request-Id";
+
         pub async fn new(client: Client, session: OreSession, base_url: String) -> Self {
             OreClient {
                 client,
-                session,
+                session: Mutex::new(session),
+                api_key: None,
+                base_url,
+                site: ORE_SITE.to_string(),
+                explain: Explain::Off,
+                concurrency: Self::DEFAULT_CONCURRENCY,
+                responses: Mutex::new(Box::<ResponseCache>::default()),
+                cache_mode: CacheMode::Off,
+                cache_max_age: ResponseCache::DEFAULT_MAX_AGE,
+                cache_ttl: ResponseCache::DEFAULT_TTL,
+                unsaved: AtomicBool::new(false),
+                oldest_served: Mutex::new(None),
+                retry: RetryPolicy::default(),
+                last_request: Mutex::new(None),
+                clock: Arc::new(SystemClock),
+            }
+        }
+
+        /// The [fingerprint](OreSession::fingerprint) of the current session
+        pub fn session_fingerprint(&self) -> Option<String> {
+            self.session
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .fingerprint()
+        }
+
+        /// A client without a session, for explaining requests without sending them
+        pub fn unauthenticated(base_url: String) -> Self {
+            OreClient {
+                client: Default::default(),
+                session: Default::default(),
+                api_key: None,
                 base_url,
+                site: ORE_SITE.to_string(),
+                explain: Explain::Only,
+                concurrency: Self::DEFAULT_CONCURRENCY,
+                responses: Mutex::new(Box::<ResponseCache>::default()),
+                cache_mode: CacheMode::Off,
+                cache_max_age: ResponseCache::DEFAULT_MAX_AGE,
+                cache_ttl: ResponseCache::DEFAULT_TTL,
+                unsaved: AtomicBool::new(false),
+                oldest_served: Mutex::new(None),
+                retry: RetryPolicy::default(),
+                last_request: Mutex::new(None),
+                clock: Arc::new(SystemClock),
+            }
+        }
+
+        /// Allows re-authenticating once the session expires
+        pub fn with_api_key(mut self, api_key: String) -> Self {
+            self.api_key = Some(api_key);
+            self
+        }
+
+        /// Downloads files from another site than [ORE_SITE], such as a mock server standing in for Ore
+        pub fn with_site(mut self, site: String) -> Self {
+            self.site = site;
+            self
+        }
+
+        pub fn with_explain(mut self, explain: Explain) -> Self {
+            self.explain = explain;
+            self
+        }
+
+        /// Limits how many requests bulk operations send at once, at least one is always sent
+        pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+            self.concurrency = concurrency.max(1);
+            self
+        }
+
+        pub fn concurrency(&self) -> usize {
+            self.concurrency
+        }
+
+        /// Tells the time by another clock than the system's, ex: a [FixedClock](crate::clock::FixedClock) in tests
+        pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+            self.clock = clock;
+            self
+        }
+
+        /// The clock the client tells the time by, for commands to agree with it
+        pub fn clock(&self) -> &dyn Clock {
+            self.clock.as_ref()
+        }
+
+        /// How GET requests are retried when Ore is rate limiting, erroring or times out
+        pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+            self.retry = retry;
+            self
+        }
+
+        /// Serves or refreshes responses of the persisted [ResponseCache], see [with_cache](Self::with_cache)
+        pub fn with_response_cache(self, mode: CacheMode, max_age: Duration) -> Self {
+            match mode {
+                CacheMode::Off => self,
+                _ => self.with_cache(mode, max_age, ResponseCache::load()),
+            }
+        }
+
+        /// Serves or refreshes responses of the cache, responses older than the max age are never served
+        /// ```
+        /// use chrono::Utc;
+        /// use ore_monitor::ore::ore_client::{CacheMode, OreClient};
+        /// use ore_monitor::response_cache::{Cache, ResponseCache};
+        /// use std::time::Duration;
+        ///
+        /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+        /// let mut cache = ResponseCache::default();
+        /// let url = "https://ore/api/v2/projects/nucleus";
+        /// cache.insert(url, url, "{}".to_string(), Utc::now(), None);
+        ///
+        /// let client = OreClient::new(Default::default(), Default::default(), "https://ore/api/v2".to_string())
+        ///     .await
+        ///     .with_cache(CacheMode::Read, Duration::from_secs(60), cache);
+        /// let res = client.get("/projects/nucleus".to_string(), None).await.unwrap();
+        /// assert_eq!(res.text().await.unwrap(), "{}");
+        /// # });
+        /// ```
+        pub fn with_cache(
+            mut self,
+            mode: CacheMode,
+            max_age: Duration,
+            cache: impl Cache + 'static,
+        ) -> Self {
+            self.responses = Mutex::new(Box::new(cache));
+            self.cache_mode = mode;
+            self.cache_max_age = max_age;
+            self
+        }
+
+        pub fn is_offline(&self) -> bool {
+            self.cache_mode == CacheMode::Offline
+        }
+
+        /// When the oldest response served offline was fetched, how old the data it served is
+        pub fn offline_since(&self) -> Option<DateTime<Utc>> {
+            *self.oldest_served.lock().unwrap_or_else(|e| e.into_inner())
+        }
+
+        /// How long responses are kept when reading the cache, zero keeps none
+        pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+            self.cache_ttl = ttl;
+            self
+        }
+
+        /// Saves the kept responses, returning how many are cached
+        pub fn save_responses(&self) -> Result<usize> {
+            let mut responses = self
+                .responses
+                .lock()
+                .map_err(|_| anyhow::Error::msg("Response cache lock poisoned"))?;
+            responses.save(self.clock.now(), self.cache_max_age)?;
+            self.unsaved.store(false, Ordering::Relaxed);
+            Ok(responses.len())
+        }
+
+        /// A response built from the cached body, when reading the cache and it's fresh enough
+        /// Explained requests are always printed, so they're never served from the cache.
+        fn cached(&self, key: &str) -> Option<Response> {
+            if self.cache_mode == CacheMode::Offline {
+                let responses = self.responses.lock().ok()?;
+                let cached = responses.last_known(key)?;
+                let fetched = cached.fetched;
+                let mut oldest = self.oldest_served.lock().ok()?;
+                *oldest = Some(oldest.map_or(fetched, |oldest| oldest.min(fetched)));
+                debug!("{} served from the cache, fetched {}", key, fetched);
+                return Self::rebuild(cached);
+            }
+            if self.cache_mode != CacheMode::Read || self.explain != Explain::Off {
+                return None;
             }
+            let responses = self.responses.lock().ok()?;
+            let cached = responses.get(key, self.clock.now(), self.cache_max_age)?;
+            debug!("{} served from the cache", key);
+            Self::rebuild(cached)
+        }
+
+        /// A response of the cached body, from the URL it was requested from
+        fn rebuild(cached: Cached) -> Option<Response> {
+            let res = http::Response::builder()
+                .url(Url::parse(cached.url).ok()?)
+                .body(cached.body.to_string())
+                .ok()?;
+            Some(res.into())
+        }
+
+        /// Caches the body of a successful response, handing back an identical response
+        /// Refreshed responses are kept for the max age, those of lookups reading the cache only for the TTL.
+        async fn remember(&self, key: &str, res: Response) -> Result<Response> {
+            let ttl = match self.cache_mode {
+                CacheMode::Refresh => None,
+                CacheMode::Read if !self.cache_ttl.is_zero() => Some(self.cache_ttl),
+                _ => return Ok(res),
+            };
+            if res.status() != StatusCode::OK {
+                return Ok(res);
+            }
+
+            let request = res.extensions().get::<RequestId>().copied();
+            let url = res.url().clone();
+            let body = res.text().await?;
+            if let Ok(mut responses) = self.responses.lock() {
+                responses.insert(key, url.as_str(), body.to_string(), self.clock.now(), ttl);
+                self.unsaved.store(true, Ordering::Relaxed);
+            }
+            let mut res: Response = http::Response::builder().url(url).body(body)?.into();
+            if let Some(request) = request {
+                res.extensions_mut().insert(request);
+            }
+            Ok(res)
         }
 
-        fn log_errors(&self, code: StatusCode) {
-            let msg = match code {
-                // No Content is actually a "successful" error
-                StatusCode::NO_CONTENT => None, //Some("Session Invalidated"),
-                StatusCode::OK => None,
-                StatusCode::BAD_REQUEST => Some("Request not made with a session"),
-                StatusCode::UNAUTHORIZED => Some("Api session missing, invalid, or expired"),
-                StatusCode::FORBIDDEN => Some("Not enough permission for endpoint"),
-                StatusCode::NOT_FOUND => {
-                    Some("Resource not found! Ensure you've used the correct identifiers")
+        /// The underlying client, for reusing its connections with other hosts
+        pub fn http(&self) -> &Client {
+            &self.client
+        }
+
+        // Sends the request, printing it to stderr first when explaining
+        async fn send(&self, builder: RequestBuilder) -> Result<Response> {
+            let mut request = builder.build().map_err(Self::unreachable)?;
+            if self.cache_mode == CacheMode::Offline {
+                return Err(anyhow::Error::msg(format!(
+                    "{} isn't cached, run `prefetch` while Ore can be reached",
+                    request.url().path()
+                )));
+            }
+
+            if self.explain != Explain::Off {
+                let mut url = request.url().clone();
+                let query = url
+                    .query_pairs()
+                    .map(|(k, v)| format!("    {} = {}", k, v))
+                    .collect::<Vec<String>>();
+                url.set_query(None);
+
+                eprintln!("{} {}", request.method(), url);
+                query.iter().for_each(|q| eprintln!("{}", q));
+            }
+
+            if self.explain == Explain::Only {
+                return Err(Explained.into());
+            }
+
+            let id = RequestId::generate();
+            request
+                .headers_mut()
+                .insert(Self::REQUEST_ID, id.to_string().parse()?);
+            let method = request.method().to_string();
+            let endpoint = request.url().path().to_string();
+            trace!("request {} {} {}", id, method, request.url());
+            let sent = self.clock.now();
+            let started = Instant::now();
+
+            // Bodies that are streamed, such as uploads, can't be sent a second time
+            let retry = request.try_clone();
+            let res = self.client.execute(request).await;
+
+            let res = match (res, retry, &self.api_key) {
+                (Ok(res), Some(mut retry), Some(api_key))
+                    if res.status() == StatusCode::UNAUTHORIZED =>
+                {
+                    self.reauthenticate(api_key).await?;
+                    let value = self.header_value().parse()?;
+                    retry.headers_mut().insert(AUTHORIZATION, value);
+                    let value = self.header_value().parse()?;
+                    retry.headers_mut().insert(header::WWW_AUTHENTICATE, value);
+                    self.client.execute(retry).await
                 }
-                _ => Some("Unexpected Status Code"),
+                (res, _, _) => res,
+            };
+
+            let context = RequestContext {
+                id,
+                method,
+                endpoint,
+                sent,
+                elapsed: started.elapsed(),
+                status: res.as_ref().ok().map(|res| res.status().as_u16()),
+                error: res.as_ref().err().map(|e| e.to_string()),
             };
-            if let Some(m) = msg {
-                println!("Status Error : {}", m)
+            if tracing::enabled!(Level::TRACE) {
+                let outcome = match &res {
+                    Ok(res) => res.status().to_string(),
+                    Err(e) => e.to_string(),
+                };
+                trace!(
+                    "request {} {} in {}ms",
+                    id,
+                    outcome,
+                    context.elapsed.as_millis()
+                );
+            }
+            self.remember_request(context);
+
+            let mut res = res.map_err(|e| Self::unreachable(e).with_request(id))?;
+            res.extensions_mut().insert(id);
+            Ok(res)
+        }
+
+        /// Keeps the context of the request for the debug bundle, replacing that of the previous one
+        fn remember_request(&self, context: RequestContext) {
+            *self.last_request.lock().unwrap_or_else(|e| e.into_inner()) = Some(context);
+        }
+
+        /// Saves the context of the last request sent for the debug bundle, if any was sent
+        /// Failing to save it only loses the debug bundle's context, so it's only reported when debugging.
+        fn save_last_request(&self) {
+            let last_request = self.last_request.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(Err(e)) = last_request.as_ref().map(RequestContext::save) {
+                debug!("Unable to keep the request for the debug bundle : {}", e)
+            }
+        }
+
+        /// Replaces the session that Ore rejected with a new one
+        async fn reauthenticate(&self, api_key: &str) -> Result<()> {
+            let session = request_session(&self.client, &self.base_url, api_key).await?;
+            if let Err(e) = session_cache::save(&self.base_url, &session) {
+                warn!("Unable to cache the session : {}", e)
+            }
+            self.session
+                .lock()
+                .map_err(|_| anyhow::Error::msg("Session lock poisoned"))?
+                .update(session);
+            Ok(())
+        }
+
+        fn header_value(&self) -> String {
+            self.session
+                .lock()
+                .map(|session| session.header_value())
+                .unwrap_or_default()
+        }
+
+        /// Turns an error status into an [OreMonitorError], with the endpoint it was sent to
+        pub(super) fn check(method: Method, res: Response) -> Result<Response, OreMonitorError> {
+            let status = res.status();
+            if status.is_client_error() || status.is_server_error() {
+                return Err(OreMonitorError::Status {
+                    request: res.extensions().get::<RequestId>().copied(),
+                    method,
+                    endpoint: res.url().path().to_string(),
+                    status,
+                });
+            }
+            Ok(res)
+        }
+
+        /// Keeps the endpoint of a request that couldn't be sent or received
+        pub(super) fn unreachable(source: reqwest::Error) -> OreMonitorError {
+            let endpoint = source
+                .url()
+                .map(|url| url.path().to_string())
+                .unwrap_or_default();
+            OreMonitorError::Http {
+                request: None,
+                endpoint,
+                source,
             }
         }
 
         // Applies auth headers
         fn apply_headers(&self, builder: RequestBuilder) -> RequestBuilder {
             builder
-                .header(
-                    reqwest::header::WWW_AUTHENTICATE,
-                    self.session.header_value(),
-                )
-                .header(AUTHORIZATION, self.session.header_value())
+                .header(reqwest::header::WWW_AUTHENTICATE, self.header_value())
+                .header(AUTHORIZATION, self.header_value())
                 .header(header::ACCEPT, "application/json")
                 .header("User-Agent", "Ore-Monitor")
         }
 
-        // Invalidates the current session
-        async fn _invalidate(&self) -> Result<()> {
-            let builder = self
-                .client
-                .delete(format!("{}/sessions/current", self.base_url));
-            let res = self.apply_headers(builder).send().await?;
-            self.log_errors(res.status());
+        /// Searches the projects, ex: by `q`, `categories`, `owner` or `sort`
+        pub async fn projects(&self, query: Query) -> Result<PaginatedProjectResult> {
+            self.fetch("/projects".to_string(), Some(query)).await
+        }
+
+        /// A page of projects, along with how long Ore allows it to be reused by its `Cache-Control`
+        pub async fn projects_page(
+            &self,
+            query: Query,
+        ) -> Result<(PaginatedProjectResult, Option<Duration>)> {
+            let res = self
+                .get("/projects".to_string(), Some(query.to_vec()))
+                .await?;
+            let res = Self::check(Method::GET, res)?;
+            let max_age = res
+                .headers()
+                .get(header::CACHE_CONTROL)
+                .and_then(|value| value.to_str().ok())
+                .and_then(max_age);
+            Ok((Self::json(res).await?, max_age))
+        }
+
+        pub async fn project(&self, plugin_id: &str) -> Result<Project> {
+            self.fetch(format!("/projects/{}", plugin_id), None).await
+        }
+
+        /// The project, or [None] when Ore has no project under the id
+        pub async fn find_project(&self, plugin_id: &str) -> Result<Option<Project>> {
+            let res = self.get(format!("/projects/{}", plugin_id), None).await?;
+            if res.status() == StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            Ok(Some(Self::json(Self::check(Method::GET, res)?).await?))
+        }
+
+        /// The users of the project, with their roles in it
+        pub async fn members(&self, plugin_id: &str) -> Result<Vec<ProjectMember>> {
+            self.fetch(format!("/projects/{}/members", plugin_id), None)
+                .await
+        }
+
+        /// The stats of each day of the project between `fromDate` and `toDate`, keyed by the day
+        pub async fn project_stats(
+            &self,
+            plugin_id: &str,
+            query: Query,
+        ) -> Result<BTreeMap<NaiveDate, ProjectStatsDay>> {
+            self.fetch(format!("/projects/{}/stats", plugin_id), Some(query))
+                .await
+        }
+
+        /// Retrieves the project of each id, a failed request doesn't stop the others
+        /// Requests are sent concurrently, each result is returned alongside the id it was requested for
+        pub async fn projects_by_id(&self, ids: Vec<String>) -> Vec<(String, Result<Project>)> {
+            stream::iter(ids)
+                .map(|id| async {
+                    let res = self.project(&id).await;
+                    (id, res)
+                })
+                .buffered(self.concurrency)
+                .collect()
+                .await
+        }
+
+        /// A page of the project's versions, ex: by `tags`, `limit` or `offset`
+        pub async fn versions(
+            &self,
+            plugin_id: &str,
+            query: Query,
+        ) -> Result<PaginatedVersionResult> {
+            let link = format!("/projects/{}/versions", plugin_id);
+            self.fetch(link, Some(query)).await
+        }
+
+        /// A single version, including the md5 of its file
+        pub async fn version(&self, plugin_id: &str, version: &str) -> Result<Version> {
+            let link = format!("/projects/{}/versions/{}", plugin_id, version);
+            self.fetch(link, None).await
+        }
+
+        /// The downloads of each day of the version between `fromDate` and `toDate`, keyed by the day
+        pub async fn version_stats(
+            &self,
+            plugin_id: &str,
+            version: &str,
+            query: Query,
+        ) -> Result<BTreeMap<NaiveDate, VersionStatsDay>> {
+            let link = format!("/projects/{}/versions/{}/stats", plugin_id, version);
+            self.fetch(link, Some(query)).await
+        }
+
+        /// Uploads a new version, the form holds the plugin file and its [DeployVersionInfo](crate::sponge_schemas::DeployVersionInfo)
+        pub async fn publish_version(&self, plugin_id: &str, form: Form) -> Result<Version> {
+            let link = format!("/projects/{}/versions", plugin_id);
+            let res = Self::check(Method::POST, self.post_multipart(link, form).await?)?;
+            Self::json(res).await
+        }
+
+        pub async fn edit_version(
+            &self,
+            plugin_id: &str,
+            version: &str,
+            edit: &EditableVersion,
+        ) -> Result<Version> {
+            let link = format!("/projects/{}/versions/{}", plugin_id, version);
+            let res = Self::check(Method::PATCH, self.patch_json(link, edit).await?)?;
+            Self::json(res).await
+        }
+
+        /// What the session is permitted to do, ex: within a project by `pluginId`
+        pub async fn permissions(&self, query: Query) -> Result<KeyPermissions> {
+            self.fetch("/permissions".to_string(), Some(query)).await
+        }
+
+        pub async fn star(&self, plugin_id: &str) -> Result<()> {
+            let link = format!("/projects/{}/_star", plugin_id);
+            Self::check(Method::POST, self.post(link, None).await?)?;
+            Ok(())
+        }
+
+        pub async fn watch(&self, plugin_id: &str) -> Result<()> {
+            let link = format!("/projects/{}/_watch", plugin_id);
+            Self::check(Method::POST, self.post(link, None).await?)?;
             Ok(())
         }
 
-        pub async fn get_install(
+        pub async fn user(&self, user: &str) -> Result<User> {
+            self.fetch(format!("/users/{}", user), None).await
+        }
+
+        /// The projects a user is a member of, ex: by `sort`, `limit` or `offset`
+        pub async fn user_projects(
+            &self,
+            user: &str,
+            query: Query,
+        ) -> Result<PaginatedCompactProjectResult> {
+            self.fetch(format!("/users/{}/projects", user), Some(query))
+                .await
+        }
+
+        /// Requests the file of a version, the body is left to be streamed by the caller
+        /// The API has no download link, so it's downloaded from the main site the same way users would.
+        pub async fn download(&self, project: &Project, version: &str) -> Result<Response> {
+            let res = self
+                .common_get(self.download_url(project, version), None)
+                .await?;
+            Ok(Self::check(Method::GET, res)?)
+        }
+
+        /// Where the file of a version is downloaded from
+        pub fn download_url(&self, project: &Project, version: &str) -> String {
+            format!(
+                "{}/{}/{}/versions/{}/download",
+                self.site, project.namespace.owner, project.namespace.slug, version
+            )
+        }
+
+        /// Sends a GET request, deserializing the body of a successful response
+        async fn fetch<T: DeserializeOwned + Send + 'static>(
+            &self,
+            link: String,
+            query: Option<Query>,
+        ) -> Result<T> {
+            let res = self.get(link, query.map(|query| query.to_vec())).await?;
+            Self::json(Self::check(Method::GET, res)?).await
+        }
+
+        /// Deserializes the body as it arrives, instead of buffering all of it first
+        /// Chunks are handed to a blocking thread that reads them as a single stream,
+        /// only a few are received ahead of it, so large bodies are never held whole.
+        pub async fn json<T: DeserializeOwned + Send + 'static>(mut res: Response) -> Result<T> {
+            let endpoint = res.url().path().to_string();
+            let request = res.extensions().get::<RequestId>().copied();
+            let (sender, receiver) = mpsc::channel(Self::CHUNKS_AHEAD);
+            let reader = task::spawn_blocking(move || {
+                serde_json::from_reader::<_, T>(ChunkReader::new(receiver))
+            });
+
+            // The start of the body is kept, showing what was received when it's not what was expected
+            let mut start = vec![];
+            while let Some(chunk) = res.chunk().await.map_err(Self::unreachable)? {
+                let kept = chunk.len().min(OreMonitorError::SNIPPET_LEN - start.len());
+                start.extend_from_slice(&chunk[..kept]);
+                // The reader only hangs up early when the JSON is already invalid
+                if sender.send(chunk).await.is_err() {
+                    break;
+                }
+            }
+            drop(sender);
+
+            reader.await?.map_err(|source| {
+                OreMonitorError::Deserialize {
+                    request,
+                    endpoint,
+                    snippet: OreMonitorError::snippet(&start),
+                    source,
+                }
+                .into()
+            })
+        }
+
+        pub async fn get(
             &self,
             url: String,
             query: Option<Vec<(String, String)>>,
         ) -> Result<Response> {
-            let url = "https://ore.spongepowered.org".to_string() + &url;
+            let url = self.base_url.to_string() + &url;
+            let key = ResponseCache::key(&url, query.as_deref().unwrap_or_default());
+            if let Some(res) = self.cached(&key) {
+                return Ok(res);
+            }
 
             let res = self.common_get(url, query).await?;
-            // Since this request is not made with the API
-            // There is no need to invalidate the request
-            // self.invalidate().await?;
-            Ok(res)
+            self.remember(&key, res).await
         }
 
-        pub async fn plugin_responses(&self, id: Vec<String>) -> Result<Vec<String>> {
-            let link = id
-                .iter()
-                .map(|f| format!("/projects/{}", f))
-                .collect::<Vec<String>>();
+        /// A request to an endpoint of the API, with the auth headers and the query applied
+        fn request(
+            &self,
+            method: Method,
+            url: String,
+            query: Option<Vec<(String, String)>>,
+        ) -> RequestBuilder {
+            let builder = self
+                .client
+                .request(method, self.base_url.to_string() + &url);
+            let builder = self.apply_headers(builder);
 
-            let mut iter = tokio_stream::iter(link);
+            match &query {
+                Some(query) => builder.query(query),
+                None => builder,
+            }
+        }
 
-            let mut res: Vec<String> = vec![];
+        pub async fn post(
+            &self,
+            url: String,
+            query: Option<Vec<(String, String)>>,
+        ) -> Result<Response> {
+            self.send(self.request(Method::POST, url, query)).await
+        }
 
-            while let Some(v) = iter.next().await {
-                let f = self.get(v, None).await?.text().await?;
+        /// Sends a POST request with the body serialized as JSON
+        pub async fn post_json<T: Serialize + ?Sized>(
+            &self,
+            url: String,
+            query: Option<Vec<(String, String)>>,
+            body: &T,
+        ) -> Result<Response> {
+            self.send(self.request(Method::POST, url, query).json(body))
+                .await
+        }
 
-                res.push(f)
-            }
+        pub async fn post_multipart(&self, url: String, form: Form) -> Result<Response> {
+            self.send(self.request(Method::POST, url, None).multipart(form))
+                .await
+        }
 
-            Ok(res)
+        /// Sends a PUT request with the body serialized as JSON
+        pub async fn put_json<T: Serialize + ?Sized>(
+            &self,
+            url: String,
+            query: Option<Vec<(String, String)>>,
+            body: &T,
+        ) -> Result<Response> {
+            self.send(self.request(Method::PUT, url, query).json(body))
+                .await
         }
 
-        pub async fn get(
+        pub async fn patch_json<T: Serialize + ?Sized>(
+            &self,
+            url: String,
+            body: &T,
+        ) -> Result<Response> {
+            self.send(self.request(Method::PATCH, url, None).json(body))
+                .await
+        }
+
+        pub async fn delete(
             &self,
             url: String,
             query: Option<Vec<(String, String)>>,
         ) -> Result<Response> {
-            let url = self.base_url.to_string() + &url;
-            let res = self.common_get(url, query).await?;
-            self.log_errors(res.status());
-            Ok(res)
+            self.send(self.request(Method::DELETE, url, query)).await
         }
 
         // This only exists as a workaround for installs
@@ -123,8 +863,63 @@ pub mod ore_client {
                 builder
             };
 
-            let res = builder.send().await?;
-            Ok(res)
+            self.send_with_retry(builder).await
+        }
+
+        /// Sends the request until it succeeds or runs out of attempts, see [RetryPolicy]
+        /// The last response or error is handed back as is once the attempts run out.
+        async fn send_with_retry(&self, builder: RequestBuilder) -> Result<Response> {
+            let mut attempt = 1;
+            loop {
+                let retry = builder
+                    .try_clone()
+                    .filter(|_| attempt < self.retry.max_attempts);
+                let Some(retry) = retry else {
+                    return self.send(builder).await;
+                };
+
+                let (reason, retry_after) = match self.send(retry).await {
+                    Ok(res) if RetryPolicy::retries_status(res.status()) => {
+                        let retry_after = res
+                            .headers()
+                            .get(header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok())
+                            .and_then(|value| retry_after(value, self.clock.now()));
+                        (res.status().to_string(), retry_after)
+                    }
+                    Err(e)
+                        if matches!(
+                            e.downcast_ref(),
+                            Some(OreMonitorError::Http { source, .. })
+                                if RetryPolicy::retries_error(source)
+                        ) =>
+                    {
+                        (e.to_string(), None)
+                    }
+                    res => return res,
+                };
+
+                let delay = self.retry.delay(attempt, retry_after);
+                info!(
+                    "Retrying in {:?} ({}/{}) : {}",
+                    delay, attempt, self.retry.max_attempts, reason
+                );
+                time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+
+    /// Lookups keep responses and the last request as they go, so they're saved once the client is dropped
+    /// Commands drop the client once they finish, whether they succeed or fail.
+    impl Drop for OreClient {
+        fn drop(&mut self) {
+            self.save_last_request();
+            if self.unsaved.load(Ordering::Relaxed) {
+                if let Err(e) = self.save_responses() {
+                    warn!("Unable to save the response cache : {}", e)
+                }
+            }
         }
     }
 }
@@ -142,17 +937,104 @@ mod ore_session {
         pub fn header_value(&self) -> String {
             format!("OreApi session={}", self.session)
         }
+
+        /// Identifies the session without revealing it, none without a session
+        pub fn fingerprint(&self) -> Option<String> {
+            use sha2::{Digest, Sha256};
+
+            if self.session.is_empty() {
+                return None;
+            }
+            let hash = format!("{:x}", Sha256::digest(self.session.as_bytes()));
+            Some(hash[..16].to_string())
+        }
+    }
+}
+
+/// Keeps the session between runs, so each command doesn't have to authenticate again
+pub mod session_cache {
+    use crate::{clock::Clock, instance_lock::write_private};
+    use anyhow::Result;
+    use chrono::Duration;
+    use serde::{Deserialize, Serialize};
+    use std::{fs, path::PathBuf};
+
+    use crate::sponge_schemas::OreSession;
+
+    #[derive(Serialize, Deserialize)]
+    struct CachedSession {
+        base_url: String,
+        session: OreSession,
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("ore-monitor").join("session.json"))
+    }
+
+    /// The cached session of the API, when it's not about to expire
+    pub fn load(base_url: &str, clock: &dyn Clock) -> Option<OreSession> {
+        let json = fs::read_to_string(path()?).ok()?;
+        let cached: CachedSession = serde_json::from_str(&json).ok()?;
+
+        (is_fresh(&cached.session, clock) && cached.base_url == base_url).then_some(cached.session)
+    }
+
+    /// A session expiring mid command would fail it, so those are replaced early
+    fn is_fresh(session: &OreSession, clock: &dyn Clock) -> bool {
+        session.expires > clock.now() + Duration::minutes(1)
+    }
+
+    pub fn save(base_url: &str, session: &OreSession) -> Result<()> {
+        let Some(path) = path() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let cached = CachedSession {
+            base_url: base_url.to_string(),
+            session: OreSession {
+                session: session.session.to_string(),
+                expires: session.expires,
+            },
+        };
+        // The session authenticates as its key until it expires, so it's kept from other users
+        write_private(&path, serde_json::to_string(&cached)?)
     }
 }
 
 pub mod ore_auth {
+    use crate::clock::{Clock, SystemClock};
     use anyhow::Result;
-    use reqwest::Response;
-    use std::env;
+    use reqwest::{Client, Method};
+    use std::sync::Arc;
+    use tracing::warn;
 
     use crate::sponge_schemas::OreSession;
 
-    use super::ore_client::OreClient;
+    use super::{ore_client::OreClient, session_cache};
+
+    /// Requests a new session for the api key
+    pub async fn request_session(
+        client: &Client,
+        base_url: &str,
+        api_key: &str,
+    ) -> Result<OreSession> {
+        let res = client
+            .post(format!("{}/authenticate", base_url))
+            .header(
+                reqwest::header::WWW_AUTHENTICATE,
+                format!("OreApi apikey={}", api_key),
+            )
+            .send()
+            .await
+            .map_err(OreClient::unreachable)?;
+        OreClient::json(OreClient::check(Method::POST, res)?).await
+    }
+
+    /// Root of the Ore v2 API
+    pub const ORE_API: &str = "https://ore.spongepowered.org/api/v2";
 
     #[derive(Debug)]
     pub struct OreAuth {
@@ -160,42 +1042,49 @@ pub mod ore_auth {
         ore_session: OreSession,
         base_url: String,
         api_key: String,
+        /// Tells whether the cached session expired, then is handed to the [OreClient]
+        clock: Arc<dyn Clock>,
     }
 
-    impl Default for OreAuth {
-        fn default() -> Self {
+    /// Handles auth for Ore
+    impl OreAuth {
+        /// The client is used to authenticate, and is then kept by the [OreClient]
+        pub fn new(client: Client, base_url: String, api_key: String) -> Self {
             OreAuth {
-                client: Default::default(),
+                client,
                 ore_session: Default::default(),
-                base_url: "https://ore.spongepowered.org/api/v2".to_string(),
-                api_key: env::var("ORE_API_KEY").expect("ENV_VAR 'ORE_API_KEY' required"),
+                base_url,
+                api_key,
+                clock: Arc::new(SystemClock),
             }
         }
-    }
 
-    /// Handles auth for Ore
-    impl OreAuth {
+        pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+            self.clock = clock;
+            self
+        }
+
         /// Main method for authorizing, This is also how the [OreClient] is created
+        /// A cached session is reused while it's valid
         pub async fn auth(mut self) -> Result<OreClient> {
-            let res = self.send_request().await;
-            let res = res?.text().await?;
-            let res: OreSession = serde_json::from_str(&res)?;
+            let res = match session_cache::load(&self.base_url, self.clock.as_ref()) {
+                Some(session) => session,
+                None => {
+                    let session =
+                        request_session(&self.client, &self.base_url, &self.api_key).await?;
+                    // Failing to cache only costs authenticating again next time
+                    if let Err(e) = session_cache::save(&self.base_url, &session) {
+                        warn!("Unable to cache the session : {}", e)
+                    }
+                    session
+                }
+            };
             self.ore_session.update(res);
 
-            Ok(OreClient::new(self.client, self.ore_session, self.base_url).await)
-        }
-
-        /// Send request for authentication
-        async fn send_request(&self) -> Result<Response> {
-            Ok(self
-                .client
-                .post(format!("{}/authenticate", self.base_url))
-                .header(
-                    reqwest::header::WWW_AUTHENTICATE,
-                    format!("OreApi apikey={}", self.api_key),
-                )
-                .send()
-                .await?)
+            Ok(OreClient::new(self.client, self.ore_session, self.base_url)
+                .await
+                .with_api_key(self.api_key)
+                .with_clock(self.clock))
         }
     }
 }
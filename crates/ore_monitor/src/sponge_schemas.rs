@@ -1,63 +1,118 @@
+use crate::{
+    clock::ago,
+    one_or_many::OneOrMany,
+    ore_mod_info::PluginDependency,
+    query::QueryValue,
+    table::{Align, Table},
+};
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
 use human_bytes::human_bytes;
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, ops::Deref};
+use std::{collections::HashMap, fmt::Display, ops::Deref};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Project {
-    created_at: DateTime<Utc>,
-    plugin_id: String,
-    name: String,
+    pub created_at: DateTime<Utc>,
+    pub plugin_id: String,
+    pub name: String,
     pub namespace: ProjectNamespace,
     pub promoted_versions: Vec<PromotedVersion>,
-    stats: ProjectStatsAll,
-    category: Category,
+    pub stats: ProjectStatsAll,
+    pub category: Category,
     description: String,
-    last_updated: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
     visibility: String,
-    user_actions: UserActions,
-    settings: ProjectSettings,
+    pub user_actions: UserActions,
+    pub settings: ProjectSettings,
     icon_url: String,
 }
 
 impl Project {
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
     pub fn version_from_tag(&self, major_version: u32) -> &str {
-        let available_tags: Vec<_> = self
+        self.version_in_channel(major_version, None)
+    }
+
+    /// The promoted version for the Sponge API major version, no less stable than the channel if given
+    pub fn version_in_channel(&self, major_version: u32, channel: Option<ReleaseChannel>) -> &str {
+        self.promoted_in_channel(major_version, channel)
+            .map(|promoted| promoted.version.as_str())
+            .unwrap_or_default()
+    }
+
+    /// The first promoted version tagged for the Sponge API major version
+    pub fn promoted_for(&self, major_version: u32) -> Option<&PromotedVersion> {
+        self.promoted_in_channel(major_version, None)
+    }
+
+    /// The first promoted version tagged for the Sponge API major version, skipping those less stable than the channel
+    pub fn promoted_in_channel(
+        &self,
+        major_version: u32,
+        channel: Option<ReleaseChannel>,
+    ) -> Option<&PromotedVersion> {
+        self.promoted_versions.iter().find(|promoted| {
+            promoted.sponge_major() == major_version
+                && channel.is_none_or(|channel| promoted.channel() <= channel)
+        })
+    }
+
+    /// The Minecraft versions the promoted versions are tagged for
+    pub fn minecraft_versions(&self) -> impl Iterator<Item = &str> {
+        self.promoted_versions
+            .iter()
+            .flat_map(|promoted| &promoted.tags)
+            .filter_map(|tag| tag.minecraft_version.as_deref())
+    }
+
+    /// The Sponge API major versions that have a promoted version
+    pub fn api_versions(&self) -> Vec<u32> {
+        let mut versions: Vec<u32> = self
             .promoted_versions
             .iter()
-            .map(|f| {
-                let ver = &f.version;
-                let tag = f
-                    .tags
-                    .iter()
-                    .find(|p| p.name.contains("Sponge"))
-                    .and_then(|f| f.display_data.as_ref())
-                    .and_then(|f| f.split_once("."))
-                    .and_then(|(f, _)| Some(f.parse::<u32>().unwrap_or_default()))
-                    .unwrap_or_default();
-                (ver, tag)
-            })
+            .map(|f| f.sponge_major())
+            .filter(|major| *major != 0)
             .collect();
+        versions.sort();
+        versions.dedup();
+        versions
+    }
+}
 
-        available_tags
-            .iter()
-            .find(|p| p.1.eq(&major_version))
-            .and_then(|f| Some(f.0.as_str()))
-            .unwrap_or_default()
+/// Shows a response with its times relative to a moment, ex: `(3 days ago)`
+/// The moment is passed in rather than read from the system, so the output follows the caller's [Clock](crate::clock::Clock).
+pub struct AsOf<'a, T> {
+    value: &'a T,
+    now: DateTime<Utc>,
+}
+
+impl Project {
+    pub fn as_of(&self, now: DateTime<Utc>) -> AsOf<'_, Project> {
+        AsOf { value: self, now }
     }
 }
 
-impl Display for Project {
+impl Display for AsOf<'_, Project> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Plugin ID : {}", self.namespace.slug)?;
-        writeln!(f, "Author : {}", self.namespace.owner)?;
-        writeln!(f, "Description : {}", self.description)?;
-        writeln!(f, "Last Updated : {}", self.last_updated)?;
+        let project = self.value;
+        writeln!(f, "Plugin ID : {}", project.namespace.slug)?;
+        writeln!(f, "Author : {}", project.namespace.owner)?;
+        writeln!(f, "Description : {}", project.description)?;
+        writeln!(
+            f,
+            "Last Updated : {} ({})",
+            project.last_updated,
+            ago(project.last_updated, self.now)
+        )?;
         writeln!(
             f,
             "Promoted Version : {}",
-            self.promoted_versions
+            project
+                .promoted_versions
                 .iter()
                 .map(|f| format!(
                     "{} - {}",
@@ -71,18 +126,19 @@ impl Display for Project {
                 .collect::<Vec<String>>()
                 .join("\n\t| ")
         )?;
-        write!(f, "{}", self.stats)
+        writeln!(f, "{}", project.stats)?;
+        write!(f, "{}", project.user_actions)
     }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Role {
-    name: String,
-    title: String,
-    color: String,
+    pub name: String,
+    pub title: String,
+    pub color: String,
 }
 
-#[derive(ValueEnum, Clone, Serialize, Deserialize, Debug)]
+#[derive(ValueEnum, Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum Category {
     AdminTools,
@@ -97,6 +153,24 @@ pub enum Category {
     Misc,
 }
 
+impl QueryValue for Category {
+    fn query_value(&self) -> String {
+        let str = match self {
+            Category::AdminTools => "admin_tools",
+            Category::Chat => "chat",
+            Category::DevTools => "dev_tools",
+            Category::Economy => "economy",
+            Category::Gameplay => "gameplay",
+            Category::Games => "games",
+            Category::Protection => "protection",
+            Category::RolePlaying => "role_playing",
+            Category::WorldManagement => "world_management",
+            Category::Misc => "misc",
+        };
+        str.to_string()
+    }
+}
+
 impl Display for Category {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
@@ -117,8 +191,8 @@ impl Display for Category {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProjectLicense {
-    name: Option<String>,
-    url: Option<String>,
+    pub name: Option<String>,
+    pub url: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -139,6 +213,78 @@ pub struct PromotedVersion {
     pub tags: Vec<PromotedVersionTag>,
 }
 
+impl PromotedVersion {
+    /// The major version of the Sponge tag, 0 if there is none
+    pub fn sponge_major(&self) -> u32 {
+        self.tags
+            .iter()
+            .find(|p| p.name.contains("Sponge"))
+            .and_then(|f| f.display_data.as_ref())
+            .and_then(|f| f.split_once("."))
+            .map(|(f, _)| f.parse::<u32>().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// The release channel from its channel or stability tag, untagged versions are releases
+    pub fn channel(&self) -> ReleaseChannel {
+        self.tags
+            .iter()
+            .find(|t| {
+                t.name.eq_ignore_ascii_case("channel") || t.name.eq_ignore_ascii_case("stability")
+            })
+            .and_then(|t| t.data.as_deref().or(t.display_data.as_deref()))
+            .map(ReleaseChannel::of_tag)
+            .unwrap_or_default()
+    }
+}
+
+/// How stable a version is, each channel also accepts the ones more stable than it
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReleaseChannel {
+    #[default]
+    Release,
+    Beta,
+    /// Alpha, snapshot and other unstable builds
+    Alpha,
+}
+
+impl ReleaseChannel {
+    /// Ore channels are named by each project, so they're matched by the usual names
+    fn of_tag(data: &str) -> ReleaseChannel {
+        let data = data.to_lowercase();
+        if data.contains("beta") {
+            ReleaseChannel::Beta
+        } else if [
+            "alpha",
+            "snapshot",
+            "dev",
+            "bleeding",
+            "nightly",
+            "unstable",
+            "unsupported",
+            "broken",
+        ]
+        .iter()
+        .any(|name| data.contains(name))
+        {
+            ReleaseChannel::Alpha
+        } else {
+            ReleaseChannel::Release
+        }
+    }
+}
+
+impl Display for ReleaseChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ReleaseChannel::Release => "Release",
+            ReleaseChannel::Beta => "Beta",
+            ReleaseChannel::Alpha => "Alpha",
+        };
+        write!(f, "{}", str)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CompactProject {
     plugin_id: String,
@@ -156,12 +302,28 @@ pub struct KeyPermissions {
     permissions: Vec<String>, // Ditto ^
 }
 
+impl KeyPermissions {
+    pub fn has(&self, permission: &str) -> bool {
+        self.permissions
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(permission))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UserActions {
     starred: bool,
     watching: bool,
 }
 
+impl Display for UserActions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let yes_no = |b: bool| if b { "Yes" } else { "No" };
+        writeln!(f, "Starred : {}", yes_no(self.starred))?;
+        write!(f, "Watching : {}", yes_no(self.watching))
+    }
+}
+
 #[derive(ValueEnum, Clone, Serialize, Deserialize, Debug)]
 enum NamedPermissions {
     ViewPublicInfo,
@@ -207,10 +369,44 @@ pub struct User {
     roles: Vec<Role>,
 }
 
+impl Display for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "User : {}", self.name)?;
+        if let Some(tagline) = &self.tagline {
+            writeln!(f, "Tagline : {}", tagline)?;
+        }
+        writeln!(
+            f,
+            "Joined : {}",
+            self.join_date
+                .as_deref()
+                .unwrap_or(&self.created_at.to_string())
+        )?;
+        write!(
+            f,
+            "Roles : {}",
+            self.roles
+                .iter()
+                .map(|r| r.title.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct VersionDependency {
-    plugin_id: String,
-    version: Option<String>,
+    pub plugin_id: String,
+    pub version: Option<String>,
+}
+
+impl From<&VersionDependency> for PluginDependency {
+    fn from(value: &VersionDependency) -> Self {
+        PluginDependency {
+            id: value.plugin_id.to_string(),
+            version: value.version.clone().unwrap_or_default(),
+        }
+    }
 }
 
 impl Display for VersionDependency {
@@ -229,10 +425,19 @@ pub struct ProjectSettings {
     homepage: Option<String>,
     issues: Option<String>,
     sources: Option<String>,
-    license: ProjectLicense,
+    pub license: ProjectLicense,
     forum_sync: bool,
 }
 
+impl ProjectSettings {
+    /// The link to the project's source code, if it has one
+    pub fn sources(&self) -> Option<&str> {
+        self.sources
+            .as_deref()
+            .filter(|sources| !sources.trim().is_empty())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct KeyToCreate {
     name: String,
@@ -245,45 +450,195 @@ pub struct PaginatedProjectResult {
     pub result: Vec<Project>,
 }
 
+/// A column of the project table printed by `search`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+#[value(rename_all = "snake_case")]
+pub enum ProjectColumn {
+    Name,
+    PluginId,
+    Category,
+    Downloads,
+    Stars,
+    LastUpdated,
+    Owner,
+    Description,
+    Views,
+    RecentDownloads,
+    Watchers,
+    Created,
+}
+
+impl ProjectColumn {
+    /// The columns printed unless others are chosen
+    pub const ALL: [ProjectColumn; 6] = [
+        ProjectColumn::Name,
+        ProjectColumn::PluginId,
+        ProjectColumn::Category,
+        ProjectColumn::Downloads,
+        ProjectColumn::Stars,
+        ProjectColumn::LastUpdated,
+    ];
+
+    fn header(&self) -> (&'static str, Align) {
+        match self {
+            ProjectColumn::Name => ("Name", Align::Left),
+            ProjectColumn::PluginId => ("Plugin ID", Align::Left),
+            ProjectColumn::Category => ("Category", Align::Left),
+            ProjectColumn::Downloads => ("Downloads", Align::Right),
+            ProjectColumn::Stars => ("Stars", Align::Right),
+            ProjectColumn::LastUpdated => ("Last Updated", Align::Left),
+            ProjectColumn::Owner => ("Owner", Align::Left),
+            ProjectColumn::Description => ("Description", Align::Left),
+            ProjectColumn::Views => ("Views", Align::Right),
+            ProjectColumn::RecentDownloads => ("Recent Downloads", Align::Right),
+            ProjectColumn::Watchers => ("Watchers", Align::Right),
+            ProjectColumn::Created => ("Created", Align::Left),
+        }
+    }
+
+    fn value(&self, project: &Project) -> String {
+        match self {
+            ProjectColumn::Name => project.name.to_string(),
+            ProjectColumn::PluginId => project.plugin_id.to_string(),
+            ProjectColumn::Category => project.category.to_string(),
+            ProjectColumn::Downloads => project.stats.downloads.to_string(),
+            ProjectColumn::Stars => project.stats.stars.to_string(),
+            ProjectColumn::LastUpdated => project.last_updated.format("%Y-%m-%d").to_string(),
+            ProjectColumn::Owner => project.namespace.owner.to_string(),
+            ProjectColumn::Description => project.description.to_string(),
+            ProjectColumn::Views => project.stats.views.to_string(),
+            ProjectColumn::RecentDownloads => project.stats.recent_downloads.to_string(),
+            ProjectColumn::Watchers => project.stats.watchers.to_string(),
+            ProjectColumn::Created => project.created_at.format("%Y-%m-%d").to_string(),
+        }
+    }
+
+    /// A table of the projects with the columns, in the order they're given
+    pub fn table(columns: &[ProjectColumn], projects: &[Project]) -> Table {
+        let mut table = Table::new(columns.iter().map(|column| column.header()).collect());
+        for project in projects {
+            table.row(columns.iter().map(|column| column.value(project)).collect());
+        }
+        table
+    }
+}
+
 impl Display for PaginatedProjectResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         //writeln!(f, "{}", self.pagination)?;
         self.result
             .iter()
-            .map(|p| writeln!(f, "{}", p.plugin_id))
-            .collect::<std::fmt::Result>()
+            .try_for_each(|p| writeln!(f, "{}", p.plugin_id))
     }
 }
 
+/// The downloads and views of a project during a single day
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProjectStatsDay {
-    downloads: i64,
-    view: i64,
+    pub downloads: i64,
+    pub views: i64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PaginatedCompactProjectResult {
-    pagination: Pagination,
-    result: Vec<CompactProject>,
+    pub pagination: Pagination,
+    pub result: Vec<CompactProject>,
+}
+
+impl Display for PaginatedCompactProjectResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.result.iter().try_for_each(|p| {
+            writeln!(
+                f,
+                "{} ({}) : {} downloads",
+                p.plugin_id, p.name, p.stats.downloads
+            )
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct DeployVersionInfo {
     create_forum_post: bool,
     description: String,
-    //tags: todo!(),
-    // This is typed in documentation as
-    // < * > : { oneOf -> String
-    //                    Vec<String>
-    // }
-    //
+    // Each tag is typed in documentation as either a String or Vec<String>
+    #[serde(default)]
+    tags: HashMap<String, OneOrMany<String>>,
+}
+
+impl DeployVersionInfo {
+    pub fn new(
+        create_forum_post: bool,
+        description: String,
+        tags: HashMap<String, OneOrMany<String>>,
+    ) -> Self {
+        DeployVersionInfo {
+            create_forum_post,
+            description,
+            tags,
+        }
+    }
+}
+
+/// The fields of a version that can be changed after it's published
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct EditableVersion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stability: Option<VersionStability>,
+}
+
+#[derive(ValueEnum, Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionStability {
+    Recommended,
+    Stable,
+    Beta,
+    Alpha,
+    Bleeding,
+    Unsupported,
+    Broken,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Pagination {
-    limit: i64,
-    offset: i64,
-    count: i64,
+    pub limit: i64,
+    pub offset: i64,
+    pub count: i64,
+}
+
+/// A page of results, which can be taken apart and put back together to join several pages
+pub trait Paginated {
+    type Item;
+
+    fn into_parts(self) -> (Pagination, Vec<Self::Item>);
+
+    fn from_parts(pagination: Pagination, result: Vec<Self::Item>) -> Self;
+}
+
+macro_rules! paginated {
+    ($($page:ty => $item:ty),+) => {
+        $(
+            impl Paginated for $page {
+                type Item = $item;
+
+                fn into_parts(self) -> (Pagination, Vec<$item>) {
+                    (self.pagination, self.result)
+                }
+
+                fn from_parts(pagination: Pagination, result: Vec<$item>) -> Self {
+                    Self { pagination, result }
+                }
+            }
+        )+
+    };
+}
+
+paginated! {
+    PaginatedProjectResult => Project,
+    PaginatedCompactProjectResult => CompactProject,
+    PaginatedVersionResult => Version
 }
 
 impl Display for Pagination {
@@ -296,12 +651,12 @@ impl Display for Pagination {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProjectStatsAll {
-    views: i64,
-    downloads: i64,
-    recent_views: i64,
-    recent_downloads: i64,
-    stars: i64,
-    watchers: i64,
+    pub views: i64,
+    pub downloads: i64,
+    pub recent_views: i64,
+    pub recent_downloads: i64,
+    pub stars: i64,
+    pub watchers: i64,
 }
 
 impl Display for ProjectStatsAll {
@@ -332,18 +687,25 @@ impl Display for VersionTag {
 
 #[derive(Serialize, Deserialize)]
 pub struct PaginatedVersionResult {
-    pagination: Pagination,
-    result: Vec<Version>,
+    pub pagination: Pagination,
+    pub result: Vec<Version>,
 }
 
-impl Display for PaginatedVersionResult {
+impl PaginatedVersionResult {
+    pub fn as_of(&self, now: DateTime<Utc>) -> AsOf<'_, PaginatedVersionResult> {
+        AsOf { value: self, now }
+    }
+}
+
+impl Display for AsOf<'_, PaginatedVersionResult> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
-            self.result
+            self.value
+                .result
                 .iter()
-                .map(|f| f.to_string())
+                .map(|version| version.as_of(self.now).to_string())
                 .rev()
                 .collect::<String>()
         )
@@ -366,13 +728,14 @@ pub struct OreSession {
 #[derive(Serialize, Deserialize)]
 pub struct FileInfo {
     name: String,
-    size_bytes: f64,
-    md_5_hash: Option<String>,
+    pub size_bytes: f64,
+    #[serde(alias = "md5_hash")]
+    pub md_5_hash: Option<String>,
 }
 
 impl Display for FileInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}", format!("{:=^45}", "[File Info]"))?;
+        writeln!(f, "{:=^45}", "[File Info]")?;
         writeln!(f, "# Name : {}", self.name)?;
         writeln!(f, "# Bytes : {}", human_bytes(self.size_bytes))?;
         writeln!(
@@ -380,13 +743,14 @@ impl Display for FileInfo {
             "# md_5 : {}",
             self.md_5_hash.as_deref().unwrap_or("Not Available")
         )?;
-        writeln!(f, "{}", format!("{:=^45}", ""))
+        writeln!(f, "{:=^45}", "")
     }
 }
 
+/// The downloads of a version during a single day
 #[derive(Serialize, Deserialize, Debug)]
 pub struct VersionStatsDay {
-    downloads: i64,
+    pub downloads: i64,
 }
 
 #[derive(ValueEnum, Clone, Serialize, Deserialize, Debug)]
@@ -401,6 +765,22 @@ pub enum ProjectSortingStrategy {
     RecentViews,
 }
 
+impl QueryValue for ProjectSortingStrategy {
+    fn query_value(&self) -> String {
+        let str = match self {
+            ProjectSortingStrategy::Stars => "stars",
+            ProjectSortingStrategy::Downloads => "downloads",
+            ProjectSortingStrategy::Views => "views",
+            ProjectSortingStrategy::Newest => "newest",
+            ProjectSortingStrategy::Updated => "updated",
+            ProjectSortingStrategy::OnlyRelevance => "only_relevance",
+            ProjectSortingStrategy::RecentDownloads => "recent_downloads",
+            ProjectSortingStrategy::RecentViews => "recent_views",
+        };
+        str.to_string()
+    }
+}
+
 impl Display for ProjectSortingStrategy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
@@ -419,28 +799,84 @@ impl Display for ProjectSortingStrategy {
 
 #[derive(Serialize, Deserialize)]
 pub struct Version {
-    created_at: DateTime<Utc>,
-    name: String,
-    dependencies: Vec<VersionDependency>,
+    pub created_at: DateTime<Utc>,
+    pub name: String,
+    pub dependencies: Vec<VersionDependency>,
     visibility: String,
     description: Option<String>,
     stats: VersionStatsAll,
-    file_info: FileInfo,
+    pub file_info: FileInfo,
     author: Option<String>,
-    review_state: String,
+    pub review_state: String,
     tags: Vec<VersionTag>,
 }
 
-impl Display for Version {
+/// Any state other than reviewed is still waiting on a reviewer
+pub fn is_awaiting_review(review_state: &str) -> bool {
+    review_state != "reviewed"
+}
+
+impl Version {
+    pub fn awaiting_review(&self) -> bool {
+        is_awaiting_review(&self.review_state)
+    }
+
+    /// The changelog of the version, as markdown
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref().filter(|d| !d.trim().is_empty())
+    }
+
+    /// The major version of the Sponge tag, 0 if there is none
+    pub fn sponge_major(&self) -> u32 {
+        self.tags
+            .iter()
+            .find(|t| t.name.contains("Sponge"))
+            .and_then(|t| t.data.as_deref())
+            .and_then(|d| d.split('.').next())
+            .map(|major| major.parse::<u32>().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// The release channel from its channel or stability tag, untagged versions are releases
+    pub fn channel(&self) -> ReleaseChannel {
+        self.tags
+            .iter()
+            .find(|t| {
+                t.name.eq_ignore_ascii_case("channel") || t.name.eq_ignore_ascii_case("stability")
+            })
+            .and_then(|t| t.data.as_deref())
+            .map(ReleaseChannel::of_tag)
+            .unwrap_or_default()
+    }
+}
+
+impl Version {
+    pub fn as_of(&self, now: DateTime<Utc>) -> AsOf<'_, Version> {
+        AsOf { value: self, now }
+    }
+}
+
+impl Display for AsOf<'_, Version> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}", format!("{:=^45}", format!("[{}]", self.name)))?;
-        writeln!(f, "Author : {}", self.author.as_deref().unwrap_or_default())?;
-        writeln!(f, "Created at : {}", self.created_at)?;
-        writeln!(f, "Review State : {}", self.review_state)?;
+        let version = self.value;
+        writeln!(f, "{:=^45}", format!("[{}]", version.name))?;
+        writeln!(
+            f,
+            "Author : {}",
+            version.author.as_deref().unwrap_or_default()
+        )?;
+        writeln!(
+            f,
+            "Created at : {} ({})",
+            version.created_at,
+            ago(version.created_at, self.now)
+        )?;
+        writeln!(f, "Review State : {}", version.review_state)?;
         writeln!(
             f,
             "Tags : {}",
-            self.tags
+            version
+                .tags
                 .iter()
                 .map(|t| format!("[{}] ", t))
                 .collect::<String>()
@@ -448,16 +884,17 @@ impl Display for Version {
         writeln!(
             f,
             "Dependencies : {}",
-            self.dependencies
+            version
+                .dependencies
                 .iter()
                 .map(|d| d.to_string())
                 .collect::<String>()
         )?;
         //writeln!(f, "{}", self.visibility)?;
         //writeln!(f, "{}", self.description.as_deref().unwrap_or_default())?;
-        writeln!(f, "Downloads : {}", self.stats)?;
+        writeln!(f, "Downloads : {}", version.stats)?;
 
-        write!(f, "{}", self.file_info)
+        write!(f, "{}", version.file_info)
     }
 }
 
@@ -475,8 +912,8 @@ impl Display for VersionTagColor {
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProjectMember {
-    user: String,
-    roles: Vec<Role>,
+    pub user: String,
+    pub roles: Vec<Role>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
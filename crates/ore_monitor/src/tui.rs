@@ -0,0 +1,267 @@
+pub mod browser {
+    use anyhow::Result;
+    use ore_monitor::{
+        ore::ore_client::OreClient,
+        query::Query,
+        query_builder,
+        sponge_schemas::{PaginatedProjectResult, Project, Version},
+    };
+    use ratatui::{
+        crossterm::event::{self, Event, KeyCode, KeyEventKind},
+        layout::{Constraint, Layout, Rect},
+        style::{Style, Stylize},
+        text::Line,
+        widgets::{Block, List, ListState, Paragraph, Wrap},
+        DefaultTerminal, Frame,
+    };
+
+    /// A version picked to install, `latest` when it was picked from the list of projects
+    pub struct Pick {
+        pub plugin_id: String,
+        pub version: String,
+    }
+
+    /// What a key press leads to
+    enum Step {
+        Continue,
+        Quit,
+        Install(Pick),
+    }
+
+    /// The page of search results being browsed, and the project opened from it
+    pub(crate) struct Browser {
+        page: PaginatedProjectResult,
+        projects: ListState,
+        /// The latest versions of the opened project
+        opened: Option<(Vec<Version>, ListState)>,
+        /// The error of the last key press, shown until the next one
+        status: Option<String>,
+    }
+
+    /// Browses the search results in a terminal UI, returning the version picked to install
+    /// `query` builds the search query of the page starting at an offset.
+    /// The first page is requested before the terminal is taken over, so failing to search is reported as usual.
+    pub async fn browse(
+        ore_client: &OreClient,
+        query: impl Fn(i64) -> Query,
+        offset: i64,
+    ) -> Result<Option<Pick>> {
+        let page = ore_client.projects(query(offset)).await?;
+        if page.result.is_empty() {
+            return Err(anyhow::Error::msg("No projects found"));
+        }
+
+        let mut browser = Browser::new(page);
+        let mut terminal = ratatui::init();
+        let res = browser.run(&mut terminal, ore_client, &query).await;
+        ratatui::restore();
+        res
+    }
+
+    impl Browser {
+        /// How many of the latest versions are listed when opening a project
+        const VERSIONS: i64 = 20;
+
+        const PROJECTS_HELP: &'static str =
+            "↑↓ move  ←→ page  enter versions  i install latest  q quit";
+        const VERSIONS_HELP: &'static str = "↑↓ move  i install  esc back  q quit";
+
+        pub(crate) fn new(page: PaginatedProjectResult) -> Self {
+            Browser {
+                page,
+                projects: ListState::default().with_selected(Some(0)),
+                opened: None,
+                status: None,
+            }
+        }
+
+        async fn run(
+            &mut self,
+            terminal: &mut DefaultTerminal,
+            ore_client: &OreClient,
+            query: &impl Fn(i64) -> Query,
+        ) -> Result<Option<Pick>> {
+            loop {
+                terminal.draw(|frame| self.draw(frame))?;
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                self.status = None;
+                match self.key(key.code, ore_client, query).await {
+                    Ok(Step::Continue) => (),
+                    Ok(Step::Quit) => return Ok(None),
+                    Ok(Step::Install(pick)) => return Ok(Some(pick)),
+                    Err(e) => self.status = Some(e.to_string()),
+                }
+            }
+        }
+
+        fn selected(&self) -> Option<&Project> {
+            self.projects
+                .selected()
+                .and_then(|selected| self.page.result.get(selected))
+        }
+
+        async fn key(
+            &mut self,
+            code: KeyCode,
+            ore_client: &OreClient,
+            query: &impl Fn(i64) -> Query,
+        ) -> Result<Step> {
+            let Some(plugin_id) = self.selected().map(|project| project.plugin_id.to_string())
+            else {
+                return Ok(Step::Quit);
+            };
+
+            if let Some((versions, state)) = &mut self.opened {
+                match code {
+                    KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                    KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+                    KeyCode::Char('i') => {
+                        if let Some(version) = state.selected().and_then(|i| versions.get(i)) {
+                            let version = version.name.to_string();
+                            return Ok(Step::Install(Pick { plugin_id, version }));
+                        }
+                    }
+                    KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => self.opened = None,
+                    KeyCode::Char('q') => return Ok(Step::Quit),
+                    _ => (),
+                }
+                return Ok(Step::Continue);
+            }
+
+            let pagination = &self.page.pagination;
+            match code {
+                KeyCode::Up | KeyCode::Char('k') => self.projects.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => self.projects.select_next(),
+                KeyCode::Right | KeyCode::PageDown | KeyCode::Char('n')
+                    if pagination.offset + pagination.limit < pagination.count =>
+                {
+                    let offset = pagination.offset + pagination.limit;
+                    self.turn(ore_client, query(offset)).await?
+                }
+                KeyCode::Left | KeyCode::PageUp | KeyCode::Char('p') if pagination.offset > 0 => {
+                    let offset = (pagination.offset - pagination.limit).max(0);
+                    self.turn(ore_client, query(offset)).await?
+                }
+                KeyCode::Enter => {
+                    let query = query_builder!("limit" : QueryType::Value(Some(Self::VERSIONS)));
+                    let versions = ore_client.versions(&plugin_id, query).await?.result;
+                    let state = ListState::default().with_selected(Some(0));
+                    self.opened = Some((versions, state));
+                }
+                KeyCode::Char('i') => {
+                    let version = "latest".to_string();
+                    return Ok(Step::Install(Pick { plugin_id, version }));
+                }
+                KeyCode::Esc | KeyCode::Char('q') => return Ok(Step::Quit),
+                _ => (),
+            }
+            Ok(Step::Continue)
+        }
+
+        /// Replaces the page with another page of the results, unless that one is empty
+        async fn turn(&mut self, ore_client: &OreClient, query: Query) -> Result<()> {
+            let page = ore_client.projects(query).await?;
+            if page.result.is_empty() {
+                return Err(anyhow::Error::msg("No more projects"));
+            }
+            self.page = page;
+            self.projects.select(Some(0));
+            Ok(())
+        }
+
+        pub(crate) fn draw(&mut self, frame: &mut Frame) {
+            let [main, help] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+            let help_text = match self.opened {
+                Some(_) => Self::VERSIONS_HELP,
+                None => Self::PROJECTS_HELP,
+            };
+            let help_line = match &self.status {
+                Some(status) => Line::from(status.as_str()).red(),
+                None => Line::from(help_text).dim(),
+            };
+            frame.render_widget(help_line, help);
+
+            match self.opened.is_some() {
+                true => self.draw_versions(frame, main),
+                false => self.draw_projects(frame, main),
+            }
+        }
+
+        fn draw_projects(&mut self, frame: &mut Frame, area: Rect) {
+            let pagination = &self.page.pagination;
+            let title = format!(
+                " Search {}-{} of {} ",
+                pagination.offset + 1,
+                pagination.offset + self.page.result.len() as i64,
+                pagination.count
+            );
+            let items = self.page.result.iter().map(|project| {
+                format!(
+                    "{:<24} {:<20} {:>10} {:>6}",
+                    project.plugin_id,
+                    project.namespace.owner,
+                    project.stats.downloads,
+                    project.stats.stars
+                )
+            });
+            let list = List::new(items)
+                .block(Block::bordered().title(title))
+                .highlight_style(Style::new().reversed());
+            frame.render_stateful_widget(list, area, &mut self.projects);
+        }
+
+        fn draw_versions(&mut self, frame: &mut Frame, area: Rect) {
+            let [about, versions_area] =
+                Layout::vertical([Constraint::Length(7), Constraint::Min(0)]).areas(area);
+
+            if let Some(project) = self.selected() {
+                let promoted = project
+                    .promoted_versions
+                    .iter()
+                    .map(|version| version.version.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                let lines = vec![
+                    Line::from(project.description().to_string()),
+                    Line::from(format!(
+                        "{} downloads, {} stars, updated {}",
+                        project.stats.downloads,
+                        project.stats.stars,
+                        project.last_updated.format("%Y-%m-%d")
+                    )),
+                    Line::from(format!("Promoted : {}", promoted)),
+                ];
+                let title = format!(" {} by {} ", project.name, project.namespace.owner);
+                let paragraph = Paragraph::new(lines)
+                    .wrap(Wrap { trim: true })
+                    .block(Block::bordered().title(title));
+                frame.render_widget(paragraph, about);
+            }
+
+            let Some((versions, state)) = &mut self.opened else {
+                return;
+            };
+            let items = versions.iter().map(|version| {
+                format!(
+                    "{:<20} API {:<3} {}  {}",
+                    version.name,
+                    version.sponge_major(),
+                    version.created_at.format("%Y-%m-%d"),
+                    version.review_state
+                )
+            });
+            let list = List::new(items)
+                .block(Block::bordered().title(" Versions "))
+                .highlight_style(Style::new().reversed());
+            frame.render_stateful_widget(list, versions_area, state);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+pub mod jar_verification {
+    use anyhow::Result;
+    use ore_monitor::{file_hash::md5_hex, quarantine::Quarantine};
+    use std::{
+        io::Write,
+        path::{Path, PathBuf},
+        thread,
+    };
+    use tokio::task::JoinSet;
+    use tracing::Level;
+
+    use crate::notify::notification::{Notification, Notifier};
+
+    /// Outcome of verifying a jar against the hash published by its source
+    pub enum Verification {
+        Verified,
+        /// The source does not publish a hash for this version
+        Unavailable,
+        Quarantined,
+    }
+
+    /// Compares the hash of the jar against the expected one, computed with `algorithm`
+    /// If it fails, the jar is moved into quarantine and the notifier is informed.
+    pub async fn compare_or_quarantine(
+        jar: &Path,
+        algorithm: &str,
+        expected: Option<&str>,
+        actual: &str,
+        quarantine: &Quarantine,
+        notifier: &Notifier,
+    ) -> Result<Verification> {
+        let Some(expected) = expected else {
+            return Ok(Verification::Unavailable);
+        };
+
+        if actual.eq_ignore_ascii_case(expected) {
+            return Ok(Verification::Verified);
+        }
+
+        let event = quarantine.isolate(jar, algorithm, expected, actual)?;
+
+        let body = format!(
+            "'{}' failed verification (expected {} {}, found {}) and was moved to '{}'",
+            event.file.display(),
+            event.algorithm,
+            event.expected_hash,
+            event.actual_hash,
+            event.quarantined_to.display()
+        );
+        notifier
+            .notify(&Notification::new("Quarantined jar", body))
+            .await;
+
+        Ok(Verification::Quarantined)
+    }
+
+    /// Hashes the jars on blocking threads, at most one per core at a time
+    /// Progress is reported on stderr unless quiet, the hashes are returned in the same order as the jars
+    pub async fn hash_jars(jars: Vec<PathBuf>) -> Vec<Result<String>> {
+        let limit = thread::available_parallelism().map_or(1, |n| n.get());
+        let total = jars.len();
+        let progress = tracing::enabled!(Level::INFO);
+
+        let mut hashes: Vec<Option<Result<String>>> = jars.iter().map(|_| None).collect();
+        let mut pending = jars.into_iter().enumerate();
+        let mut running = JoinSet::new();
+        let mut done = 0;
+
+        loop {
+            while running.len() < limit {
+                let Some((index, jar)) = pending.next() else {
+                    break;
+                };
+                running.spawn_blocking(move || (index, md5_hex(&jar)));
+            }
+
+            let Some(joined) = running.join_next().await else {
+                break;
+            };
+            // A panicking hash leaves its slot empty, reported as an error below
+            if let Ok((index, hash)) = joined {
+                hashes[index] = Some(hash);
+            }
+
+            done += 1;
+            if progress {
+                eprint!("\rHashed {}/{} jars", done, total);
+                let _ = std::io::stderr().flush();
+            }
+        }
+        if progress && total > 0 {
+            eprintln!();
+        }
+
+        hashes
+            .into_iter()
+            .map(|hash| hash.unwrap_or_else(|| Err(anyhow::Error::msg("Hashing was interrupted"))))
+            .collect()
+    }
+}
@@ -0,0 +1,80 @@
+//! Recorded Ore responses, and a mock Ore serving them to an [OreClient]
+//! Shared by the integration tests of the client and the tests of the commands, each only uses part of it.
+#![allow(dead_code)]
+
+use chrono::{Duration, Utc};
+use ore_monitor::{ore::ore_client::OreClient, retry::RetryPolicy, sponge_schemas::OreSession};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+pub const PROJECT: &str = include_str!("project.json");
+pub const VERSION: &str = include_str!("version.json");
+pub const VERSIONS: &str = include_str!("versions.json");
+pub const SEARCH: &str = include_str!("search.json");
+pub const MEMBERS: &str = include_str!("members.json");
+pub const STATS: &str = include_str!("stats.json");
+pub const VERSION_STATS: &str = include_str!("version_stats.json");
+/// The body of a 404 from the API
+pub const NOT_FOUND: &str = include_str!("not_found.json");
+/// What the proxy in front of Ore answers when Ore is down
+pub const BAD_GATEWAY: &str = include_str!("bad_gateway.html");
+
+/// Where the API is mounted on the mock, as on Ore
+pub const API: &str = "/api/v2";
+
+/// A local server standing in for Ore's API and site
+pub struct MockOre {
+    pub server: MockServer,
+}
+
+impl MockOre {
+    pub async fn start() -> MockOre {
+        MockOre {
+            server: MockServer::start().await,
+        }
+    }
+
+    /// A client of the mock with a session that won't expire during the test
+    /// Requests are sent once, so failures are seen without waiting on retries.
+    pub async fn client(&self) -> OreClient {
+        let session = OreSession {
+            session: "mock-session".to_string(),
+            expires: Utc::now() + Duration::hours(1),
+        };
+        OreClient::new(Default::default(), session, self.url(API))
+            .await
+            .with_site(self.server.uri())
+            .with_retry(RetryPolicy::never())
+    }
+
+    /// The address of a path on the mock
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.server.uri(), path)
+    }
+
+    /// Answers GET requests of the API endpoint with the status and body
+    pub async fn api(&self, endpoint: &str, status: u16, body: &str) {
+        self.serve(&format!("{}{}", API, endpoint), status, body)
+            .await
+    }
+
+    /// Answers GET requests of the path with the status and body
+    pub async fn serve(&self, at: &str, status: u16, body: impl Into<Vec<u8>>) {
+        Mock::given(method("GET"))
+            .and(path(at))
+            .respond_with(ResponseTemplate::new(status).set_body_bytes(body))
+            .mount(&self.server)
+            .await
+    }
+
+    /// Answers GET requests of the path with the response
+    pub async fn respond(&self, at: &str, response: ResponseTemplate) {
+        Mock::given(method("GET"))
+            .and(path(at))
+            .respond_with(response)
+            .mount(&self.server)
+            .await
+    }
+}
@@ -0,0 +1,170 @@
+//! Integration tests of the [OreClient] against a mock Ore serving recorded responses
+//! See the fixtures module for what's recorded, and how to point a client at the mock.
+
+mod fixtures;
+
+use fixtures::{MockOre, BAD_GATEWAY, NOT_FOUND, PROJECT, VERSION, VERSIONS};
+use ore_monitor::{
+    error::OreMonitorError, ore::ore_client::CacheMode, query::Query,
+    response_cache::ResponseCache, sponge_schemas::ReleaseChannel,
+};
+use reqwest::StatusCode;
+use std::time::Duration;
+use wiremock::ResponseTemplate;
+
+#[tokio::test]
+async fn fetches_a_project() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, PROJECT).await;
+
+    let project = ore.client().await.project("nucleus").await.unwrap();
+    assert_eq!(project.plugin_id, "nucleus");
+    assert_eq!(project.namespace.owner, "NucleusPowered");
+}
+
+#[tokio::test]
+async fn promoted_betas_are_skipped_on_the_release_channel() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, PROJECT).await;
+
+    let project = ore.client().await.project("nucleus").await.unwrap();
+    assert_eq!(project.version_in_channel(8, None), "3.0.0-BETA1");
+    assert_eq!(
+        project.version_in_channel(8, Some(ReleaseChannel::Beta)),
+        "3.0.0-BETA1"
+    );
+    assert_eq!(
+        project.version_in_channel(8, Some(ReleaseChannel::Release)),
+        ""
+    );
+    assert_eq!(
+        project.version_in_channel(7, Some(ReleaseChannel::Release)),
+        "2.1.4"
+    );
+}
+
+#[tokio::test]
+async fn fetches_a_page_of_versions() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus/versions", 200, VERSIONS).await;
+
+    let page = ore
+        .client()
+        .await
+        .versions("nucleus", Query::new(vec![]))
+        .await
+        .unwrap();
+    let names: Vec<_> = page.result.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(names, ["3.0.0-BETA1", "2.1.4"]);
+}
+
+#[tokio::test]
+async fn missing_project_is_a_not_found_status() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 404, NOT_FOUND).await;
+
+    let err = ore.client().await.project("nucleus").await.unwrap_err();
+    let err = err.downcast::<OreMonitorError>().unwrap();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(err.exit_code(), 6);
+    assert!(err.to_string().starts_with("request "), "{err}");
+}
+
+#[tokio::test]
+async fn find_project_treats_not_found_as_none() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 404, NOT_FOUND).await;
+
+    let found = ore.client().await.find_project("nucleus").await.unwrap();
+    assert!(found.is_none());
+}
+
+#[tokio::test]
+async fn proxy_error_page_is_a_server_error() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 502, BAD_GATEWAY).await;
+
+    let err = ore.client().await.project("nucleus").await.unwrap_err();
+    let err = err.downcast::<OreMonitorError>().unwrap();
+    assert_eq!(err.status(), Some(StatusCode::BAD_GATEWAY));
+    assert_eq!(err.exit_code(), 5);
+}
+
+#[tokio::test]
+async fn unexpected_body_names_the_endpoint() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, BAD_GATEWAY).await;
+
+    let err = ore.client().await.project("nucleus").await.unwrap_err();
+    let err = err.downcast::<OreMonitorError>().unwrap();
+    assert_eq!(err.exit_code(), 8);
+    assert!(err.to_string().contains("/projects/nucleus"), "{err}");
+}
+
+#[tokio::test]
+async fn streamed_body_keeps_only_its_start_when_unexpected() {
+    let ore = MockOre::start().await;
+    let body = format!("[{}", "1,".repeat(1024 * 1024));
+    ore.api("/projects/nucleus", 200, &body).await;
+
+    let err = ore.client().await.project("nucleus").await.unwrap_err();
+    match err.downcast::<OreMonitorError>().unwrap() {
+        OreMonitorError::Deserialize { snippet, .. } => {
+            assert_eq!(snippet.len(), OreMonitorError::SNIPPET_LEN)
+        }
+        err => panic!("{err}"),
+    }
+}
+
+#[tokio::test]
+async fn cached_body_names_the_endpoint() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, BAD_GATEWAY).await;
+
+    let client = ore.client().await.with_cache(
+        CacheMode::Read,
+        Duration::from_secs(60),
+        ResponseCache::default(),
+    );
+    for _ in 0..2 {
+        let err = client.project("nucleus").await.unwrap_err();
+        assert!(err.to_string().contains("/projects/nucleus"), "{err}");
+    }
+    // The second lookup was served from the cache
+    assert_eq!(ore.server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn downloads_from_the_site() {
+    let ore = MockOre::start().await;
+    ore.api("/projects/nucleus", 200, PROJECT).await;
+    ore.serve(
+        "/NucleusPowered/Nucleus/versions/2.1.4/download",
+        200,
+        b"jar".to_vec(),
+    )
+    .await;
+
+    let client = ore.client().await;
+    let project = client.project("nucleus").await.unwrap();
+    let res = client.download(&project, "2.1.4").await.unwrap();
+    assert_eq!(res.bytes().await.unwrap().as_ref(), b"jar");
+}
+
+#[tokio::test]
+async fn sends_a_request_id() {
+    let ore = MockOre::start().await;
+    ore.respond(
+        "/api/v2/projects/nucleus/versions/2.1.4",
+        ResponseTemplate::new(200).set_body_string(VERSION),
+    )
+    .await;
+
+    ore.client()
+        .await
+        .version("nucleus", "2.1.4")
+        .await
+        .unwrap();
+    let requests = ore.server.received_requests().await.unwrap();
+    assert!(requests[0].headers.contains_key("x-request-id"));
+}
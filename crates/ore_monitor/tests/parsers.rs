@@ -0,0 +1,195 @@
+//! Property tests of the parsers that read what jars and Ore hand over
+//! Inputs found in the wild are often malformed, these should only ever fail with an error.
+
+use std::io::{Cursor, Write};
+
+use ore_monitor::{
+    duration::{parse_duration, parse_interval},
+    file_naming::{extract_filename, NameTemplate},
+    file_reader::FileReader,
+    ore_mod_info::McModInfo,
+    update_order::satisfies,
+};
+use ore_monitor_common::version_status::{UnknownReason, VersionStatus};
+use proptest::prelude::*;
+use serde_json::json;
+use zip::{write::FileOptions, ZipWriter};
+
+/// Builds a jar in memory holding a single file
+fn jar_with(name: &str, contents: &[u8]) -> Vec<u8> {
+    let mut jar = ZipWriter::new(Cursor::new(vec![]));
+    jar.start_file(name, FileOptions::default()).unwrap();
+    jar.write_all(contents).unwrap();
+    jar.finish().unwrap().into_inner()
+}
+
+fn version() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[0-9]{1,3}(\\.[0-9]{1,3}){0,3}",
+        "[0-9]{1,2}\\.[0-9]{1,2}(-[A-Za-z0-9]{1,8})?",
+        any::<String>(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn jar_bytes_never_panic(bytes in proptest::collection::vec(any::<u8>(), 0..512)) {
+        let _ = FileReader::read_jar(Cursor::new(bytes));
+    }
+
+    #[test]
+    fn metadata_contents_never_panic(contents in any::<String>()) {
+        for name in [
+            "mcmod.info",
+            "META-INF/sponge_plugins.json",
+            "META-INF/plugins.json",
+            "fabric.mod.json",
+            "META-INF/MANIFEST.MF",
+        ] {
+            let _ = FileReader::read_jar(Cursor::new(jar_with(name, contents.as_bytes())));
+        }
+    }
+
+    #[test]
+    fn mcmod_info_is_read_back(
+        modid in "[a-z][a-z0-9_-]{0,31}",
+        name in any::<String>(),
+        version in version(),
+        api in "[0-9]{1,2}\\.[0-9]{1,2}",
+    ) {
+        let info = json!({
+            "info": {
+                "modid": modid,
+                "name": name,
+                "version": version,
+                "dependencies": [format!("spongeapi@{}", api)],
+                "requiredMods": [],
+            }
+        });
+        let jar = jar_with("mcmod.info", info.to_string().as_bytes());
+
+        let read = FileReader::read_jar(Cursor::new(jar)).unwrap();
+        prop_assert_eq!(read.modid, modid);
+        prop_assert_eq!(read.name, name);
+        prop_assert_eq!(read.version, version);
+    }
+
+    #[test]
+    fn sponge_plugins_json_is_read_back(
+        id in "[a-z][a-z0-9_-]{0,31}",
+        name in any::<String>(),
+        version in proptest::option::of(version()),
+    ) {
+        let info = json!({
+            "plugins": [{
+                "id": id,
+                "name": name,
+                "version": version,
+                "dependencies": [],
+            }]
+        });
+        let jar = jar_with("META-INF/sponge_plugins.json", info.to_string().as_bytes());
+
+        let read = FileReader::read_jar(Cursor::new(jar)).unwrap();
+        prop_assert_eq!(read.modid, id);
+        prop_assert_eq!(read.version, version.unwrap_or_default().replace(' ', "-"));
+    }
+
+    #[test]
+    fn sponge_8_plugins_json_is_read_back(
+        id in "[a-z][a-z0-9_-]{0,31}",
+        version in version(),
+        api in "[0-9]{1,2}\\.[0-9]{1,2}\\.[0-9]{1,2}",
+    ) {
+        // The version and Sponge API are only declared globally, the plugin has no name or dependencies
+        let info = json!({
+            "loader": { "name": "java_plain", "version": "1.0" },
+            "license": "MIT",
+            "global": {
+                "version": version,
+                "dependencies": [{ "id": "spongeapi", "version": api, "load-order": "after", "optional": false }],
+            },
+            "plugins": [{ "id": id, "entrypoint": "com.example.Plugin" }]
+        });
+        let jar = jar_with("META-INF/sponge_plugins.json", info.to_string().as_bytes());
+
+        let read = FileReader::read_jar(Cursor::new(jar)).unwrap();
+        prop_assert_eq!(&read.name, &id);
+        prop_assert_eq!(read.modid, id);
+        prop_assert_eq!(read.version, version.replace(' ', "-"));
+        prop_assert_eq!(read.major_api_version, api.split('.').next().unwrap().parse::<u32>().unwrap());
+    }
+
+    #[test]
+    fn dependency_entries_never_panic(
+        dependencies in proptest::collection::vec(any::<String>(), 0..8),
+        required_mods in proptest::collection::vec(any::<String>(), 0..8),
+    ) {
+        let info = McModInfo {
+            modid: "nucleus".to_string(),
+            name: "Nucleus".to_string(),
+            version: "2.1.4".to_string(),
+            dependencies,
+            required_mods,
+        };
+        let _ = info.sponge_tag_version();
+        let _ = info.plugin_dependencies();
+    }
+
+    #[test]
+    fn content_disposition_never_panics(header in any::<String>()) {
+        let _ = extract_filename(&header);
+    }
+
+    #[test]
+    fn quoted_filename_is_extracted(name in "[^\"]{1,64}") {
+        let header = format!("attachment; filename=\"{}\"", name);
+        prop_assert_eq!(extract_filename(&header), Some(name.as_str()));
+    }
+
+    #[test]
+    fn rendered_names_stay_in_the_directory(
+        id in any::<String>(),
+        version in any::<String>(),
+        file in any::<String>(),
+    ) {
+        let name = "{id}-{version}-{file}".parse::<NameTemplate>().unwrap().render(&id, &version, &file);
+        prop_assert!(!name.contains('/') && !name.contains('\\'));
+    }
+
+    #[test]
+    fn durations_never_panic(value in prop_oneof!["[0-9]{1,25}[smhd]?", any::<String>()]) {
+        let _ = parse_duration(&value);
+        let _ = parse_interval(&value);
+    }
+
+    #[test]
+    fn version_ranges_never_panic(version in any::<String>(), range in any::<String>()) {
+        let _ = satisfies(&version, &range);
+    }
+
+    #[test]
+    fn versions_satisfy_their_own_minimum(version in version()) {
+        let range = format!("[{},)", version);
+        prop_assert!(satisfies(&version, &range));
+        prop_assert!(satisfies(&version, &version));
+    }
+
+    #[test]
+    fn versions_are_up_to_date_with_themselves(version in version(), other in version()) {
+        prop_assert_eq!(VersionStatus::new(&version, &version), VersionStatus::UpToDate);
+
+        // Comparing the other way around always gives the opposite status
+        let opposite = match VersionStatus::new(&other, &version) {
+            VersionStatus::OutOfDate => VersionStatus::Overdated,
+            VersionStatus::Overdated => VersionStatus::OutOfDate,
+            VersionStatus::UpToDate => VersionStatus::UpToDate,
+            VersionStatus::Unknown(reason) => VersionStatus::Unknown(match reason {
+                UnknownReason::Local => UnknownReason::Remote,
+                UnknownReason::Remote => UnknownReason::Local,
+                UnknownReason::Both => UnknownReason::Both,
+            }),
+        };
+        prop_assert_eq!(VersionStatus::new(&version, &other), opposite);
+    }
+}
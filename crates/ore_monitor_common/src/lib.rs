@@ -1,10 +1,10 @@
 /// Module handles version checking implementation
 pub mod version_status {
     use std::{cmp::Ordering, fmt::Display};
-    use versions::Versioning;
 
     /// Represents the status a version can have compared to Ore
-    #[derive(PartialEq, Debug)]
+    /// Statuses are ordered by how urgently they need attention
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
     pub enum VersionStatus {
         /// Version is outdated
         OutOfDate,
@@ -12,14 +12,46 @@ pub mod version_status {
         UpToDate,
         /// Version is higher than remote version
         Overdated,
+        /// Versions couldn't be compared, rather than guessing
+        Unknown(UnknownReason),
     }
 
+    /// Which side of a comparison wasn't a readable version
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+    pub enum UnknownReason {
+        /// The installed version, ex: an unexpanded `${version}`
+        Local,
+        /// The version on Ore
+        Remote,
+        /// Neither version
+        Both,
+    }
+
+    impl Display for UnknownReason {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let reason = match self {
+                UnknownReason::Local => "the local version isn't a version number",
+                UnknownReason::Remote => "the remote version isn't a version number",
+                UnknownReason::Both => "neither version is a version number",
+            };
+            write!(f, "{}", reason)
+        }
+    }
+
+    /// Pre-release tags that are compared, other suffixes are plugin-specific and dropped
+    const PRE_RELEASE_TAGS: [&str; 8] = [
+        "alpha", "beta", "pre", "preview", "rc", "snapshot", "dev", "nightly",
+    ];
+
     impl Display for VersionStatus {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             let status = match self {
                 VersionStatus::OutOfDate => "Version is outdated",
                 VersionStatus::UpToDate => "Version is up to date",
                 VersionStatus::Overdated => "Local version is newer than Remote version",
+                VersionStatus::Unknown(reason) => {
+                    return write!(f, "Unable to compare versions, {}", reason);
+                }
             };
             write!(f, "{}", status)
         }
@@ -28,17 +60,37 @@ pub mod version_status {
     impl VersionStatus {
         /// Compares the local and remote versions
         /// ```
-        /// use ore_monitor_common::version_status::VersionStatus;
+        /// use ore_monitor_common::version_status::{UnknownReason, VersionStatus};
         ///
         /// assert_eq!(VersionStatus::new("2.0","2.0"), VersionStatus::UpToDate);
         ///
         /// assert_eq!(VersionStatus::new("1.0","2.0"), VersionStatus::OutOfDate);
         ///
         /// assert_eq!(VersionStatus::new("2.0","1.0"), VersionStatus::Overdated);
+        ///
+        /// assert_eq!(VersionStatus::new("2.0.0PRE9H2","2.0.0"), VersionStatus::OutOfDate);
+        ///
+        /// assert_eq!(VersionStatus::new("2.0.0PRE9H2","2.0.0PRE10"), VersionStatus::OutOfDate);
+        ///
+        /// assert_eq!(
+        ///     VersionStatus::new("${version}","1.0"),
+        ///     VersionStatus::Unknown(UnknownReason::Local)
+        /// );
         /// ```
         pub fn new(local: &'_ str, remote: &'_ str) -> VersionStatus {
-            let local = Versioning::new(local).unwrap_or_default();
-            let remote = Versioning::new(remote).unwrap_or_default();
+            if local.trim() == remote.trim() {
+                return VersionStatus::UpToDate;
+            }
+
+            let local = Lenient::parse(local);
+            let remote = Lenient::parse(remote);
+
+            let (local, remote) = match (local, remote) {
+                (Some(local), Some(remote)) => (local, remote),
+                (None, Some(_)) => return VersionStatus::Unknown(UnknownReason::Local),
+                (Some(_), None) => return VersionStatus::Unknown(UnknownReason::Remote),
+                (None, None) => return VersionStatus::Unknown(UnknownReason::Both),
+            };
 
             match local.cmp(&remote) {
                 Ordering::Less => VersionStatus::OutOfDate,
@@ -47,4 +99,171 @@ pub mod version_status {
             }
         }
     }
+
+    /// Leniently rewrites a version written by a plugin author into a comparable one
+    /// Returns None when there's no version number to compare
+    /// ```
+    /// use ore_monitor_common::version_status::normalize;
+    ///
+    /// assert_eq!(normalize("v2.1").as_deref(), Some("2.1"));
+    ///
+    /// assert_eq!(normalize("2.0.0PRE9H2").as_deref(), Some("2.0.0-pre.9.h.2"));
+    ///
+    /// assert_eq!(normalize("1.4.0-SNAPSHOT+b12").as_deref(), Some("1.4.0-snapshot"));
+    ///
+    /// assert_eq!(normalize("3.2-API7").as_deref(), Some("3.2"));
+    ///
+    /// assert_eq!(normalize("${version}"), None);
+    /// ```
+    pub fn normalize(version: &str) -> Option<String> {
+        Lenient::parse(version).map(|version| version.to_string())
+    }
+
+    /// A version number with an optional pre-release, split into comparable parts
+    /// Ordered the way [VersionStatus] compares versions.
+    /// ```
+    /// use ore_monitor_common::version_status::Lenient;
+    ///
+    /// assert_eq!(Lenient::parse("v1.2.0"), Lenient::parse("1.2.0+build"));
+    /// assert!(Lenient::parse("v1.2.0") > Lenient::parse("1.2.0-beta"));
+    /// assert_eq!(Lenient::parse("${version}"), None);
+    /// ```
+    #[derive(Debug)]
+    pub struct Lenient {
+        core: Vec<String>,
+        pre_release: Vec<String>,
+    }
+
+    impl Lenient {
+        /// Returns None when there's no version number to compare
+        pub fn parse(version: &str) -> Option<Lenient> {
+            let version = version.trim();
+            let version = version
+                .strip_prefix(['v', 'V'])
+                .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+                .unwrap_or(version);
+            if !version.starts_with(|c: char| c.is_ascii_digit()) {
+                return None;
+            }
+
+            // Build metadata never affects which version is newer
+            let version = version.split('+').next().unwrap_or(version);
+
+            let core_end = version
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(version.len());
+            let (core, suffix) = version.split_at(core_end);
+            let core = core
+                .split('.')
+                .filter(|part| !part.is_empty())
+                .map(|part| part.trim_start_matches('0').to_string())
+                .collect();
+
+            let mut pre_release = tokens(suffix);
+            let compared = pre_release.first().is_some_and(|first| {
+                first.starts_with(|c: char| c.is_ascii_digit())
+                    || PRE_RELEASE_TAGS.contains(&first.as_str())
+            });
+            if !compared {
+                pre_release.clear();
+            }
+
+            Some(Lenient { core, pre_release })
+        }
+    }
+
+    impl Ord for Lenient {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Missing parts count as zero, so 1.2 is 1.2.0
+            let parts = self.core.len().max(other.core.len());
+            (0..parts)
+                .map(|i| {
+                    let ours = self.core.get(i).map_or("", String::as_str);
+                    let theirs = other.core.get(i).map_or("", String::as_str);
+                    compare_token(ours, theirs)
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| {
+                    // A release is newer than any of its pre-releases
+                    match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+                        (true, true) => Ordering::Equal,
+                        (true, false) => Ordering::Greater,
+                        (false, true) => Ordering::Less,
+                        (false, false) => self
+                            .pre_release
+                            .iter()
+                            .zip(&other.pre_release)
+                            .map(|(ours, theirs)| compare_token(ours, theirs))
+                            .find(|ordering| ordering.is_ne())
+                            .unwrap_or_else(|| {
+                                self.pre_release.len().cmp(&other.pre_release.len())
+                            }),
+                    }
+                })
+        }
+    }
+
+    impl PartialEq for Lenient {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other).is_eq()
+        }
+    }
+
+    impl Eq for Lenient {}
+
+    impl PartialOrd for Lenient {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Display for Lenient {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let core: Vec<&str> = self
+                .core
+                .iter()
+                .map(|part| if part.is_empty() { "0" } else { part })
+                .collect();
+            write!(f, "{}", core.join("."))?;
+            if !self.pre_release.is_empty() {
+                write!(f, "-{}", self.pre_release.join("."))?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Numbers compare numerically, without overflowing, and before words
+    fn compare_token(ours: &str, theirs: &str) -> Ordering {
+        let numeric = |token: &str| token.chars().all(|c| c.is_ascii_digit());
+        match (numeric(ours), numeric(theirs)) {
+            (true, true) => {
+                let ours = ours.trim_start_matches('0');
+                let theirs = theirs.trim_start_matches('0');
+                ours.len().cmp(&theirs.len()).then_with(|| ours.cmp(theirs))
+            }
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => ours.cmp(theirs),
+        }
+    }
+
+    /// Splits a suffix into lowercase runs of letters and runs of digits,
+    /// so `PRE9H2` compares its numbers numerically
+    fn tokens(suffix: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut last: Option<bool> = None;
+        for c in suffix.chars() {
+            if !c.is_ascii_alphanumeric() {
+                last = None;
+                continue;
+            }
+            let digit = c.is_ascii_digit();
+            match tokens.last_mut() {
+                Some(token) if last == Some(digit) => token.push(c.to_ascii_lowercase()),
+                _ => tokens.push(c.to_ascii_lowercase().to_string()),
+            }
+            last = Some(digit);
+        }
+        tokens
+    }
 }